@@ -1,12 +1,39 @@
 use dotenvy::dotenv;
-use sqlx::sqlite::SqlitePoolOptions;
 use std::sync::Arc;
 use techpulse_adapter::http::{routes, AppState};
+use techpulse_domain::blindspot::BlindSpotPolicy;
+use techpulse_domain::briefing::BriefingLimits;
+use techpulse_domain::decay::DecayPolicy;
+use techpulse_domain::event::ClusteringPolicy;
+use techpulse_domain::interaction::InteractionPolicy;
+use techpulse_domain::personalization::PersonalizationWeights;
+use techpulse_domain::search::SearchRanking;
+use techpulse_domain::trend::MilestonePolicy;
+use techpulse_domain::webhook::SpikePolicy;
 use techpulse_infra::gateway::HackerNewsGateway;
-use techpulse_infra::repo::db::{SqliteArticleRepo, SqliteTrendRepo};
-use techpulse_usecase::feed::GetChronologicalFeed;
+use techpulse_infra::repo::backend::{DatabaseBackend, Repositories};
+use techpulse_usecase::blindspots::GetBlindSpots;
+use techpulse_usecase::articles::GetArticleDetail;
+use techpulse_usecase::briefing::{GenerateBriefing, ListBriefings};
+use techpulse_usecase::clustering::ClusterStories;
+use techpulse_usecase::cooccurrence::{BuildCooccurrenceGraph, GetTopicNeighborhood};
+use techpulse_usecase::delivery::ListDeliveries;
+use techpulse_usecase::decay::{ApplyKnowledgeDecay, GetRevisitSuggestions};
+use techpulse_usecase::feed::{GetChronologicalFeed, PersonalizeFeed};
 use techpulse_usecase::ingest::IngestArticles;
+use techpulse_usecase::search::SearchArticles;
+use techpulse_usecase::interactions::{RecordInteractions, UpdateProfileFromInteractions};
+use techpulse_usecase::timeline::{
+    DeleteTimelineEvent, GenerateTimeline, GetTimeline, PinArticle, UpdateTimelineEvent,
+};
+use techpulse_usecase::topics::{GetCategoryRollup, ListTopics, SeedDefaultTopics};
 use techpulse_usecase::trends::CalculateTrends;
+use techpulse_usecase::users::{GetUserProfile, ListTopicsByState, SetTopicKnowledge, UpdateUserSettings};
+use techpulse_usecase::mutes::{AddMuteRule, ListMuteRules, RemoveMuteRule};
+use techpulse_usecase::watchlist::{AddWatchRule, EvaluateWatchlists, ListAlerts, ListWatchRules, RemoveWatchRule};
+use techpulse_usecase::webhooks::{
+    CreateWebhook, DeleteWebhook, ListWebhookDeliveries, ListWebhooks, PublishWebhookEvents,
+};
 use tokio::net::TcpListener;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -20,29 +47,139 @@ async fn main() {
         .with(tracing_subscriber::fmt::layer())
         .init();
 
-    // Initialize database pool
+    // Connect to the configured backend and run its migrations
     let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    let pool = SqlitePoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
+    let backend = std::env::var("DATABASE_BACKEND")
+        .map_or_else(|_| Ok(DatabaseBackend::from_url(&database_url)), |b| b.parse())
+        .expect("DATABASE_BACKEND must be sqlite or postgres");
+    let repos = Repositories::connect(backend, &database_url, 5)
         .await
         .expect("Failed to connect to database");
 
-    // Run migrations
-    sqlx::migrate!("../../migrations")
-        .run(&pool)
-        .await
-        .expect("Failed to run migrations");
-
     // Composition root: construct repositories, gateways, and use cases
-    let article_repo = Arc::new(SqliteArticleRepo::new(pool.clone()));
-    let trend_repo = Arc::new(SqliteTrendRepo::new(pool.clone()));
+    let article_repo = repos.articles.clone();
+    let trend_repo = repos.trends.clone();
+    let topic_repo = repos.topics.clone();
+    let graph_repo = repos.cooccurrence.clone();
+    let timeline_repo = repos.timeline.clone();
+    let user_repo = repos.users.clone();
+    let interaction_repo = repos.interactions.clone();
+    let briefing_repo = repos.briefings.clone();
+    let webhook_repo = repos.webhooks.clone();
+    let alert_repo = repos.alerts.clone();
+    let snapshot_repo = repos.snapshots.clone();
     let hn_gateway = Arc::new(HackerNewsGateway::new());
 
+    // Make sure the built-in taxonomy exists before serving requests
+    SeedDefaultTopics::new(topic_repo.clone())
+        .execute()
+        .await
+        .expect("Failed to seed topics");
+
+    let clusterer = Arc::new(ClusterStories::new(
+        article_repo.clone(),
+        topic_repo.clone(),
+        ClusteringPolicy::default(),
+    ));
+
+    let personalized_feed = Arc::new(PersonalizeFeed::new(
+        article_repo.clone(),
+        topic_repo.clone(),
+        user_repo.clone(),
+        PersonalizationWeights::default(),
+    ));
+    let blind_spots = Arc::new(GetBlindSpots::new(
+        article_repo.clone(),
+        topic_repo.clone(),
+        user_repo.clone(),
+        BlindSpotPolicy::default(),
+    ));
+
+    // Ingest and trend runs queue webhook deliveries; the worker sends them
+    let webhook_publisher = Arc::new(PublishWebhookEvents::new(webhook_repo.clone()));
+
     let state = AppState {
         feed: Arc::new(GetChronologicalFeed::new(article_repo.clone())),
-        trends: Arc::new(CalculateTrends::new(article_repo.clone(), trend_repo)),
-        ingest: Arc::new(IngestArticles::new(hn_gateway, article_repo)),
+        personalized_feed: personalized_feed.clone(),
+        search: Arc::new(SearchArticles::new(article_repo.clone(), SearchRanking::default())),
+        article_detail: Arc::new(GetArticleDetail::new(article_repo.clone(), snapshot_repo.clone())),
+        trends: Arc::new(
+            CalculateTrends::new(article_repo.clone(), trend_repo.clone())
+                .with_publisher(webhook_publisher.clone(), SpikePolicy::default()),
+        ),
+        ingest: Arc::new(
+            IngestArticles::new(hn_gateway, article_repo.clone())
+                .with_publisher(webhook_publisher)
+                .with_watchlists(Arc::new(EvaluateWatchlists::new(user_repo.clone(), alert_repo.clone())))
+                .with_snapshots(snapshot_repo),
+        ),
+        topics: Arc::new(ListTopics::new(topic_repo.clone())),
+        topic_rollup: Arc::new(GetCategoryRollup::new(topic_repo.clone(), trend_repo)),
+        build_graph: Arc::new(BuildCooccurrenceGraph::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            graph_repo.clone(),
+        )),
+        neighborhood: Arc::new(GetTopicNeighborhood::new(topic_repo.clone(), graph_repo)),
+        events: clusterer.clone(),
+        timeline: Arc::new(GetTimeline::new(timeline_repo.clone())),
+        pin_timeline: Arc::new(PinArticle::new(article_repo.clone(), topic_repo.clone(), timeline_repo.clone())),
+        update_timeline: Arc::new(UpdateTimelineEvent::new(timeline_repo.clone())),
+        delete_timeline: Arc::new(DeleteTimelineEvent::new(timeline_repo.clone())),
+        generate_timeline: Arc::new(GenerateTimeline::new(
+            clusterer,
+            topic_repo.clone(),
+            timeline_repo,
+            MilestonePolicy::default(),
+        )),
+        user_profile: Arc::new(GetUserProfile::new(user_repo.clone())),
+        set_knowledge: Arc::new(SetTopicKnowledge::new(user_repo.clone(), topic_repo.clone())),
+        knowledge_by_state: Arc::new(ListTopicsByState::new(user_repo.clone())),
+        user_settings: Arc::new(UpdateUserSettings::new(user_repo.clone())),
+        blind_spots: blind_spots.clone(),
+        interactions: Arc::new(RecordInteractions::new(
+            interaction_repo,
+            Arc::new(UpdateProfileFromInteractions::new(
+                article_repo.clone(),
+                topic_repo.clone(),
+                user_repo.clone(),
+                InteractionPolicy::default(),
+            )),
+        )),
+        knowledge_decay: Arc::new(ApplyKnowledgeDecay::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            user_repo.clone(),
+            DecayPolicy::default(),
+        )),
+        revisit: Arc::new(GetRevisitSuggestions::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            user_repo.clone(),
+            DecayPolicy::default(),
+        )),
+        briefing: Arc::new(GenerateBriefing::new(
+            personalized_feed,
+            blind_spots,
+            article_repo,
+            topic_repo,
+            user_repo.clone(),
+            briefing_repo.clone(),
+            BriefingLimits::default(),
+        )),
+        briefing_archive: Arc::new(ListBriefings::new(briefing_repo)),
+        deliveries: Arc::new(ListDeliveries::new(repos.deliveries.clone())),
+        create_webhook: Arc::new(CreateWebhook::new(webhook_repo.clone())),
+        webhooks: Arc::new(ListWebhooks::new(webhook_repo.clone())),
+        delete_webhook: Arc::new(DeleteWebhook::new(webhook_repo.clone())),
+        webhook_deliveries: Arc::new(ListWebhookDeliveries::new(webhook_repo)),
+        add_watch_rule: Arc::new(AddWatchRule::new(user_repo.clone())),
+        watch_rules: Arc::new(ListWatchRules::new(user_repo.clone())),
+        remove_watch_rule: Arc::new(RemoveWatchRule::new(user_repo.clone())),
+        alerts: Arc::new(ListAlerts::new(alert_repo)),
+        add_mute: Arc::new(AddMuteRule::new(user_repo.clone())),
+        mutes: Arc::new(ListMuteRules::new(user_repo.clone())),
+        remove_mute: Arc::new(RemoveMuteRule::new(user_repo)),
     };
 
     // Initialize routes with state
@@ -0,0 +1,119 @@
+use chrono::{NaiveDate, Utc};
+use clap::{Parser, Subcommand};
+use dotenvy::dotenv;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+use techpulse_adapter::render::{render_briefing, BriefingTemplate, RenderFormat};
+use techpulse_domain::blindspot::BlindSpotPolicy;
+use techpulse_domain::briefing::BriefingLimits;
+use techpulse_domain::personalization::PersonalizationWeights;
+use techpulse_domain::user::UserId;
+use techpulse_infra::repo::backend::{DatabaseBackend, Repositories};
+use techpulse_usecase::blindspots::GetBlindSpots;
+use techpulse_usecase::briefing::GenerateBriefing;
+use techpulse_usecase::feed::PersonalizeFeed;
+use techpulse_usecase::topics::SeedDefaultTopics;
+
+#[derive(Parser)]
+#[command(name = "techpulse", about = "Tech Pulse command line tools")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Render a user's daily briefing
+    Briefing {
+        #[arg(long)]
+        user: String,
+        /// Day to render (YYYY-MM-DD), defaults to today
+        #[arg(long)]
+        date: Option<NaiveDate>,
+        /// markdown, html or text
+        #[arg(long, default_value = "markdown")]
+        format: RenderFormat,
+        /// Document template with {{date}}, {{user}}, {{stories}}, {{trends}} and {{blind_spots}} slots
+        #[arg(long)]
+        template: Option<PathBuf>,
+        /// Write to this file instead of stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv().ok();
+
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
+    let database_url = std::env::var("DATABASE_URL").map_err(|_| "DATABASE_URL must be set")?;
+    let backend = match std::env::var("DATABASE_BACKEND") {
+        Ok(backend) => backend.parse()?,
+        Err(_) => DatabaseBackend::from_url(&database_url),
+    };
+    let repos = Repositories::connect(backend, &database_url, 1).await?;
+
+    match cli.command {
+        Command::Briefing {
+            user,
+            date,
+            format,
+            template,
+            output,
+        } => {
+            let article_repo = repos.articles;
+            let topic_repo = repos.topics;
+            let user_repo = repos.users;
+            let briefing_repo = repos.briefings;
+
+            SeedDefaultTopics::new(topic_repo.clone()).execute().await?;
+
+            let generate = GenerateBriefing::new(
+                Arc::new(PersonalizeFeed::new(
+                    article_repo.clone(),
+                    topic_repo.clone(),
+                    user_repo.clone(),
+                    PersonalizationWeights::default(),
+                )),
+                Arc::new(GetBlindSpots::new(
+                    article_repo.clone(),
+                    topic_repo.clone(),
+                    user_repo.clone(),
+                    BlindSpotPolicy::default(),
+                )),
+                article_repo,
+                topic_repo,
+                user_repo,
+                briefing_repo,
+                BriefingLimits::default(),
+            );
+            let briefing = generate
+                .execute(&UserId::from(user.as_str()), date, Utc::now().timestamp())
+                .await?;
+
+            let mut layout = BriefingTemplate::for_format(format);
+            if let Some(path) = template {
+                layout = layout.with_document(std::fs::read_to_string(path)?);
+            }
+            let rendered = render_briefing(&briefing, format, &layout);
+
+            match output {
+                Some(path) => std::fs::write(path, rendered)?,
+                None => print!("{}", rendered),
+            }
+        }
+    }
+
+    Ok(())
+}
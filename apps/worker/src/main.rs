@@ -1,3 +1,180 @@
-fn main() {
-    println!("Worker app placeholder");
+use chrono::Utc;
+use dotenvy::dotenv;
+use std::sync::Arc;
+use std::time::Duration;
+use techpulse_adapter::render::BriefingEmailComposer;
+use techpulse_domain::blindspot::BlindSpotPolicy;
+use techpulse_domain::briefing::BriefingLimits;
+use techpulse_domain::delivery::RetryPolicy;
+use techpulse_domain::personalization::PersonalizationWeights;
+use techpulse_domain::retention::RetentionPolicy;
+use techpulse_infra::email::{SmtpConfig, SmtpEmailGateway, SmtpSecurity};
+use techpulse_infra::repo::backend::{DatabaseBackend, Repositories};
+use techpulse_infra::webhook::HttpWebhookGateway;
+use techpulse_usecase::blindspots::GetBlindSpots;
+use techpulse_usecase::briefing::GenerateBriefing;
+use techpulse_usecase::delivery::DeliverBriefings;
+use techpulse_usecase::feed::PersonalizeFeed;
+use techpulse_usecase::retention::PruneData;
+use techpulse_usecase::topics::SeedDefaultTopics;
+use techpulse_usecase::webhooks::DispatchWebhooks;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+const WEBHOOK_BATCH: usize = 100;
+
+fn env_or(name: &str, default: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| default.to_string())
+}
+
+/// SMTP settings, or None when SMTP_HOST is unset and email delivery is disabled.
+fn smtp_config() -> Option<SmtpConfig> {
+    let host = std::env::var("SMTP_HOST").ok()?;
+    let from = std::env::var("SMTP_FROM").expect("SMTP_FROM must be set when SMTP_HOST is");
+
+    let mut config = SmtpConfig::new(host, from);
+    config.port = env_or("SMTP_PORT", "587").parse().expect("SMTP_PORT must be a port number");
+    config.security = env_or("SMTP_SECURITY", "starttls")
+        .parse::<SmtpSecurity>()
+        .expect("SMTP_SECURITY must be starttls or plain");
+    if let (Ok(username), Ok(password)) = (std::env::var("SMTP_USERNAME"), std::env::var("SMTP_PASSWORD")) {
+        config.credentials = Some((username, password));
+    }
+    Some(config)
+}
+
+#[tokio::main]
+async fn main() {
+    dotenv().ok();
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let backend = std::env::var("DATABASE_BACKEND")
+        .map_or_else(|_| Ok(DatabaseBackend::from_url(&database_url)), |b| b.parse())
+        .expect("DATABASE_BACKEND must be sqlite or postgres");
+    let repos = Repositories::connect(backend, &database_url, 5)
+        .await
+        .expect("Failed to connect to database");
+
+    let interval_secs: u64 = env_or("WORKER_INTERVAL_SECS", "60")
+        .parse()
+        .expect("WORKER_INTERVAL_SECS must be a number of seconds");
+    let webhook_timeout_secs: u64 = env_or("WEBHOOK_TIMEOUT_SECS", "10")
+        .parse()
+        .expect("WEBHOOK_TIMEOUT_SECS must be a number of seconds");
+    let prune_interval_secs: i64 = env_or("PRUNE_INTERVAL_SECS", "86400")
+        .parse()
+        .expect("PRUNE_INTERVAL_SECS must be a number of seconds");
+    let retention = RetentionPolicy::new(
+        env_or("RETAIN_ARTICLES_DAYS", "180")
+            .parse()
+            .expect("RETAIN_ARTICLES_DAYS must be a number of days"),
+        env_or("RETAIN_TREND_REPORTS_DAYS", "730")
+            .parse()
+            .expect("RETAIN_TREND_REPORTS_DAYS must be a number of days"),
+    )
+    .expect("Invalid retention policy");
+
+    // Composition root
+    let article_repo = repos.articles.clone();
+    let topic_repo = repos.topics.clone();
+    let user_repo = repos.users.clone();
+    let briefing_repo = repos.briefings.clone();
+    let delivery_repo = repos.deliveries.clone();
+
+    SeedDefaultTopics::new(topic_repo.clone())
+        .execute()
+        .await
+        .expect("Failed to seed topics");
+
+    let deliver = match smtp_config() {
+        Some(config) => {
+            let email_gateway = Arc::new(SmtpEmailGateway::new(config).expect("Invalid SMTP configuration"));
+            let briefings = Arc::new(GenerateBriefing::new(
+                Arc::new(PersonalizeFeed::new(
+                    article_repo.clone(),
+                    topic_repo.clone(),
+                    user_repo.clone(),
+                    PersonalizationWeights::default(),
+                )),
+                Arc::new(GetBlindSpots::new(
+                    article_repo.clone(),
+                    topic_repo.clone(),
+                    user_repo.clone(),
+                    BlindSpotPolicy::default(),
+                )),
+                article_repo.clone(),
+                topic_repo,
+                user_repo.clone(),
+                briefing_repo,
+                BriefingLimits::default(),
+            ));
+            Some(DeliverBriefings::new(
+                user_repo,
+                briefings,
+                Arc::new(BriefingEmailComposer::default()),
+                email_gateway,
+                delivery_repo,
+                RetryPolicy::default(),
+            ))
+        }
+        None => {
+            tracing::warn!("SMTP_HOST is not set, email delivery of briefings is disabled");
+            None
+        }
+    };
+    let dispatch = DispatchWebhooks::new(
+        repos.webhooks.clone(),
+        Arc::new(
+            HttpWebhookGateway::new(Duration::from_secs(webhook_timeout_secs)).expect("Failed to build HTTP client"),
+        ),
+        RetryPolicy::default(),
+    );
+
+    let prune = PruneData::new(
+        article_repo,
+        repos.snapshots,
+        repos.trends,
+        repos.timeline,
+        repos.interactions,
+        retention,
+    );
+
+    tracing::info!("Worker running, checking for due briefings and webhooks every {}s", interval_secs);
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval_secs));
+    let mut last_pruned: Option<i64> = None;
+    loop {
+        ticker.tick().await;
+        let now = Utc::now().timestamp();
+        if last_pruned.is_none_or(|t| now - t >= prune_interval_secs) {
+            last_pruned = Some(now);
+            match prune.execute(now).await {
+                Ok(report) => tracing::info!(
+                    "Pruned {} articles, {} snapshots and {} trend reports",
+                    report.articles,
+                    report.snapshots,
+                    report.trend_reports
+                ),
+                Err(e) => tracing::error!("Pruning run failed: {}", e),
+            }
+        }
+        if let Some(deliver) = &deliver {
+            match deliver.execute(Utc::now().timestamp()).await {
+                Ok(report) if report.sent + report.failed > 0 => {
+                    tracing::info!("Briefing delivery: {} sent, {} failed", report.sent, report.failed);
+                }
+                Ok(_) => {}
+                Err(e) => tracing::error!("Briefing delivery run failed: {}", e),
+            }
+        }
+        match dispatch.execute(Utc::now().timestamp(), WEBHOOK_BATCH).await {
+            Ok(report) if report.sent + report.failed > 0 => {
+                tracing::info!("Webhook dispatch: {} sent, {} failed", report.sent, report.failed);
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("Webhook dispatch run failed: {}", e),
+        }
+    }
 }
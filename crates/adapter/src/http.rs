@@ -1,32 +1,129 @@
 use axum::{
     Json, Router,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::{get, post},
+    routing::{delete, get, patch, post, put},
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use techpulse_domain::article::Article;
+use techpulse_domain::article::{Article, ArticleId, ArticleSnapshot};
+use techpulse_domain::briefing::{Briefing, BriefingStory};
+use techpulse_domain::cooccurrence::TopicNeighbor;
+use techpulse_domain::delivery::BriefingDelivery;
 use techpulse_domain::error::DomainError;
-use techpulse_domain::trend::TrendReport;
-use techpulse_usecase::feed::GetChronologicalFeed;
+use techpulse_domain::event::{EventCluster, EventLifecycle};
+use techpulse_domain::query::ArticleQuery;
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::{Topic, TopicCategory};
+use techpulse_domain::interaction::{InteractionEvent, InteractionKind};
+use techpulse_domain::trend::{TimelineEvent, TimelineEventId, TrendReport};
+use techpulse_domain::user::{
+    DeliveryPreferencesPatch, KnowledgeState, TopicKnowledge, UserId, UserProfile, UserSettingsPatch,
+};
+use techpulse_domain::mute::{parse_mute_duration, MuteKind, MuteReport, MuteRule, MuteRuleId};
+use techpulse_domain::watchlist::{WatchAlert, WatchRule, WatchRuleId};
+use techpulse_domain::webhook::{WebhookDelivery, WebhookEventType, WebhookId, WebhookSubscription};
+use techpulse_usecase::articles::{ArticleDetail, GetArticleDetail};
+use techpulse_usecase::briefing::{GenerateBriefing, ListBriefings};
+use techpulse_usecase::blindspots::{BlindSpotView, GetBlindSpots};
+use techpulse_usecase::clustering::ClusterStories;
+use techpulse_usecase::cooccurrence::{BuildCooccurrenceGraph, GetTopicNeighborhood};
+use techpulse_usecase::delivery::ListDeliveries;
+use techpulse_usecase::decay::{ApplyKnowledgeDecay, GetRevisitSuggestions, RevisitSuggestion};
+use techpulse_usecase::feed::{GetChronologicalFeed, PersonalizeFeed, RankedArticle};
 use techpulse_usecase::ingest::IngestArticles;
+use techpulse_usecase::interactions::RecordInteractions;
+use techpulse_usecase::search::{SearchArticles, SearchResult};
+use techpulse_usecase::topics::{CategoryRollup, GetCategoryRollup, ListTopics};
+use techpulse_usecase::timeline::{
+    DeleteTimelineEvent, GenerateTimeline, GetTimeline, PinArticle, PinOptions, TimelineEventPatch,
+    TimelineFilter, TimelineMonth, UpdateTimelineEvent,
+};
 use techpulse_usecase::trends::CalculateTrends;
+use techpulse_usecase::users::{GetUserProfile, ListTopicsByState, SetTopicKnowledge, UpdateUserSettings};
+use techpulse_usecase::mutes::{AddMuteRule, ListMuteRules, RemoveMuteRule};
+use techpulse_usecase::watchlist::{AddWatchRule, ListAlerts, ListWatchRules, RemoveWatchRule};
+use techpulse_usecase::webhooks::{CreateWebhook, DeleteWebhook, ListWebhookDeliveries, ListWebhooks};
 
 #[derive(Clone)]
 pub struct AppState {
     pub feed: Arc<GetChronologicalFeed>,
+    pub personalized_feed: Arc<PersonalizeFeed>,
+    pub search: Arc<SearchArticles>,
+    pub article_detail: Arc<GetArticleDetail>,
     pub trends: Arc<CalculateTrends>,
     pub ingest: Arc<IngestArticles>,
+    pub topics: Arc<ListTopics>,
+    pub topic_rollup: Arc<GetCategoryRollup>,
+    pub build_graph: Arc<BuildCooccurrenceGraph>,
+    pub neighborhood: Arc<GetTopicNeighborhood>,
+    pub events: Arc<ClusterStories>,
+    pub timeline: Arc<GetTimeline>,
+    pub pin_timeline: Arc<PinArticle>,
+    pub update_timeline: Arc<UpdateTimelineEvent>,
+    pub delete_timeline: Arc<DeleteTimelineEvent>,
+    pub generate_timeline: Arc<GenerateTimeline>,
+    pub user_profile: Arc<GetUserProfile>,
+    pub set_knowledge: Arc<SetTopicKnowledge>,
+    pub knowledge_by_state: Arc<ListTopicsByState>,
+    pub user_settings: Arc<UpdateUserSettings>,
+    pub blind_spots: Arc<GetBlindSpots>,
+    pub interactions: Arc<RecordInteractions>,
+    pub knowledge_decay: Arc<ApplyKnowledgeDecay>,
+    pub revisit: Arc<GetRevisitSuggestions>,
+    pub briefing: Arc<GenerateBriefing>,
+    pub briefing_archive: Arc<ListBriefings>,
+    pub deliveries: Arc<ListDeliveries>,
+    pub create_webhook: Arc<CreateWebhook>,
+    pub webhooks: Arc<ListWebhooks>,
+    pub delete_webhook: Arc<DeleteWebhook>,
+    pub webhook_deliveries: Arc<ListWebhookDeliveries>,
+    pub add_watch_rule: Arc<AddWatchRule>,
+    pub watch_rules: Arc<ListWatchRules>,
+    pub remove_watch_rule: Arc<RemoveWatchRule>,
+    pub alerts: Arc<ListAlerts>,
+    pub add_mute: Arc<AddMuteRule>,
+    pub mutes: Arc<ListMuteRules>,
+    pub remove_mute: Arc<RemoveMuteRule>,
 }
 
 pub fn routes(state: AppState) -> Router {
     Router::new()
         .route("/health", get(|| async { "OK" }))
         .route("/api/feed", get(get_feed))
+        .route("/api/search", get(search_articles))
+        .route("/api/articles/:id", get(get_article))
         .route("/api/ingest", post(ingest_articles))
         .route("/api/trends/calculate", post(calculate_trends))
+        .route("/api/topics", get(list_topics))
+        .route("/api/topics/categories", get(topic_categories))
+        .route("/api/topics/graph", post(build_topic_graph))
+        .route("/api/topics/:topic/neighbors", get(topic_neighbors))
+        .route("/api/events", get(list_events))
+        .route("/api/timeline", get(get_timeline).post(pin_to_timeline))
+        .route("/api/timeline/generate", post(generate_timeline))
+        .route("/api/timeline/:id", patch(update_timeline_event).delete(delete_timeline_event))
+        .route("/api/users/:id", get(get_user_profile))
+        .route("/api/users/:id/knowledge", get(list_user_knowledge))
+        .route("/api/users/:id/knowledge/:topic", put(set_user_knowledge))
+        .route("/api/users/:id/settings", put(update_user_settings))
+        .route("/api/users/:id/knowledge-decay", post(apply_knowledge_decay))
+        .route("/api/users/:id/revisit", get(get_revisit_suggestions))
+        .route("/api/users/:id/blind-spots", get(get_blind_spots))
+        .route("/api/users/:id/deliveries", get(list_deliveries))
+        .route("/api/users/:id/watchlist", get(list_watch_rules).post(add_watch_rule))
+        .route("/api/users/:id/watchlist/:rule", delete(remove_watch_rule))
+        .route("/api/users/:id/alerts", get(list_alerts))
+        .route("/api/users/:id/mutes", get(list_mutes).post(add_mute))
+        .route("/api/users/:id/mutes/:rule", delete(remove_mute))
+        .route("/api/interactions", post(record_interactions))
+        .route("/api/briefing", get(get_briefing))
+        .route("/api/briefing/archive", get(briefing_archive))
+        .route("/api/webhooks", get(list_webhooks).post(create_webhook))
+        .route("/api/webhooks/:id", delete(delete_webhook))
+        .route("/api/webhooks/:id/deliveries", get(list_webhook_deliveries))
         .with_state(state)
 }
 
@@ -34,6 +131,33 @@ pub fn routes(state: AppState) -> Router {
 pub struct FeedQuery {
     #[serde(default = "default_limit")]
     limit: usize,
+    user_id: Option<String>, // Ranks the feed for this user instead of chronologically
+    source: Option<String>,  // Comma-separated, e.g. "hn,rd-rust"
+    tag: Option<String>,     // Comma-separated, all required
+    since: Option<i64>,
+    until: Option<i64>,
+    min_score: Option<f64>,
+    domain: Option<String>,
+    q: Option<String>,      // Title text
+    cursor: Option<String>, // `next_cursor` of the previous page, chronological feed only
+}
+
+impl FeedQuery {
+    fn to_query(&self) -> Result<ArticleQuery, DomainError> {
+        let list = |value: &Option<String>| -> Vec<String> {
+            value.as_deref().map_or_else(Vec::new, |v| v.split(',').map(str::to_string).collect())
+        };
+        let mut query = ArticleQuery::latest(self.limit.clamp(1, 100));
+        query.sources = list(&self.source);
+        query.tags = list(&self.tag);
+        query.since = self.since;
+        query.until = self.until;
+        query.min_score = self.min_score;
+        query.domain = self.domain.clone();
+        query.text = self.q.clone();
+        query.cursor = self.cursor.as_deref().filter(|c| !c.is_empty()).map(str::parse).transpose()?;
+        Ok(query)
+    }
 }
 
 fn default_limit() -> usize {
@@ -43,6 +167,36 @@ fn default_limit() -> usize {
 #[derive(Serialize, Deserialize)]
 pub struct FeedResponse {
     pub articles: Vec<ArticleDto>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<MutedDto>, // Only in personalized feeds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>, // Only in chronological feeds with more pages
+}
+
+/// What the user's mute rules hid from a response.
+#[derive(Serialize, Deserialize)]
+pub struct MutedDto {
+    pub hidden: usize,
+    pub by_rule: Vec<MutedCountDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MutedCountDto {
+    pub rule_id: String,
+    pub hidden: usize,
+}
+
+impl From<MuteReport> for MutedDto {
+    fn from(r: MuteReport) -> Self {
+        Self {
+            hidden: r.hidden,
+            by_rule: r
+                .by_rule
+                .into_iter()
+                .map(|(id, hidden)| MutedCountDto { rule_id: id.to_string(), hidden })
+                .collect(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -52,6 +206,10 @@ pub struct ArticleDto {
     pub url: String,
     pub source: String,
     pub timestamp: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>, // Personalized ranking score, only in ranked feeds
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub watch_matches: Vec<String>, // Watch rules of the requesting user matching the article
 }
 
 impl From<Article> for ArticleDto {
@@ -62,10 +220,29 @@ impl From<Article> for ArticleDto {
             url: a.url,
             source: a.source.to_string(),
             timestamp: a.timestamp,
+            score: None,
+            watch_matches: Vec::new(),
+        }
+    }
+}
+
+impl From<RankedArticle> for ArticleDto {
+    fn from(r: RankedArticle) -> Self {
+        Self {
+            score: Some(r.score),
+            watch_matches: r.watch_matches,
+            ..ArticleDto::from(r.article)
         }
     }
 }
 
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
 pub struct ApiError(DomainError);
 
 impl IntoResponse for ApiError {
@@ -104,10 +281,146 @@ async fn get_feed(
     State(state): State<AppState>,
     Query(params): Query<FeedQuery>,
 ) -> Result<Json<FeedResponse>, ApiError> {
-    let limit = params.limit.min(100).max(1);
-    let articles = state.feed.execute(limit).await?;
-    Ok(Json(FeedResponse {
-        articles: articles.into_iter().map(ArticleDto::from).collect(),
+    let query = params.to_query()?;
+    match params.user_id.filter(|id| !id.is_empty()) {
+        Some(user_id) => {
+            let feed = state
+                .personalized_feed
+                .execute(&UserId::from(user_id.as_str()), query, unix_now())
+                .await?;
+            Ok(Json(FeedResponse {
+                articles: feed.articles.into_iter().map(ArticleDto::from).collect(),
+                muted: Some(MutedDto::from(feed.muted)),
+                next_cursor: None,
+            }))
+        }
+        None => {
+            let page = state.feed.execute(query).await?;
+            Ok(Json(FeedResponse {
+                articles: page.articles.into_iter().map(ArticleDto::from).collect(),
+                muted: None,
+                next_cursor: page.next_cursor.map(|c| c.to_string()),
+            }))
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ArticleDetailDto {
+    pub id: String,
+    pub title: String,
+    pub url: String,
+    pub source: String,
+    pub author: String,
+    pub timestamp: i64,
+    pub tags: Vec<String>,
+    pub comment_count: u32,
+    pub is_hot_on_source: bool,
+    pub source_score: f64, // Normalized 0-100, as reported by the source
+    pub score: f64,        // Decayed ranking score at request time
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    pub first_seen_at: i64,
+    pub last_seen_at: i64,
+    pub times_seen: u32,
+    pub history: Vec<SnapshotDto>, // Oldest first
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SnapshotDto {
+    pub captured_at: i64,
+    pub score: f64,
+    pub comment_count: u32,
+    pub is_hot_on_source: bool,
+}
+
+impl From<ArticleSnapshot> for SnapshotDto {
+    fn from(s: ArticleSnapshot) -> Self {
+        Self {
+            captured_at: s.captured_at,
+            score: s.score,
+            comment_count: s.comment_count,
+            is_hot_on_source: s.is_hot_on_source,
+        }
+    }
+}
+
+impl From<ArticleDetail> for ArticleDetailDto {
+    fn from(d: ArticleDetail) -> Self {
+        let a = d.article;
+        let mut tags: Vec<String> = a.tags.into_iter().collect();
+        tags.sort();
+        Self {
+            id: a.id.to_string(),
+            title: a.title,
+            url: a.url,
+            source: a.source.to_string(),
+            author: a.author,
+            timestamp: a.timestamp,
+            tags,
+            comment_count: a.comment_count,
+            is_hot_on_source: a.is_hot_on_source,
+            source_score: a.score,
+            score: d.score,
+            text: a.text,
+            first_seen_at: a.first_seen_at,
+            last_seen_at: a.last_seen_at,
+            times_seen: a.times_seen,
+            history: d.history.into_iter().map(SnapshotDto::from).collect(),
+        }
+    }
+}
+
+async fn get_article(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ArticleDetailDto>, ApiError> {
+    let id = ArticleId::parse(&id)?;
+    let detail = state.article_detail.execute(&id, unix_now()).await?;
+    Ok(Json(ArticleDetailDto::from(detail)))
+}
+
+#[derive(Deserialize)]
+pub struct SearchParams {
+    q: String, // Words, `prefix*` and `"quoted phrases"`, all required
+    #[serde(default = "default_limit")]
+    limit: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchResponse {
+    pub results: Vec<SearchResultDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchResultDto {
+    pub article: ArticleDto,
+    pub snippet: String, // HTML-escaped, matches wrapped in <mark> tags
+    pub relevance: f64,
+    pub score: f64, // Relevance blended with recency
+}
+
+impl From<SearchResult> for SearchResultDto {
+    fn from(r: SearchResult) -> Self {
+        Self {
+            article: ArticleDto::from(r.article),
+            snippet: r.snippet,
+            relevance: r.relevance,
+            score: r.score,
+        }
+    }
+}
+
+async fn search_articles(
+    State(state): State<AppState>,
+    Query(params): Query<SearchParams>,
+) -> Result<Json<SearchResponse>, ApiError> {
+    let results = state
+        .search
+        .execute(&params.q, params.limit.clamp(1, 50), unix_now())
+        .await?;
+    Ok(Json(SearchResponse {
+        results: results.into_iter().map(SearchResultDto::from).collect(),
     }))
 }
 
@@ -150,11 +463,7 @@ async fn calculate_trends(
     body: Option<Json<TrendsRequest>>,
 ) -> Result<Json<TrendsResponse>, ApiError> {
     let request = body.map(|b| b.0).unwrap_or_default();
-    
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_secs() as i64;
+    let now = unix_now();
 
     let report: TrendReport = state.trends.execute(&request.keywords, now).await?;
 
@@ -182,118 +491,2184 @@ fn default_ingest_limit() -> usize {
     30
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 pub struct IngestResponse {
     pub ingested: usize,
+    pub new: usize,
+    pub updated: usize, // Already stored; engagement refreshed
 }
 
 async fn ingest_articles(
     State(state): State<AppState>,
     body: Option<Json<IngestRequest>>,
 ) -> Result<Json<IngestResponse>, ApiError> {
-    let limit = body.map(|b| b.0.limit).unwrap_or(default_ingest_limit()).min(50).max(1);
-    let count = state.ingest.execute(limit).await?;
-    Ok(Json(IngestResponse { ingested: count }))
+    let limit = body.map(|b| b.0.limit).unwrap_or(default_ingest_limit()).clamp(1, 50);
+    let report = state.ingest.execute(limit, unix_now()).await?;
+    Ok(Json(IngestResponse {
+        ingested: report.total(),
+        new: report.new,
+        updated: report.updated,
+    }))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use axum::body::Body;
-    use axum::http::Request;
-    use http_body_util::BodyExt;
-    use tower::ServiceExt;
-    use techpulse_infra::repo::mem::{InMemoryArticleRepo, InMemoryTrendRepo};
+#[derive(Deserialize)]
+pub struct TopicsQuery {
+    pub category: Option<String>,
+}
 
-    use techpulse_domain::article::Article;
-    use techpulse_domain::error::DomainError;
-    use techpulse_domain::gateway::ArticleGateway;
-    use techpulse_usecase::ingest::IngestArticles;
-    use async_trait::async_trait;
+#[derive(Serialize, Deserialize)]
+pub struct TopicsResponse {
+    pub topics: Vec<TopicDto>,
+}
 
-    struct StubGateway;
-    #[async_trait]
-    impl ArticleGateway for StubGateway {
-        async fn fetch_top_articles(&self, _limit: usize) -> Result<Vec<Article>, DomainError> {
-            Ok(vec![])
+#[derive(Serialize, Deserialize)]
+pub struct TopicDto {
+    pub slug: String,
+    pub display_name: String,
+    pub aliases: Vec<String>,
+    pub category: String,
+}
+
+impl From<Topic> for TopicDto {
+    fn from(t: Topic) -> Self {
+        Self {
+            slug: t.slug.to_string(),
+            display_name: t.display_name,
+            aliases: t.aliases,
+            category: t.category.to_string(),
         }
     }
+}
 
-    fn test_state() -> AppState {
-        let article_repo = Arc::new(InMemoryArticleRepo::new());
-        let trend_repo = Arc::new(InMemoryTrendRepo::new());
-        let gateway = Arc::new(StubGateway);
-        
-        AppState {
-            feed: Arc::new(GetChronologicalFeed::new(article_repo.clone())),
-            trends: Arc::new(CalculateTrends::new(article_repo.clone(), trend_repo)),
-            ingest: Arc::new(IngestArticles::new(gateway, article_repo)),
+async fn list_topics(
+    State(state): State<AppState>,
+    Query(params): Query<TopicsQuery>,
+) -> Result<Json<TopicsResponse>, ApiError> {
+    let category = params
+        .category
+        .map(|c| c.parse::<TopicCategory>())
+        .transpose()?;
+    let topics = state.topics.execute(category).await?;
+    Ok(Json(TopicsResponse {
+        topics: topics.into_iter().map(TopicDto::from).collect(),
+    }))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CategoriesResponse {
+    pub categories: Vec<CategoryDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CategoryDto {
+    pub category: String,
+    pub score: f64,
+    pub volume: u32,
+    pub topics: Vec<String>,
+}
+
+impl From<CategoryRollup> for CategoryDto {
+    fn from(r: CategoryRollup) -> Self {
+        Self {
+            category: r.category.to_string(),
+            score: r.score,
+            volume: r.volume,
+            topics: r.topics,
         }
     }
+}
 
-    #[tokio::test]
-    async fn test_health_endpoint() {
-        let app = routes(test_state());
-        let response = app
-            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
-            .await
-            .unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
+async fn topic_categories(
+    State(state): State<AppState>,
+) -> Result<Json<CategoriesResponse>, ApiError> {
+    let rollups = state.topic_rollup.execute().await?;
+    Ok(Json(CategoriesResponse {
+        categories: rollups.into_iter().map(CategoryDto::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct GraphRequest {
+    #[serde(default = "default_graph_hours")]
+    pub hours: i64,
+    #[serde(default = "default_min_count")]
+    pub min_count: u32,
+}
+
+impl Default for GraphRequest {
+    fn default() -> Self {
+        Self {
+            hours: default_graph_hours(),
+            min_count: default_min_count(),
+        }
     }
+}
 
-    #[tokio::test]
-    async fn test_feed_endpoint() {
-        let app = routes(test_state());
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .uri("/api/feed?limit=5")
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
-        
-        let body = response.into_body().collect().await.unwrap().to_bytes();
-        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
-        assert!(feed.articles.is_empty()); // Empty in-memory repo
+fn default_graph_hours() -> i64 {
+    24 * 7
+}
+
+// Graph windows end on the hour, so rebuilds within the same hour replace one graph
+const GRAPH_PERIOD_STEP_SECS: i64 = 3600;
+
+fn graph_period(as_of: i64, hours: i64) -> Result<TimeWindow, DomainError> {
+    TimeWindow::aligned_ending_at(as_of, hours.clamp(1, 24 * 90) * 3600, GRAPH_PERIOD_STEP_SECS)
+}
+
+fn default_min_count() -> u32 {
+    2
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GraphResponse {
+    pub period_start: i64,
+    pub period_end: i64,
+    pub document_count: u32,
+    pub edges: usize,
+}
+
+async fn build_topic_graph(
+    State(state): State<AppState>,
+    body: Option<Json<GraphRequest>>,
+) -> Result<Json<GraphResponse>, ApiError> {
+    let request = body.map(|b| b.0).unwrap_or_default();
+    let window = graph_period(unix_now(), request.hours)?;
+
+    let graph = state.build_graph.execute(window, request.min_count).await?;
+    Ok(Json(GraphResponse {
+        period_start: graph.window.start,
+        period_end: graph.window.end,
+        document_count: graph.document_count,
+        edges: graph.edges.len(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct NeighborsQuery {
+    #[serde(default = "default_neighbors_limit")]
+    pub limit: usize,
+    pub as_of: Option<i64>, // End of the graph period; the latest graph when unset
+    #[serde(default = "default_graph_hours")]
+    pub hours: i64,
+}
+
+fn default_neighbors_limit() -> usize {
+    10
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct NeighborsResponse {
+    pub topic: String,
+    pub neighbors: Vec<NeighborDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct NeighborDto {
+    pub topic: String,
+    pub count: u32,
+    pub pmi: f64,
+}
+
+impl From<TopicNeighbor> for NeighborDto {
+    fn from(n: TopicNeighbor) -> Self {
+        Self {
+            topic: n.topic,
+            count: n.count,
+            pmi: n.pmi,
+        }
     }
+}
 
-    #[tokio::test]
-    async fn test_trends_endpoint_with_body() {
-        let app = routes(test_state());
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/trends/calculate")
-                    .header("content-type", "application/json")
-                    .body(Body::from(r#"{"keywords":["Rust"]}"#))
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
-        
-        let body = response.into_body().collect().await.unwrap().to_bytes();
-        let trends: TrendsResponse = serde_json::from_slice(&body).unwrap();
-        assert!(trends.trends.is_empty()); // No articles to match
+async fn topic_neighbors(
+    State(state): State<AppState>,
+    Path(topic): Path<String>,
+    Query(params): Query<NeighborsQuery>,
+) -> Result<Json<NeighborsResponse>, ApiError> {
+    let limit = params.limit.clamp(1, 50);
+    let period = params.as_of.map(|as_of| graph_period(as_of, params.hours)).transpose()?;
+    let neighbors = state.neighborhood.execute(&topic, period, limit).await?;
+    Ok(Json(NeighborsResponse {
+        topic,
+        neighbors: neighbors.into_iter().map(NeighborDto::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct EventsQuery {
+    #[serde(default = "default_event_hours")]
+    pub hours: i64,
+    #[serde(default = "default_min_articles")]
+    pub min_articles: usize,
+}
+
+fn default_event_hours() -> i64 {
+    72
+}
+
+fn default_min_articles() -> usize {
+    2
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EventsResponse {
+    pub events: Vec<EventDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EventDto {
+    pub id: String,
+    pub headline: String,
+    pub representative: String,
+    pub url: String,
+    pub articles: Vec<String>,
+    pub sources: Vec<String>,
+    pub topics: Vec<String>,
+    pub first_seen: i64,
+    pub last_seen: i64,
+    pub score: f64,
+    pub lifecycle: String,
+}
+
+impl From<EventCluster> for EventDto {
+    fn from(e: EventCluster) -> Self {
+        let lifecycle = match e.lifecycle {
+            EventLifecycle::Emerging => "emerging",
+            EventLifecycle::Peaking => "peaking",
+            EventLifecycle::Fading => "fading",
+        };
+        Self {
+            id: e.id.to_string(),
+            headline: e.headline,
+            representative: e.representative.to_string(),
+            url: e.url,
+            articles: e.articles.iter().map(|id| id.to_string()).collect(),
+            sources: e.sources,
+            topics: e.topics.into_iter().collect(),
+            first_seen: e.first_seen,
+            last_seen: e.last_seen,
+            score: e.score,
+            lifecycle: lifecycle.to_string(),
+        }
     }
-    
-    #[tokio::test]
-    async fn test_trends_endpoint_without_body() {
-        let app = routes(test_state());
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .method("POST")
-                    .uri("/api/trends/calculate")
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
+}
+
+async fn list_events(
+    State(state): State<AppState>,
+    Query(params): Query<EventsQuery>,
+) -> Result<Json<EventsResponse>, ApiError> {
+    let now = unix_now();
+    let window = TimeWindow::ending_at(now, params.hours.clamp(1, 24 * 30) * 3600)?;
+    let clusters = state.events.execute(window, now).await?;
+    Ok(Json(EventsResponse {
+        events: clusters
+            .into_iter()
+            .filter(|c| c.articles.len() >= params.min_articles.max(1))
+            .map(EventDto::from)
+            .collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct TimelineQuery {
+    pub category: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TimelineResponse {
+    pub months: Vec<TimelineMonthDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TimelineMonthDto {
+    pub year: i32,
+    pub month: u32,
+    pub events: Vec<TimelineEventDto>,
+}
+
+impl From<TimelineMonth> for TimelineMonthDto {
+    fn from(m: TimelineMonth) -> Self {
+        Self {
+            year: m.year,
+            month: m.month,
+            events: m.events.into_iter().map(TimelineEventDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TimelineEventDto {
+    pub id: String,
+    pub title: String,
+    pub date: NaiveDate,
+    pub description: String,
+    pub category: String,
+    pub importance_score: f64,
+    pub url: Option<String>,
+    pub article_id: Option<String>,
+}
+
+impl From<TimelineEvent> for TimelineEventDto {
+    fn from(e: TimelineEvent) -> Self {
+        Self {
+            id: e.id.to_string(),
+            title: e.title,
+            date: e.date,
+            description: e.description,
+            category: e.category,
+            importance_score: e.importance_score,
+            url: e.url,
+            article_id: e.article_id.map(|id| id.to_string()),
+        }
+    }
+}
+
+async fn get_timeline(
+    State(state): State<AppState>,
+    Query(params): Query<TimelineQuery>,
+) -> Result<Json<TimelineResponse>, ApiError> {
+    let filter = TimelineFilter {
+        category: params.category.filter(|c| !c.is_empty()),
+        from: params.from,
+        to: params.to,
+    };
+    let months = state.timeline.execute(&filter).await?;
+    Ok(Json(TimelineResponse {
+        months: months.into_iter().map(TimelineMonthDto::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct PinRequest {
+    pub article_id: String,
+    pub category: Option<String>,
+    pub description: Option<String>,
+    pub importance_score: Option<f64>,
+}
+
+async fn pin_to_timeline(
+    State(state): State<AppState>,
+    Json(request): Json<PinRequest>,
+) -> Result<(StatusCode, Json<TimelineEventDto>), ApiError> {
+    let article_id = ArticleId::parse(&request.article_id)?;
+    let options = PinOptions {
+        category: request.category,
+        description: request.description,
+        importance_score: request.importance_score,
+    };
+    let event = state.pin_timeline.execute(&article_id, options).await?;
+    Ok((StatusCode::CREATED, Json(TimelineEventDto::from(event))))
+}
+
+#[derive(Deserialize)]
+pub struct UpdateTimelineRequest {
+    pub title: Option<String>,
+    pub date: Option<NaiveDate>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub importance_score: Option<f64>,
+    pub url: Option<String>,
+}
+
+async fn update_timeline_event(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateTimelineRequest>,
+) -> Result<Json<TimelineEventDto>, ApiError> {
+    let patch = TimelineEventPatch {
+        title: request.title,
+        date: request.date,
+        description: request.description,
+        category: request.category,
+        importance_score: request.importance_score,
+        url: request.url,
+    };
+    let event = state
+        .update_timeline
+        .execute(&TimelineEventId::from(id.as_str()), patch)
+        .await?;
+    Ok(Json(TimelineEventDto::from(event)))
+}
+
+async fn delete_timeline_event(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .delete_timeline
+        .execute(&TimelineEventId::from(id.as_str()))
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Deserialize)]
+pub struct GenerateTimelineRequest {
+    #[serde(default = "default_event_hours")]
+    pub hours: i64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct GenerateTimelineResponse {
+    pub created: Vec<TimelineEventDto>,
+}
+
+async fn generate_timeline(
+    State(state): State<AppState>,
+    body: Option<Json<GenerateTimelineRequest>>,
+) -> Result<Json<GenerateTimelineResponse>, ApiError> {
+    let hours = body.map(|b| b.0.hours).unwrap_or(default_event_hours()).clamp(1, 24 * 30);
+    let now = unix_now();
+    let window = TimeWindow::ending_at(now, hours * 3600)?;
+    let created = state.generate_timeline.execute(window, now).await?;
+    Ok(Json(GenerateTimelineResponse {
+        created: created.into_iter().map(TimelineEventDto::from).collect(),
+    }))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TopicKnowledgeDto {
+    pub topic: String,
+    pub state: String,
+    pub first_seen: i64,
+    pub last_interaction: i64,
+    pub interaction_count: u32,
+}
+
+impl From<TopicKnowledge> for TopicKnowledgeDto {
+    fn from(k: TopicKnowledge) -> Self {
+        Self {
+            topic: k.topic,
+            state: k.state.to_string(),
+            first_seen: k.first_seen,
+            last_interaction: k.last_interaction,
+            interaction_count: k.interaction_count,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserSettingsDto {
+    #[serde(default)]
+    pub preferred_sources: Vec<String>,
+    #[serde(default)]
+    pub theme: String,
+    #[serde(default)]
+    pub time_zone: String,
+    #[serde(default)]
+    pub delivery: DeliveryPreferencesDto,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct DeliveryPreferencesDto {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub send_at: String,
+    #[serde(default)]
+    pub format: String,
+}
+
+/// Settings update; omitted fields keep their stored value.
+#[derive(Serialize, Deserialize, Default)]
+pub struct UpdateUserSettingsRequest {
+    pub preferred_sources: Option<Vec<String>>,
+    pub theme: Option<String>,
+    pub time_zone: Option<String>,
+    #[serde(default)]
+    pub delivery: UpdateDeliveryRequest,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct UpdateDeliveryRequest {
+    pub enabled: Option<bool>,
+    pub email: Option<String>,
+    pub send_at: Option<String>,
+    pub format: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserProfileDto {
+    pub id: String,
+    pub settings: UserSettingsDto,
+    pub knowledge: Vec<TopicKnowledgeDto>, // Sorted by topic
+    #[serde(default)]
+    pub watchlist: Vec<WatchRuleDto>,
+}
+
+impl From<UserProfile> for UserProfileDto {
+    fn from(p: UserProfile) -> Self {
+        let mut knowledge: Vec<TopicKnowledgeDto> = p
+            .knowledge
+            .topics
+            .into_values()
+            .map(TopicKnowledgeDto::from)
+            .collect();
+        knowledge.sort_by(|a, b| a.topic.cmp(&b.topic));
+        Self {
+            id: p.id.to_string(),
+            settings: UserSettingsDto {
+                preferred_sources: p.settings.preferred_sources,
+                theme: p.settings.theme,
+                time_zone: p.settings.time_zone,
+                delivery: DeliveryPreferencesDto {
+                    enabled: p.settings.delivery.enabled,
+                    email: p.settings.delivery.email,
+                    send_at: p.settings.delivery.send_at,
+                    format: p.settings.delivery.format,
+                },
+            },
+            knowledge,
+            watchlist: p.watchlist.into_iter().map(WatchRuleDto::from).collect(),
+        }
+    }
+}
+
+async fn get_user_profile(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<UserProfileDto>, ApiError> {
+    let profile = state.user_profile.execute(&UserId::from(id.as_str())).await?;
+    Ok(Json(UserProfileDto::from(profile)))
+}
+
+#[derive(Deserialize)]
+pub struct KnowledgeQuery {
+    pub state: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct KnowledgeResponse {
+    pub topics: Vec<TopicKnowledgeDto>,
+}
+
+async fn list_user_knowledge(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<KnowledgeQuery>,
+) -> Result<Json<KnowledgeResponse>, ApiError> {
+    let filter = match params.state.as_deref() {
+        Some(s) if !s.is_empty() => Some(s.parse::<KnowledgeState>()?),
+        _ => None,
+    };
+    let topics = state
+        .knowledge_by_state
+        .execute(&UserId::from(id.as_str()), filter)
+        .await?;
+    Ok(Json(KnowledgeResponse {
+        topics: topics.into_iter().map(TopicKnowledgeDto::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct SetKnowledgeRequest {
+    pub state: String,
+}
+
+async fn set_user_knowledge(
+    State(state): State<AppState>,
+    Path((id, topic)): Path<(String, String)>,
+    Json(request): Json<SetKnowledgeRequest>,
+) -> Result<Json<TopicKnowledgeDto>, ApiError> {
+    let knowledge_state = request.state.parse::<KnowledgeState>()?;
+    let entry = state
+        .set_knowledge
+        .execute(&UserId::from(id.as_str()), &topic, knowledge_state, unix_now())
+        .await?;
+    Ok(Json(TopicKnowledgeDto::from(entry)))
+}
+
+async fn update_user_settings(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateUserSettingsRequest>,
+) -> Result<Json<UserProfileDto>, ApiError> {
+    let patch = UserSettingsPatch {
+        preferred_sources: request.preferred_sources,
+        theme: request.theme,
+        time_zone: request.time_zone,
+        delivery: DeliveryPreferencesPatch {
+            enabled: request.delivery.enabled,
+            email: request.delivery.email,
+            send_at: request.delivery.send_at,
+            format: request.delivery.format,
+        },
+    };
+    let profile = state
+        .user_settings
+        .execute(&UserId::from(id.as_str()), patch)
+        .await?;
+    Ok(Json(UserProfileDto::from(profile)))
+}
+
+#[derive(Deserialize)]
+pub struct BlindSpotsQuery {
+    #[serde(default = "default_blind_spot_hours")]
+    pub hours: i64,
+    #[serde(default = "default_blind_spot_limit")]
+    pub limit: usize,
+    #[serde(default = "default_blind_spot_samples")]
+    pub samples: usize,
+}
+
+fn default_blind_spot_hours() -> i64 {
+    24
+}
+
+fn default_blind_spot_limit() -> usize {
+    5
+}
+
+fn default_blind_spot_samples() -> usize {
+    3
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BlindSpotDto {
+    pub topic: String,
+    pub state: Option<String>, // Null if the user never interacted with the topic
+    pub volume: u32,
+    pub velocity: f64,
+    pub severity: f64,
+    pub samples: Vec<ArticleDto>,
+}
+
+impl From<BlindSpotView> for BlindSpotDto {
+    fn from(v: BlindSpotView) -> Self {
+        Self {
+            topic: v.blind_spot.topic,
+            state: v.blind_spot.state.map(|s| s.to_string()),
+            volume: v.blind_spot.volume,
+            velocity: v.blind_spot.velocity,
+            severity: v.blind_spot.severity,
+            samples: v.samples.into_iter().map(ArticleDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BlindSpotsResponse {
+    pub blind_spots: Vec<BlindSpotDto>,
+}
+
+async fn get_blind_spots(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<BlindSpotsQuery>,
+) -> Result<Json<BlindSpotsResponse>, ApiError> {
+    let now = unix_now();
+    let window = TimeWindow::ending_at(now, params.hours.clamp(1, 24 * 30) * 3600)?;
+    let spots = state
+        .blind_spots
+        .execute(
+            &UserId::from(id.as_str()),
+            window,
+            now,
+            params.limit.clamp(1, 50),
+            params.samples.clamp(0, 10),
+        )
+        .await?;
+    Ok(Json(BlindSpotsResponse {
+        blind_spots: spots.into_iter().map(BlindSpotDto::from).collect(),
+    }))
+}
+
+fn default_review_hours() -> i64 {
+    24 * 7
+}
+
+#[derive(Deserialize)]
+pub struct DecayRequest {
+    #[serde(default = "default_review_hours")]
+    pub hours: i64, // Window used to measure topic velocity
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DecayResponse {
+    pub downgraded: Vec<TopicKnowledgeDto>,
+}
+
+async fn apply_knowledge_decay(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    body: Option<Json<DecayRequest>>,
+) -> Result<Json<DecayResponse>, ApiError> {
+    let hours = body.map(|b| b.0.hours).unwrap_or(default_review_hours()).clamp(1, 24 * 90);
+    let now = unix_now();
+    let window = TimeWindow::ending_at(now, hours * 3600)?;
+    let downgraded = state
+        .knowledge_decay
+        .execute(&UserId::from(id.as_str()), window, now)
+        .await?;
+    Ok(Json(DecayResponse {
+        downgraded: downgraded.into_iter().map(TopicKnowledgeDto::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct RevisitQuery {
+    #[serde(default = "default_review_hours")]
+    pub hours: i64,
+    #[serde(default = "default_blind_spot_limit")]
+    pub limit: usize,
+    #[serde(default = "default_blind_spot_samples")]
+    pub articles: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RevisitDto {
+    pub topic: String,
+    pub confidence: f64,
+    pub last_interaction: i64,
+    pub velocity: f64,
+    pub articles: Vec<ArticleDto>,
+}
+
+impl From<RevisitSuggestion> for RevisitDto {
+    fn from(s: RevisitSuggestion) -> Self {
+        Self {
+            topic: s.topic,
+            confidence: s.confidence,
+            last_interaction: s.last_interaction,
+            velocity: s.velocity,
+            articles: s.articles.into_iter().map(ArticleDto::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RevisitResponse {
+    pub suggestions: Vec<RevisitDto>,
+}
+
+async fn get_revisit_suggestions(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<RevisitQuery>,
+) -> Result<Json<RevisitResponse>, ApiError> {
+    let now = unix_now();
+    let window = TimeWindow::ending_at(now, params.hours.clamp(1, 24 * 90) * 3600)?;
+    let suggestions = state
+        .revisit
+        .execute(
+            &UserId::from(id.as_str()),
+            window,
+            now,
+            params.limit.clamp(1, 50),
+            params.articles.clamp(1, 10),
+        )
+        .await?;
+    Ok(Json(RevisitResponse {
+        suggestions: suggestions.into_iter().map(RevisitDto::from).collect(),
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct InteractionDto {
+    pub article_id: String,
+    pub kind: String,
+    pub dwell_secs: Option<u32>,
+    pub timestamp: Option<i64>, // Defaults to the time the batch is received
+}
+
+#[derive(Deserialize)]
+pub struct InteractionBatchRequest {
+    pub user_id: String,
+    pub events: Vec<InteractionDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InteractionBatchResponse {
+    pub recorded: usize,
+    pub profiles_updated: usize,
+}
+
+async fn record_interactions(
+    State(state): State<AppState>,
+    Json(request): Json<InteractionBatchRequest>,
+) -> Result<Json<InteractionBatchResponse>, ApiError> {
+    let now = unix_now();
+    let user_id = UserId::from(request.user_id.as_str());
+    let events = request
+        .events
+        .iter()
+        .map(|e| {
+            Ok(InteractionEvent {
+                user_id: user_id.clone(),
+                article_id: ArticleId::parse(&e.article_id)?,
+                kind: InteractionKind::from_parts(&e.kind, e.dwell_secs)?,
+                timestamp: e.timestamp.unwrap_or(now),
+            })
+        })
+        .collect::<Result<Vec<_>, DomainError>>()?;
+
+    let result = state.interactions.execute(&events).await?;
+    Ok(Json(InteractionBatchResponse {
+        recorded: result.recorded,
+        profiles_updated: result.profiles_updated,
+    }))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BriefingStoryDto {
+    pub article_id: String,
+    pub title: String,
+    pub url: String,
+    pub source: String,
+    pub score: f64,
+}
+
+impl From<BriefingStory> for BriefingStoryDto {
+    fn from(s: BriefingStory) -> Self {
+        Self {
+            article_id: s.article_id.to_string(),
+            title: s.title,
+            url: s.url,
+            source: s.source,
+            score: s.score,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BriefingTrendDto {
+    pub topic: String,
+    pub volume: u32,
+    pub velocity: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BriefingNudgeDto {
+    pub topic: String,
+    pub severity: f64,
+    pub story: Option<BriefingStoryDto>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BriefingDto {
+    pub user_id: String,
+    pub date: NaiveDate,
+    pub generated_at: i64,
+    pub stories: Vec<BriefingStoryDto>,
+    pub trends: Vec<BriefingTrendDto>,
+    pub nudges: Vec<BriefingNudgeDto>,
+    pub muted: usize, // Stories hidden by mute rules
+}
+
+impl From<Briefing> for BriefingDto {
+    fn from(b: Briefing) -> Self {
+        Self {
+            user_id: b.user_id.to_string(),
+            date: b.date,
+            generated_at: b.generated_at,
+            stories: b.stories.into_iter().map(BriefingStoryDto::from).collect(),
+            trends: b
+                .trends
+                .into_iter()
+                .map(|t| BriefingTrendDto {
+                    topic: t.topic,
+                    volume: t.volume,
+                    velocity: t.velocity,
+                })
+                .collect(),
+            nudges: b
+                .nudges
+                .into_iter()
+                .map(|n| BriefingNudgeDto {
+                    topic: n.topic,
+                    severity: n.severity,
+                    story: n.story.map(BriefingStoryDto::from),
+                })
+                .collect(),
+            muted: b.muted,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct BriefingQuery {
+    pub user_id: String,
+    pub date: Option<NaiveDate>, // Defaults to today (UTC)
+}
+
+async fn get_briefing(
+    State(state): State<AppState>,
+    Query(params): Query<BriefingQuery>,
+) -> Result<Json<BriefingDto>, ApiError> {
+    let briefing = state
+        .briefing
+        .execute(&UserId::from(params.user_id.as_str()), params.date, unix_now())
+        .await?;
+    Ok(Json(BriefingDto::from(briefing)))
+}
+
+#[derive(Deserialize)]
+pub struct BriefingArchiveQuery {
+    pub user_id: String,
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BriefingArchiveResponse {
+    pub briefings: Vec<BriefingDto>,
+}
+
+async fn briefing_archive(
+    State(state): State<AppState>,
+    Query(params): Query<BriefingArchiveQuery>,
+) -> Result<Json<BriefingArchiveResponse>, ApiError> {
+    let briefings = state
+        .briefing_archive
+        .execute(&UserId::from(params.user_id.as_str()), params.limit.clamp(1, 100))
+        .await?;
+    Ok(Json(BriefingArchiveResponse {
+        briefings: briefings.into_iter().map(BriefingDto::from).collect(),
+    }))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeliveryDto {
+    pub date: NaiveDate,
+    pub email: String,
+    pub status: String,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub last_attempt_at: i64,
+    pub next_attempt_at: Option<i64>,
+}
+
+impl From<BriefingDelivery> for DeliveryDto {
+    fn from(d: BriefingDelivery) -> Self {
+        Self {
+            date: d.date,
+            email: d.email,
+            status: d.state.status.to_string(),
+            attempts: d.state.attempts,
+            last_error: d.state.last_error,
+            last_attempt_at: d.state.last_attempt_at,
+            next_attempt_at: d.state.next_attempt_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct DeliveriesQuery {
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DeliveriesResponse {
+    pub deliveries: Vec<DeliveryDto>,
+}
+
+async fn list_deliveries(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<DeliveriesQuery>,
+) -> Result<Json<DeliveriesResponse>, ApiError> {
+    let deliveries = state
+        .deliveries
+        .execute(&UserId::from(id.as_str()), params.limit.clamp(1, 100))
+        .await?;
+    Ok(Json(DeliveriesResponse {
+        deliveries: deliveries.into_iter().map(DeliveryDto::from).collect(),
+    }))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WatchRuleDto {
+    pub id: String,
+    pub name: String,
+    pub expression: String, // Canonical form
+    pub created_at: i64,
+}
+
+impl From<WatchRule> for WatchRuleDto {
+    fn from(r: WatchRule) -> Self {
+        Self {
+            id: r.id.to_string(),
+            name: r.name,
+            expression: r.expression.to_string(),
+            created_at: r.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct AddWatchRuleRequest {
+    #[serde(default)]
+    pub name: String,
+    pub expression: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WatchlistResponse {
+    pub rules: Vec<WatchRuleDto>,
+}
+
+async fn add_watch_rule(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(payload): Json<AddWatchRuleRequest>,
+) -> Result<(StatusCode, Json<WatchRuleDto>), ApiError> {
+    let rule = state
+        .add_watch_rule
+        .execute(&UserId::from(id.as_str()), &payload.name, &payload.expression, unix_now())
+        .await?;
+    Ok((StatusCode::CREATED, Json(WatchRuleDto::from(rule))))
+}
+
+async fn list_watch_rules(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<WatchlistResponse>, ApiError> {
+    let rules = state.watch_rules.execute(&UserId::from(id.as_str())).await?;
+    Ok(Json(WatchlistResponse {
+        rules: rules.into_iter().map(WatchRuleDto::from).collect(),
+    }))
+}
+
+async fn remove_watch_rule(
+    State(state): State<AppState>,
+    Path((id, rule)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .remove_watch_rule
+        .execute(&UserId::from(id.as_str()), &WatchRuleId::from(rule.as_str()))
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AlertDto {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub article_id: String,
+    pub title: String,
+    pub url: String,
+    pub source: String,
+    pub matched_at: i64,
+}
+
+impl From<WatchAlert> for AlertDto {
+    fn from(a: WatchAlert) -> Self {
+        Self {
+            rule_id: a.rule_id.to_string(),
+            rule_name: a.rule_name,
+            article_id: a.article_id.to_string(),
+            title: a.title,
+            url: a.url,
+            source: a.source,
+            matched_at: a.matched_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct AlertsQuery {
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AlertsResponse {
+    pub alerts: Vec<AlertDto>,
+}
+
+async fn list_alerts(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<AlertsQuery>,
+) -> Result<Json<AlertsResponse>, ApiError> {
+    let alerts = state
+        .alerts
+        .execute(&UserId::from(id.as_str()), params.limit.clamp(1, 100))
+        .await?;
+    Ok(Json(AlertsResponse {
+        alerts: alerts.into_iter().map(AlertDto::from).collect(),
+    }))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MuteRuleDto {
+    pub id: String,
+    pub kind: String,
+    pub pattern: String,
+    pub created_at: i64,
+    pub expires_at: Option<i64>,
+}
+
+impl From<MuteRule> for MuteRuleDto {
+    fn from(r: MuteRule) -> Self {
+        Self {
+            id: r.id.to_string(),
+            kind: r.kind.to_string(),
+            pattern: r.pattern,
+            created_at: r.created_at,
+            expires_at: r.expires_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct AddMuteRequest {
+    pub kind: String,
+    pub pattern: String,
+    pub duration: Option<String>, // e.g. "7d", "12h"; omitted mutes until removed
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MutesResponse {
+    pub rules: Vec<MuteRuleDto>,
+}
+
+async fn add_mute(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(payload): Json<AddMuteRequest>,
+) -> Result<(StatusCode, Json<MuteRuleDto>), ApiError> {
+    let kind: MuteKind = payload.kind.parse()?;
+    let duration = payload.duration.as_deref().map(parse_mute_duration).transpose()?;
+    let rule = state
+        .add_mute
+        .execute(&UserId::from(id.as_str()), kind, &payload.pattern, duration, unix_now())
+        .await?;
+    Ok((StatusCode::CREATED, Json(MuteRuleDto::from(rule))))
+}
+
+async fn list_mutes(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<MutesResponse>, ApiError> {
+    let rules = state.mutes.execute(&UserId::from(id.as_str()), unix_now()).await?;
+    Ok(Json(MutesResponse {
+        rules: rules.into_iter().map(MuteRuleDto::from).collect(),
+    }))
+}
+
+async fn remove_mute(
+    State(state): State<AppState>,
+    Path((id, rule)): Path<(String, String)>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .remove_mute
+        .execute(&UserId::from(id.as_str()), &MuteRuleId::from(rule.as_str()))
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Subscriptions are returned without their secret.
+#[derive(Serialize, Deserialize)]
+pub struct WebhookDto {
+    pub id: String,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub keywords: Vec<String>,
+    pub active: bool,
+    pub created_at: i64,
+}
+
+impl From<WebhookSubscription> for WebhookDto {
+    fn from(s: WebhookSubscription) -> Self {
+        Self {
+            id: s.id.to_string(),
+            url: s.url,
+            event_types: s.event_types.iter().map(|t| t.as_str().to_string()).collect(),
+            keywords: s.keywords,
+            active: s.active,
+            created_at: s.created_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WebhooksResponse {
+    pub webhooks: Vec<WebhookDto>,
+}
+
+async fn create_webhook(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<(StatusCode, Json<WebhookDto>), ApiError> {
+    let event_types = payload
+        .event_types
+        .iter()
+        .map(|t| t.parse::<WebhookEventType>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let subscription = state
+        .create_webhook
+        .execute(&payload.url, &payload.secret, event_types, &payload.keywords, unix_now())
+        .await?;
+    Ok((StatusCode::CREATED, Json(WebhookDto::from(subscription))))
+}
+
+async fn list_webhooks(State(state): State<AppState>) -> Result<Json<WebhooksResponse>, ApiError> {
+    let webhooks = state.webhooks.execute().await?;
+    Ok(Json(WebhooksResponse {
+        webhooks: webhooks.into_iter().map(WebhookDto::from).collect(),
+    }))
+}
+
+async fn delete_webhook(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    state.delete_webhook.execute(&WebhookId::from(id.as_str())).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WebhookDeliveryDto {
+    pub id: String,
+    pub event_id: String,
+    pub event_type: String,
+    pub created_at: i64,
+    pub response_status: Option<u16>,
+    pub status: String,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub last_attempt_at: i64,
+    pub next_attempt_at: Option<i64>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(d: WebhookDelivery) -> Self {
+        Self {
+            event_type: d.event.event_type().as_str().to_string(),
+            id: d.id,
+            event_id: d.event.id,
+            created_at: d.created_at,
+            response_status: d.response_status,
+            status: d.state.status.to_string(),
+            attempts: d.state.attempts,
+            last_error: d.state.last_error,
+            last_attempt_at: d.state.last_attempt_at,
+            next_attempt_at: d.state.next_attempt_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct WebhookDeliveriesResponse {
+    pub deliveries: Vec<WebhookDeliveryDto>,
+}
+
+async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(params): Query<DeliveriesQuery>,
+) -> Result<Json<WebhookDeliveriesResponse>, ApiError> {
+    let deliveries = state
+        .webhook_deliveries
+        .execute(&WebhookId::from(id.as_str()), params.limit.clamp(1, 100))
+        .await?;
+    Ok(Json(WebhookDeliveriesResponse {
+        deliveries: deliveries.into_iter().map(WebhookDeliveryDto::from).collect(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use techpulse_domain::search::SearchRanking;
+    use axum::body::Body;
+    use axum::http::Request;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+    use techpulse_infra::repo::mem::{
+        InMemoryArticleRepo, InMemoryBriefingRepo, InMemoryCooccurrenceRepo, InMemoryDeliveryRepo, InMemoryTimelineRepo,
+        InMemoryTopicRepo, InMemoryInteractionRepo, InMemoryTrendRepo, InMemoryUserRepo, InMemoryWebhookRepo, InMemoryAlertRepo,
+        InMemorySnapshotRepo,
+    };
+
+    use techpulse_domain::article::{Article, Source};
+    use techpulse_domain::repository::{ArticleRepo, SnapshotRepo};
+    use techpulse_domain::error::DomainError;
+    use techpulse_domain::event::ClusteringPolicy;
+    use techpulse_domain::gateway::ArticleGateway;
+    use techpulse_domain::blindspot::BlindSpotPolicy;
+    use techpulse_domain::briefing::BriefingLimits;
+    use techpulse_domain::decay::DecayPolicy;
+    use techpulse_domain::interaction::InteractionPolicy;
+    use techpulse_domain::personalization::PersonalizationWeights;
+    use techpulse_usecase::interactions::UpdateProfileFromInteractions;
+    use techpulse_domain::trend::MilestonePolicy;
+    use techpulse_usecase::ingest::IngestArticles;
+    use async_trait::async_trait;
+
+    struct StubGateway;
+    #[async_trait]
+    impl ArticleGateway for StubGateway {
+        async fn fetch_top_articles(&self, _limit: usize) -> Result<Vec<Article>, DomainError> {
+            Ok(vec![])
+        }
+    }
+
+    struct FixedGateway(Vec<Article>);
+    #[async_trait]
+    impl ArticleGateway for FixedGateway {
+        async fn fetch_top_articles(&self, limit: usize) -> Result<Vec<Article>, DomainError> {
+            Ok(self.0.iter().take(limit).cloned().collect())
+        }
+    }
+
+    fn test_state() -> AppState {
+        test_state_with(Arc::new(InMemoryArticleRepo::new()))
+    }
+
+    fn test_state_with(article_repo: Arc<InMemoryArticleRepo>) -> AppState {
+        let trend_repo = Arc::new(InMemoryTrendRepo::new());
+        let topic_repo = Arc::new(InMemoryTopicRepo::new());
+        let graph_repo = Arc::new(InMemoryCooccurrenceRepo::new());
+        let timeline_repo = Arc::new(InMemoryTimelineRepo::new());
+        let user_repo = Arc::new(InMemoryUserRepo::new());
+        let interaction_repo = Arc::new(InMemoryInteractionRepo::new());
+        let briefing_repo = Arc::new(InMemoryBriefingRepo::new());
+        let webhook_repo = Arc::new(InMemoryWebhookRepo::new());
+        let alert_repo = Arc::new(InMemoryAlertRepo::new());
+        let snapshot_repo = Arc::new(InMemorySnapshotRepo::new());
+        let gateway = Arc::new(StubGateway);
+        let clusterer = Arc::new(ClusterStories::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            ClusteringPolicy::default(),
+        ));
+        
+        let personalized_feed = Arc::new(PersonalizeFeed::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            user_repo.clone(),
+            PersonalizationWeights::default(),
+        ));
+        let blind_spots = Arc::new(GetBlindSpots::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            user_repo.clone(),
+            BlindSpotPolicy::default(),
+        ));
+
+        AppState {
+            feed: Arc::new(GetChronologicalFeed::new(article_repo.clone())),
+            personalized_feed: personalized_feed.clone(),
+            search: Arc::new(SearchArticles::new(article_repo.clone(), SearchRanking::default())),
+            article_detail: Arc::new(GetArticleDetail::new(article_repo.clone(), snapshot_repo.clone())),
+            trends: Arc::new(CalculateTrends::new(article_repo.clone(), trend_repo.clone())),
+            ingest: Arc::new(IngestArticles::new(gateway, article_repo.clone()).with_snapshots(snapshot_repo)),
+            topics: Arc::new(ListTopics::new(topic_repo.clone())),
+            topic_rollup: Arc::new(GetCategoryRollup::new(topic_repo.clone(), trend_repo)),
+            build_graph: Arc::new(BuildCooccurrenceGraph::new(
+                article_repo.clone(),
+                topic_repo.clone(),
+                graph_repo.clone(),
+            )),
+            neighborhood: Arc::new(GetTopicNeighborhood::new(topic_repo.clone(), graph_repo)),
+            events: clusterer.clone(),
+            timeline: Arc::new(GetTimeline::new(timeline_repo.clone())),
+            pin_timeline: Arc::new(PinArticle::new(article_repo.clone(), topic_repo.clone(), timeline_repo.clone())),
+            update_timeline: Arc::new(UpdateTimelineEvent::new(timeline_repo.clone())),
+            delete_timeline: Arc::new(DeleteTimelineEvent::new(timeline_repo.clone())),
+            generate_timeline: Arc::new(GenerateTimeline::new(
+                clusterer,
+                topic_repo.clone(),
+                timeline_repo,
+                MilestonePolicy::default(),
+            )),
+            user_profile: Arc::new(GetUserProfile::new(user_repo.clone())),
+            set_knowledge: Arc::new(SetTopicKnowledge::new(user_repo.clone(), topic_repo.clone())),
+            knowledge_by_state: Arc::new(ListTopicsByState::new(user_repo.clone())),
+            user_settings: Arc::new(UpdateUserSettings::new(user_repo.clone())),
+            blind_spots: blind_spots.clone(),
+            interactions: Arc::new(RecordInteractions::new(
+                interaction_repo,
+                Arc::new(UpdateProfileFromInteractions::new(
+                    article_repo.clone(),
+                    topic_repo.clone(),
+                    user_repo.clone(),
+                    InteractionPolicy::default(),
+                )),
+            )),
+            knowledge_decay: Arc::new(ApplyKnowledgeDecay::new(
+                article_repo.clone(),
+                topic_repo.clone(),
+                user_repo.clone(),
+                DecayPolicy::default(),
+            )),
+            revisit: Arc::new(GetRevisitSuggestions::new(
+                article_repo.clone(),
+                topic_repo.clone(),
+                user_repo.clone(),
+                DecayPolicy::default(),
+            )),
+            briefing: Arc::new(GenerateBriefing::new(
+                personalized_feed,
+                blind_spots,
+                article_repo,
+                topic_repo,
+                user_repo.clone(),
+                briefing_repo.clone(),
+                BriefingLimits::default(),
+            )),
+            briefing_archive: Arc::new(ListBriefings::new(briefing_repo)),
+            deliveries: Arc::new(ListDeliveries::new(Arc::new(InMemoryDeliveryRepo::new()))),
+            create_webhook: Arc::new(CreateWebhook::new(webhook_repo.clone())),
+            webhooks: Arc::new(ListWebhooks::new(webhook_repo.clone())),
+            delete_webhook: Arc::new(DeleteWebhook::new(webhook_repo.clone())),
+            webhook_deliveries: Arc::new(ListWebhookDeliveries::new(webhook_repo)),
+            add_watch_rule: Arc::new(AddWatchRule::new(user_repo.clone())),
+            watch_rules: Arc::new(ListWatchRules::new(user_repo.clone())),
+            remove_watch_rule: Arc::new(RemoveWatchRule::new(user_repo.clone())),
+            alerts: Arc::new(ListAlerts::new(alert_repo)),
+            add_mute: Arc::new(AddMuteRule::new(user_repo.clone())),
+            mutes: Arc::new(ListMuteRules::new(user_repo.clone())),
+            remove_mute: Arc::new(RemoveMuteRule::new(user_repo.clone())),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_health_endpoint() {
+        let app = routes(test_state());
+        let response = app
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_feed_endpoint() {
+        let app = routes(test_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/feed?limit=5")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        assert!(feed.articles.is_empty()); // Empty in-memory repo
+    }
+
+    #[tokio::test]
+    async fn test_trends_endpoint_with_body() {
+        let app = routes(test_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/trends/calculate")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"keywords":["Rust"]}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let trends: TrendsResponse = serde_json::from_slice(&body).unwrap();
+        assert!(trends.trends.is_empty()); // No articles to match
+    }
+    
+    #[tokio::test]
+    async fn test_trends_endpoint_without_body() {
+        let app = routes(test_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/trends/calculate")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_topics_endpoint_rejects_unknown_category() {
+        let app = routes(test_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/topics?category=astrology")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_topic_categories_endpoint() {
+        let app = routes(test_state());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/topics/categories")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let rollup: CategoriesResponse = serde_json::from_slice(&body).unwrap();
+        assert!(rollup.categories.is_empty()); // No trend report yet
+    }
+
+    #[tokio::test]
+    async fn test_build_graph_then_neighbors() {
+        let app = routes(test_state());
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/topics/graph")
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"hours":24}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let graph: GraphResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(graph.period_end - graph.period_start, 24 * 3600);
+        assert_eq!(graph.period_end % 3600, 0); // Aligned, so rebuilds replace the same period
+
+        for uri in [
+            "/api/topics/rust/neighbors?limit=5".to_string(),
+            format!("/api/topics/rust/neighbors?as_of={}&hours=24", graph.period_end + 60),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            let neighbors: NeighborsResponse = serde_json::from_slice(&body).unwrap();
+            assert_eq!(neighbors.topic, "rust");
+            assert!(neighbors.neighbors.is_empty());
+        }
+
+        let uri = format!("/api/topics/rust/neighbors?as_of={}&hours=24", graph.period_start);
+        let response = app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND); // No graph was built for that period
+    }
+
+    #[tokio::test]
+    async fn test_events_endpoint_groups_articles() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        for (id, title) in [("1", "OpenAI releases GPT-5"), ("2", "GPT-5 released by OpenAI"), ("3", "Unrelated post")] {
+            let article = Article::new(Source::HackerNews, id, title.into(), "".into(), now - 60).unwrap();
+            repo.save(&article).await.unwrap();
+        }
+        let state = test_state_with(repo);
+
+        let response = routes(state)
+            .oneshot(Request::builder().uri("/api/events?hours=24").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let events: EventsResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(events.events.len(), 1); // Singleton filtered by min_articles=2
+        assert_eq!(events.events[0].articles.len(), 2);
+        assert_eq!(events.events[0].lifecycle, "emerging");
+    }
+
+    async fn send(app: &Router, request: Request<Body>) -> (StatusCode, axum::body::Bytes) {
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, body)
+    }
+
+    fn json_request(method: &str, uri: &str, body: &str) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_timeline_pin_update_delete_flow() {
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        // 2024-03-01T00:00:00Z
+        let article = Article::new(Source::HackerNews, "7", "Rust 2.0".into(), "http://rust".into(), 1709251200).unwrap();
+        repo.save(&article).await.unwrap();
+        let app = routes(test_state_with(repo));
+
+        let (status, body) = send(&app, json_request("POST", "/api/timeline", r#"{"article_id":"hn-7","category":"languages"}"#)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let pinned: TimelineEventDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(pinned.id, "pin-hn-7");
+        assert_eq!(pinned.date, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(pinned.url.as_deref(), Some("http://rust"));
+
+        // Pinning twice conflicts, unknown articles are 404
+        let (status, _) = send(&app, json_request("POST", "/api/timeline", r#"{"article_id":"hn-7"}"#)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (status, _) = send(&app, json_request("POST", "/api/timeline", r#"{"article_id":"hn-8"}"#)).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = send(&app, json_request("PATCH", "/api/timeline/pin-hn-7", r#"{"title":"Rust 2.0 released"}"#)).await;
+        assert_eq!(status, StatusCode::OK);
+        let updated: TimelineEventDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(updated.title, "Rust 2.0 released");
+        assert_eq!(updated.category, "languages"); // Omitted fields are kept
+        let (status, _) = send(&app, json_request("PUT", "/api/timeline/pin-hn-7", r#"{"title":"Rust 2.0"}"#)).await;
+        assert_eq!(status, StatusCode::METHOD_NOT_ALLOWED);
+
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let (status, body) = send(&app, get("/api/timeline?category=languages&from=2024-01-01&to=2024-12-31")).await;
+        assert_eq!(status, StatusCode::OK);
+        let timeline: TimelineResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(timeline.months.len(), 1);
+        assert_eq!((timeline.months[0].year, timeline.months[0].month), (2024, 3));
+
+        let (_, body) = send(&app, get("/api/timeline?category=ai")).await;
+        let timeline: TimelineResponse = serde_json::from_slice(&body).unwrap();
+        assert!(timeline.months.is_empty());
+
+        let delete = Request::builder().method("DELETE").uri("/api/timeline/pin-hn-7").body(Body::empty()).unwrap();
+        let (status, _) = send(&app, delete).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let delete = Request::builder().method("DELETE").uri("/api/timeline/pin-hn-7").body(Body::empty()).unwrap();
+        let (status, _) = send(&app, delete).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_timeline_generate_endpoint() {
+        let app = routes(test_state());
+        let (status, body) = send(&app, json_request("POST", "/api/timeline/generate", r#"{"hours":24}"#)).await;
+        assert_eq!(status, StatusCode::OK);
+        let generated: GenerateTimelineResponse = serde_json::from_slice(&body).unwrap();
+        assert!(generated.created.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_user_knowledge_and_settings_flow() {
+        let app = routes(test_state());
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let (status, _) = send(&app, get("/api/users/u1")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = send(&app, json_request("PUT", "/api/users/u1/knowledge/rust", r#"{"state":"know_it"}"#)).await;
+        assert_eq!(status, StatusCode::OK);
+        let entry: TopicKnowledgeDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(entry.state, "know_it");
+        send(&app, json_request("PUT", "/api/users/u1/knowledge/go", r#"{"state":"want_to_learn"}"#)).await;
+
+        let (status, _) = send(&app, json_request("PUT", "/api/users/u1/knowledge/go", r#"{"state":"expert"}"#)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (_, body) = send(&app, get("/api/users/u1/knowledge?state=want_to_learn")).await;
+        let listed: KnowledgeResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed.topics.len(), 1);
+        assert_eq!(listed.topics[0].topic, "go");
+
+        let (status, body) = send(&app, json_request("PUT", "/api/users/u1/settings", r#"{"preferred_sources":["hn"],"theme":"dark"}"#)).await;
+        assert_eq!(status, StatusCode::OK);
+        let profile: UserProfileDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(profile.settings.theme, "dark");
+        assert_eq!(profile.knowledge.len(), 2); // Settings update keeps the knowledge map
+
+        let (status, body) = send(&app, get("/api/users/u1")).await;
+        assert_eq!(status, StatusCode::OK);
+        let profile: UserProfileDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(profile.settings.preferred_sources, vec!["hn".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_watchlist_rules_flag_the_feed() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        for (id, title, url) in [
+            ("1", "SQLite 3.45 released", "https://sqlite.org/releaselog"),
+            ("2", "Zig 0.13", "https://ziglang.org"),
+        ] {
+            repo.save(&Article::new(Source::HackerNews, id, title.into(), url.into(), now - 60).unwrap())
+                .await
+                .unwrap();
+        }
+        let app = routes(test_state_with(repo));
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let rule = r#"{"name":"sqlite","expression":"domain:sqlite.org  OR  title:\"sqlite 4\""}"#;
+        let (status, body) = send(&app, json_request("POST", "/api/users/u1/watchlist", rule)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created: WatchRuleDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(created.expression, r#"domain:sqlite.org OR title:"sqlite 4""#);
+
+        let (status, _) = send(&app, json_request("POST", "/api/users/u1/watchlist", r#"{"expression":"(zig"}"#)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = send(&app, json_request("POST", "/api/users/u1/watchlist", rule)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (_, body) = send(&app, get("/api/feed?user_id=u1")).await;
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        let flagged: Vec<&str> = feed
+            .articles
+            .iter()
+            .filter(|a| !a.watch_matches.is_empty())
+            .map(|a| a.id.as_str())
+            .collect();
+        assert_eq!(flagged, vec!["hn-1"]);
+
+        let (status, body) = send(&app, get("/api/users/u1/alerts")).await;
+        assert_eq!(status, StatusCode::OK);
+        let alerts: AlertsResponse = serde_json::from_slice(&body).unwrap();
+        assert!(alerts.alerts.is_empty()); // Alerts are raised at ingest time
+
+        let delete = Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/users/u1/watchlist/{}", created.id))
+            .body(Body::empty())
+            .unwrap();
+        let (status, _) = send(&app, delete).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (_, body) = send(&app, get("/api/users/u1/watchlist")).await;
+        let listed: WatchlistResponse = serde_json::from_slice(&body).unwrap();
+        assert!(listed.rules.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_article_detail_with_history() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        let mut article = Article::new(Source::HackerNews, "7", "Rust 2.0".into(), "https://rust-lang.org".into(), now - 7200).unwrap();
+        article.tags.insert("rust".into());
+        article.score = 60.0;
+        article.comment_count = 120;
+        repo.save(&article).await.unwrap();
+
+        let snapshots = Arc::new(InMemorySnapshotRepo::new());
+        let series: Vec<ArticleSnapshot> = [(now - 3600, 20.0, 15), (now, 60.0, 120)]
+            .iter()
+            .map(|(at, score, comments)| ArticleSnapshot { score: *score, comment_count: *comments, ..ArticleSnapshot::of(&article, *at) })
+            .collect();
+        snapshots.append(&series).await.unwrap();
+        let mut state = test_state_with(repo.clone());
+        state.article_detail = Arc::new(GetArticleDetail::new(repo, snapshots));
+        let app = routes(state);
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let (status, body) = send(&app, get("/api/articles/hn-7")).await;
+        assert_eq!(status, StatusCode::OK);
+        let detail: ArticleDetailDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(detail.tags, vec!["rust".to_string()]);
+        assert_eq!(detail.comment_count, 120);
+        assert_eq!(detail.source_score, 60.0);
+        assert!(detail.score > 0.0 && detail.score < detail.source_score);
+        let comments: Vec<u32> = detail.history.iter().map(|s| s.comment_count).collect();
+        assert_eq!(comments, vec![15, 120]);
+
+        let (status, _) = send(&app, get("/api/articles/hn-8")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, get("/api/articles/nodash")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_reingest_updates_engagement_only() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        let mut article = Article::new(Source::HackerNews, "1", "Rust 2.0".into(), "https://rust-lang.org".into(), now - 60).unwrap();
+        article.score = 10.0;
+        let first = vec![article.clone(), Article::new(Source::HackerNews, "2", "Go 2".into(), "".into(), now).unwrap()];
+        article.title = "Rust 2.0 [video]".into();
+        article.score = 70.0;
+
+        let mut state = test_state_with(repo.clone());
+        state.ingest = Arc::new(IngestArticles::new(Arc::new(FixedGateway(first)), repo.clone()));
+        let app = routes(state.clone());
+        let (status, body) = send(&app, json_request("POST", "/api/ingest", "{}")).await;
+        assert_eq!(status, StatusCode::OK);
+        let report: IngestResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!((report.ingested, report.new, report.updated), (2, 2, 0));
+
+        state.ingest = Arc::new(IngestArticles::new(Arc::new(FixedGateway(vec![article])), repo));
+        let app = routes(state);
+        let (_, body) = send(&app, json_request("POST", "/api/ingest", "{}")).await;
+        let report: IngestResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!((report.ingested, report.new, report.updated), (1, 0, 1));
+
+        let get = Request::builder().uri("/api/articles/hn-1").body(Body::empty()).unwrap();
+        let (_, body) = send(&app, get).await;
+        let detail: ArticleDetailDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(detail.title, "Rust 2.0");
+        assert_eq!(detail.source_score, 70.0);
+        assert_eq!(detail.times_seen, 2);
+        assert!(detail.first_seen_at <= detail.last_seen_at);
+    }
+
+    #[tokio::test]
+    async fn test_search_highlights_matches() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        let mut post = Article::new(Source::HackerNews, "1", "Ask HN: Favorite databases?".into(), "".into(), now - 60).unwrap();
+        post.text = "I keep coming back to SQLite for side projects".into();
+        repo.save(&post).await.unwrap();
+        repo.save(&Article::new(Source::HackerNews, "2", "SQLite 3.45 released".into(), "".into(), now - 3600).unwrap())
+            .await
+            .unwrap();
+        let app = routes(test_state_with(repo));
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let (status, body) = send(&app, get("/api/search?q=sqlite")).await;
+        assert_eq!(status, StatusCode::OK);
+        let found: SearchResponse = serde_json::from_slice(&body).unwrap();
+        let ids: Vec<&str> = found.results.iter().map(|r| r.article.id.as_str()).collect();
+        assert_eq!(ids, vec!["hn-2", "hn-1"]); // Title match first
+        assert_eq!(found.results[0].snippet, "<mark>SQLite</mark> 3 45 released");
+
+        let (_, body) = send(&app, get("/api/search?q=%22side%20projects%22%20datab*")).await;
+        let found: SearchResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(found.results.len(), 1);
+        assert_eq!(found.results[0].article.id, "hn-1");
+
+        let (status, _) = send(&app, get("/api/search?q=%22unterminated")).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_feed_filters_and_cursor_pages() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        for i in 0..5 {
+            let source = if i % 2 == 0 { Source::HackerNews } else { Source::Reddit("rust".into()) };
+            let mut article = Article::new(source, &i.to_string(), format!("Story {}", i), format!("https://site{}.example/a", i), now - i * 60).unwrap();
+            article.score = 10.0 * i as f64;
+            repo.save(&article).await.unwrap();
+        }
+        let app = routes(test_state_with(repo));
+        let get = |uri: String| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let mut uri = "/api/feed?limit=2".to_string();
+        let mut seen = Vec::new();
+        loop {
+            let (status, body) = send(&app, get(uri.clone())).await;
+            assert_eq!(status, StatusCode::OK);
+            let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+            seen.extend(feed.articles.into_iter().map(|a| a.id));
+            match feed.next_cursor {
+                Some(cursor) => uri = format!("/api/feed?limit=2&cursor={}", cursor),
+                None => break,
+            }
+        }
+        assert_eq!(seen, vec!["hn-0", "rd-rust-1", "hn-2", "rd-rust-3", "hn-4"]);
+
+        let (_, body) = send(&app, get("/api/feed?source=rd&min_score=20".to_string())).await;
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        let ids: Vec<String> = feed.articles.into_iter().map(|a| a.id).collect();
+        assert_eq!(ids, vec!["rd-rust-3"]);
+
+        let (_, body) = send(&app, get("/api/feed?domain=site2.example&q=story".to_string())).await;
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(feed.articles.len(), 1);
+        assert!(feed.next_cursor.is_none());
+
+        for bad in ["/api/feed?cursor=nothex", "/api/feed?since=10&until=5", "/api/feed?user_id=u1&cursor=313a686e2d31"] {
+            let (status, _) = send(&app, get(bad.to_string())).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", bad);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mutes_hide_feed_items_and_report_counts() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        for (id, title) in [("1", "Bitcoin price today"), ("2", "Rust 2.0"), ("3", "ETH price crash")] {
+            repo.save(&Article::new(Source::HackerNews, id, title.into(), "".into(), now - 60).unwrap())
+                .await
+                .unwrap();
+        }
+        let app = routes(test_state_with(repo));
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let body = r#"{"kind":"regex","pattern":"\\bprice\\b","duration":"7d"}"#;
+        let (status, body) = send(&app, json_request("POST", "/api/users/u1/mutes", body)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let rule: MuteRuleDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(rule.expires_at.map(|t| t - rule.created_at), Some(7 * 24 * 3600));
+
+        for bad in [
+            r#"{"kind":"color","pattern":"red"}"#,
+            r#"{"kind":"regex","pattern":"(unclosed"}"#,
+            r#"{"kind":"keyword","pattern":"nft","duration":"forever"}"#,
+        ] {
+            let (status, _) = send(&app, json_request("POST", "/api/users/u1/mutes", bad)).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", bad);
+        }
+
+        let (_, body) = send(&app, get("/api/feed?user_id=u1")).await;
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(feed.articles.len(), 1);
+        let muted = feed.muted.unwrap();
+        assert_eq!(muted.hidden, 2);
+        assert_eq!(muted.by_rule[0].rule_id, rule.id);
+
+        // The unpersonalized feed is never filtered
+        let (_, body) = send(&app, get("/api/feed")).await;
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(feed.articles.len(), 3);
+        assert!(feed.muted.is_none());
+
+        let delete = Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/users/u1/mutes/{}", rule.id))
+            .body(Body::empty())
+            .unwrap();
+        let (status, _) = send(&app, delete).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (_, body) = send(&app, get("/api/users/u1/mutes")).await;
+        let listed: MutesResponse = serde_json::from_slice(&body).unwrap();
+        assert!(listed.rules.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delivery_settings_and_log() {
+        let app = routes(test_state());
+
+        let body = r#"{"preferred_sources":[],"theme":"","time_zone":"Europe/Berlin",
+            "delivery":{"enabled":true,"email":"ada@example.com","send_at":"06:45","format":"text"}}"#;
+        let (status, body) = send(&app, json_request("PUT", "/api/users/u1/settings", body)).await;
+        assert_eq!(status, StatusCode::OK);
+        let profile: UserProfileDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(profile.settings.time_zone, "Europe/Berlin");
+        assert_eq!(profile.settings.delivery.send_at, "06:45");
+
+        // A settings update in the older shape leaves time zone and delivery alone
+        let old_shape = r#"{"preferred_sources":["hn"],"theme":"dark"}"#;
+        let (status, body) = send(&app, json_request("PUT", "/api/users/u1/settings", old_shape)).await;
+        assert_eq!(status, StatusCode::OK);
+        let profile: UserProfileDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!((profile.settings.theme.as_str(), profile.settings.time_zone.as_str()), ("dark", "Europe/Berlin"));
+        assert!(profile.settings.delivery.enabled);
+        assert_eq!(profile.settings.delivery.email.as_deref(), Some("ada@example.com"));
+
+        let bad_zone = r#"{"time_zone":"Nowhere/Special"}"#;
+        let (status, _) = send(&app, json_request("PUT", "/api/users/u1/settings", bad_zone)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let no_address = r#"{"delivery":{"enabled":true,"email":""}}"#;
+        let (status, _) = send(&app, json_request("PUT", "/api/users/u1/settings", no_address)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let request = Request::builder().uri("/api/users/u1/deliveries").body(Body::empty()).unwrap();
+        let (status, body) = send(&app, request).await;
+        assert_eq!(status, StatusCode::OK);
+        let log: DeliveriesResponse = serde_json::from_slice(&body).unwrap();
+        assert!(log.deliveries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_subscriptions() {
+        let app = routes(test_state());
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let body = r#"{"url":"https://hooks.example.com/tp","secret":"0123456789abcdef",
+            "event_types":["trend.spike","article.matched"],"keywords":["Rust"]}"#;
+        let (status, body) = send(&app, json_request("POST", "/api/webhooks", body)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let created: WebhookDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(created.event_types, vec!["trend.spike".to_string(), "article.matched".to_string()]);
+        assert_eq!(created.keywords, vec!["rust".to_string()]);
+        assert!(!String::from_utf8_lossy(&body).contains("0123456789abcdef")); // Secret is never echoed
+
+        let unknown = r#"{"url":"https://hooks.example.com","secret":"0123456789abcdef","event_types":["trend.dip"]}"#;
+        let (status, _) = send(&app, json_request("POST", "/api/webhooks", unknown)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let short_secret = r#"{"url":"https://hooks.example.com","secret":"abc","event_types":["trend.spike"]}"#;
+        let (status, _) = send(&app, json_request("POST", "/api/webhooks", short_secret)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (_, body) = send(&app, get("/api/webhooks")).await;
+        let listed: WebhooksResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed.webhooks.len(), 1);
+
+        let (status, body) = send(&app, get(&format!("/api/webhooks/{}/deliveries", created.id))).await;
+        assert_eq!(status, StatusCode::OK);
+        let log: WebhookDeliveriesResponse = serde_json::from_slice(&body).unwrap();
+        assert!(log.deliveries.is_empty());
+
+        let delete = Request::builder()
+            .method("DELETE")
+            .uri(format!("/api/webhooks/{}", created.id))
+            .body(Body::empty())
+            .unwrap();
+        let (status, _) = send(&app, delete).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&app, get(&format!("/api/webhooks/{}/deliveries", created.id))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_blind_spots_endpoint() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        for (id, tag) in [("1", "rust"), ("2", "rust"), ("3", "rust"), ("4", "go")] {
+            let mut article = Article::new(Source::HackerNews, id, format!("Post {}", id), "".into(), now - 60).unwrap();
+            article.tags.insert(tag.to_string());
+            repo.save(&article).await.unwrap();
+        }
+        let app = routes(test_state_with(repo));
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let (status, body) = send(&app, get("/api/users/u1/blind-spots?samples=1")).await;
+        assert_eq!(status, StatusCode::OK);
+        let spots: BlindSpotsResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(spots.blind_spots.len(), 1);
+        assert_eq!(spots.blind_spots[0].topic, "rust");
+        assert_eq!(spots.blind_spots[0].state, None);
+        assert_eq!(spots.blind_spots[0].samples.len(), 1);
+
+        // Once the user knows the topic it is no longer a blind spot
+        send(&app, json_request("PUT", "/api/users/u1/knowledge/rust", r#"{"state":"know_it"}"#)).await;
+        let (_, body) = send(&app, get("/api/users/u1/blind-spots")).await;
+        let spots: BlindSpotsResponse = serde_json::from_slice(&body).unwrap();
+        assert!(spots.blind_spots.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_interactions_endpoint_updates_knowledge() {
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        let mut article = Article::new(Source::HackerNews, "1", "Post".into(), "".into(), 0).unwrap();
+        article.tags.insert("rust".into());
+        repo.save(&article).await.unwrap();
+        let app = routes(test_state_with(repo));
+
+        let batch = r#"{"user_id":"u1","events":[
+            {"article_id":"hn-1","kind":"open","timestamp":10},
+            {"article_id":"hn-1","kind":"mark_known","timestamp":20}
+        ]}"#;
+        let (status, body) = send(&app, json_request("POST", "/api/interactions", batch)).await;
+        assert_eq!(status, StatusCode::OK);
+        let result: InteractionBatchResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.recorded, 2);
+        assert_eq!(result.profiles_updated, 1);
+
+        let get = Request::builder().uri("/api/users/u1/knowledge?state=know_it").body(Body::empty()).unwrap();
+        let (_, body) = send(&app, get).await;
+        let listed: KnowledgeResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(listed.topics[0].topic, "rust");
+
+        // Dwell without a duration is rejected as a whole batch
+        let bad = r#"{"user_id":"u1","events":[{"article_id":"hn-1","kind":"dwell"}]}"#;
+        let (status, _) = send(&app, json_request("POST", "/api/interactions", bad)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_decay_and_revisit_endpoints() {
+        let app = routes(test_state());
+
+        // Nothing stale for a fresh profile
+        send(&app, json_request("PUT", "/api/users/u1/knowledge/rust", r#"{"state":"know_it"}"#)).await;
+        let decay = Request::builder().method("POST").uri("/api/users/u1/knowledge-decay").body(Body::empty()).unwrap();
+        let (status, body) = send(&app, decay).await;
+        assert_eq!(status, StatusCode::OK);
+        let result: DecayResponse = serde_json::from_slice(&body).unwrap();
+        assert!(result.downgraded.is_empty());
+        // "decay" under /knowledge is a topic slug, not an action
+        let old = Request::builder().method("POST").uri("/api/users/u1/knowledge/decay").body(Body::empty()).unwrap();
+        assert_eq!(send(&app, old).await.0, StatusCode::METHOD_NOT_ALLOWED);
+
+        let get = Request::builder().uri("/api/users/u1/revisit?hours=48").body(Body::empty()).unwrap();
+        let (status, body) = send(&app, get).await;
+        assert_eq!(status, StatusCode::OK);
+        let result: RevisitResponse = serde_json::from_slice(&body).unwrap();
+        assert!(result.suggestions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_feed_endpoint_personalized_for_user() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        for (id, tag, timestamp) in [("1", "rust", now - 60), ("2", "go", now - 120)] {
+            let mut article = Article::new(Source::HackerNews, id, format!("Post {}", id), "".into(), timestamp).unwrap();
+            article.score = 50.0;
+            article.tags.insert(tag.to_string());
+            repo.save(&article).await.unwrap();
+        }
+        let app = routes(test_state_with(repo));
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let (_, body) = send(&app, get("/api/feed")).await;
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(feed.articles[0].id, "hn-1");
+        assert!(feed.articles[0].score.is_none());
+
+        send(&app, json_request("PUT", "/api/users/u1/knowledge/go", r#"{"state":"want_to_learn"}"#)).await;
+        send(&app, json_request("PUT", "/api/users/u1/knowledge/rust", r#"{"state":"know_it"}"#)).await;
+
+        let (status, body) = send(&app, get("/api/feed?user_id=u1")).await;
+        assert_eq!(status, StatusCode::OK);
+        let feed: FeedResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(feed.articles[0].id, "hn-2");
+        assert!(feed.articles[0].score.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_briefing_is_stable_and_archived() {
+        let now = unix_now();
+        let repo = Arc::new(InMemoryArticleRepo::new());
+        let article = Article::new(Source::HackerNews, "1", "First story".into(), "".into(), now - 60).unwrap();
+        repo.save(&article).await.unwrap();
+        let app = routes(test_state_with(repo.clone()));
+        let get = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let (status, body) = send(&app, get("/api/briefing?user_id=u1")).await;
+        assert_eq!(status, StatusCode::OK);
+        let first: BriefingDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(first.stories.len(), 1);
+
+        // New articles do not change today's briefing
+        let later = Article::new(Source::HackerNews, "2", "Second story".into(), "".into(), now).unwrap();
+        repo.save(&later).await.unwrap();
+        let uri = format!("/api/briefing?user_id=u1&date={}", first.date);
+        let (_, body) = send(&app, get(&uri)).await;
+        let again: BriefingDto = serde_json::from_slice(&body).unwrap();
+        assert_eq!(again.generated_at, first.generated_at);
+        assert_eq!(again.stories.len(), 1);
+
+        let (status, _) = send(&app, get("/api/briefing?user_id=u1&date=2000-01-01")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (_, body) = send(&app, get("/api/briefing/archive?user_id=u1")).await;
+        let archive: BriefingArchiveResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(archive.briefings.len(), 1);
+        assert_eq!(archive.briefings[0].date, first.date);
     }
 }
@@ -0,0 +1,364 @@
+// Briefing renderers for sharing outside the app (Slack, email, files)
+use std::fmt;
+use std::str::FromStr;
+use techpulse_domain::briefing::{Briefing, BriefingStory};
+use techpulse_domain::delivery::{BriefingComposer, EmailMessage};
+use techpulse_domain::error::DomainError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderFormat {
+    Markdown,
+    Html,
+    Text,
+}
+
+impl RenderFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RenderFormat::Markdown => "markdown",
+            RenderFormat::Html => "html",
+            RenderFormat::Text => "text",
+        }
+    }
+
+    fn escape(&self, value: &str) -> String {
+        match self {
+            RenderFormat::Html => escape_html(value),
+            RenderFormat::Markdown => escape_markdown(value),
+            RenderFormat::Text => value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for RenderFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RenderFormat {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "markdown" | "md" => Ok(RenderFormat::Markdown),
+            "html" => Ok(RenderFormat::Html),
+            "text" | "txt" => Ok(RenderFormat::Text),
+            other => Err(DomainError::Validation(format!("Unknown render format '{}'", other))),
+        }
+    }
+}
+
+/// Templates with `{{placeholder}}` slots. Unknown placeholders are left as is.
+///
+/// - `document`: `{{date}}`, `{{user}}`, `{{stories}}`, `{{trends}}`, `{{blind_spots}}`
+/// - `story`: `{{title}}`, `{{url}}`, `{{source}}`, `{{score}}`
+/// - `trend`: `{{topic}}`, `{{volume}}`, `{{velocity}}`
+/// - `blind_spot`: `{{topic}}`, `{{severity}}`, `{{title}}`, `{{url}}`, `{{source}}`
+/// - `empty`: used in place of a section without items
+#[derive(Debug, Clone, PartialEq)]
+pub struct BriefingTemplate {
+    pub document: String,
+    pub story: String,
+    pub trend: String,
+    pub blind_spot: String,
+    pub empty: String,
+}
+
+impl BriefingTemplate {
+    pub fn for_format(format: RenderFormat) -> Self {
+        match format {
+            RenderFormat::Markdown => Self {
+                document: "# Tech Pulse briefing, {{date}}\n\n\
+                    ## Top stories\n{{stories}}\n\n\
+                    ## Trending topics\n{{trends}}\n\n\
+                    ## Blind spots\n{{blind_spots}}\n"
+                    .to_string(),
+                story: "- [{{title}}]({{url}}) ({{source}})".to_string(),
+                trend: "- **{{topic}}**: {{volume}} articles ({{velocity}})".to_string(),
+                blind_spot: "- **{{topic}}** is busy and new to you. Start with [{{title}}]({{url}})".to_string(),
+                empty: "_Nothing today._".to_string(),
+            },
+            RenderFormat::Html => Self {
+                document: "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+                    <title>Tech Pulse briefing, {{date}}</title>\n\
+                    <style>body{font-family:sans-serif;max-width:40em;margin:2em auto;color:#222}\
+                    h2{border-bottom:1px solid #ddd}li{margin:.4em 0}.meta{color:#777}</style>\n\
+                    </head>\n<body>\n<h1>Tech Pulse briefing, {{date}}</h1>\n\
+                    <h2>Top stories</h2>\n<ul>\n{{stories}}\n</ul>\n\
+                    <h2>Trending topics</h2>\n<ul>\n{{trends}}\n</ul>\n\
+                    <h2>Blind spots</h2>\n<ul>\n{{blind_spots}}\n</ul>\n\
+                    </body>\n</html>\n"
+                    .to_string(),
+                story: "<li><a href=\"{{url}}\">{{title}}</a> <span class=\"meta\">{{source}}</span></li>".to_string(),
+                trend: "<li><strong>{{topic}}</strong>: {{volume}} articles <span class=\"meta\">{{velocity}}</span></li>"
+                    .to_string(),
+                blind_spot: "<li><strong>{{topic}}</strong> is busy and new to you. Start with <a href=\"{{url}}\">{{title}}</a></li>"
+                    .to_string(),
+                empty: "<li class=\"meta\">Nothing today.</li>".to_string(),
+            },
+            RenderFormat::Text => Self {
+                document: "TECH PULSE BRIEFING, {{date}}\n\n\
+                    TOP STORIES\n{{stories}}\n\n\
+                    TRENDING TOPICS\n{{trends}}\n\n\
+                    BLIND SPOTS\n{{blind_spots}}\n"
+                    .to_string(),
+                story: "* {{title}} ({{source}})\n  {{url}}".to_string(),
+                trend: "* {{topic}}: {{volume}} articles ({{velocity}})".to_string(),
+                blind_spot: "* {{topic}} is busy and new to you. Start with: {{title}}\n  {{url}}".to_string(),
+                empty: "Nothing today.".to_string(),
+            },
+        }
+    }
+
+    /// Replaces the document template, keeping the item templates.
+    pub fn with_document(mut self, document: impl Into<String>) -> Self {
+        self.document = document.into();
+        self
+    }
+}
+
+/// Renders `briefing` with `template`. Values are escaped for `format`; templates are used verbatim.
+pub fn render_briefing(briefing: &Briefing, format: RenderFormat, template: &BriefingTemplate) -> String {
+    let esc = |value: &str| format.escape(value);
+    let section = |items: Vec<String>| {
+        if items.is_empty() {
+            template.empty.clone()
+        } else {
+            items.join("\n")
+        }
+    };
+
+    let stories = briefing
+        .stories
+        .iter()
+        .map(|s| fill(&template.story, &story_values(s, &esc)))
+        .collect();
+
+    let trends = briefing
+        .trends
+        .iter()
+        .map(|t| {
+            fill(
+                &template.trend,
+                &[
+                    ("topic", esc(&t.topic)),
+                    ("volume", t.volume.to_string()),
+                    ("velocity", format_velocity(t.velocity)),
+                ],
+            )
+        })
+        .collect();
+
+    let blind_spots = briefing
+        .nudges
+        .iter()
+        .map(|n| {
+            let mut values = vec![("topic", esc(&n.topic)), ("severity", format!("{:.1}", n.severity))];
+            match &n.story {
+                Some(story) => values.extend(story_values(story, &esc)),
+                None => values.extend([("title", esc(&n.topic)), ("url", String::new()), ("source", String::new())]),
+            }
+            fill(&template.blind_spot, &values)
+        })
+        .collect();
+
+    fill(
+        &template.document,
+        &[
+            ("date", briefing.date.to_string()),
+            ("user", esc(&briefing.user_id.to_string())),
+            ("stories", section(stories)),
+            ("trends", section(trends)),
+            ("blind_spots", section(blind_spots)),
+        ],
+    )
+}
+
+/// Builds briefing emails: always a plain-text part, plus an HTML alternative when wanted.
+#[derive(Debug, Clone)]
+pub struct BriefingEmailComposer {
+    pub html: BriefingTemplate,
+    pub text: BriefingTemplate,
+}
+
+impl Default for BriefingEmailComposer {
+    fn default() -> Self {
+        Self {
+            html: BriefingTemplate::for_format(RenderFormat::Html),
+            text: BriefingTemplate::for_format(RenderFormat::Text),
+        }
+    }
+}
+
+impl BriefingComposer for BriefingEmailComposer {
+    fn compose(&self, briefing: &Briefing, to: &str, html: bool) -> EmailMessage {
+        EmailMessage {
+            to: to.to_string(),
+            subject: format!("Your Tech Pulse briefing for {}", briefing.date),
+            text_body: render_briefing(briefing, RenderFormat::Text, &self.text),
+            html_body: html.then(|| render_briefing(briefing, RenderFormat::Html, &self.html)),
+        }
+    }
+}
+
+fn story_values(story: &BriefingStory, esc: &impl Fn(&str) -> String) -> Vec<(&'static str, String)> {
+    vec![
+        ("title", esc(&story.title)),
+        ("url", esc(&story.url)),
+        ("source", esc(&story.source)),
+        ("score", format!("{:.1}", story.score)),
+    ]
+}
+
+fn format_velocity(velocity: f64) -> String {
+    format!("{:+.0}%", velocity * 100.0)
+}
+
+// Single pass, so placeholders inside substituted values are never expanded
+fn fill(template: &str, values: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after.find("}}") {
+            Some(end) => {
+                let key = after[..end].trim();
+                match values.iter().find(|(k, _)| *k == key) {
+                    Some((_, value)) => out.push_str(value),
+                    None => out.push_str(&rest[start..start + 2 + end + 2]),
+                }
+                rest = &after[end + 2..];
+            }
+            None => {
+                out.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn escape_html(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+// Only the characters that would break link and emphasis syntax
+fn escape_markdown(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '[' | ']' | '(' | ')' | '*' | '_' | '`' | '\\') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use techpulse_domain::article::ArticleId;
+    use techpulse_domain::briefing::{BriefingNudge, BriefingTrend};
+    use techpulse_domain::user::UserId;
+
+    fn briefing() -> Briefing {
+        let story = BriefingStory {
+            article_id: ArticleId::from_persisted("hn-1".into()),
+            title: "Rust <2.0> & [friends]".into(),
+            url: "https://example.com/rust".into(),
+            source: "hn".into(),
+            score: 12.345,
+        };
+        Briefing {
+            user_id: UserId::from("u1"),
+            date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            generated_at: 0,
+            stories: vec![story.clone()],
+            trends: vec![BriefingTrend { topic: "rust".into(), volume: 4, velocity: 0.5 }],
+            nudges: vec![BriefingNudge { topic: "cloud".into(), severity: 1.8, story: Some(story) }],
+            muted: 0,
+        }
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let out = render_briefing(&briefing(), RenderFormat::Markdown, &BriefingTemplate::for_format(RenderFormat::Markdown));
+
+        assert!(out.starts_with("# Tech Pulse briefing, 2024-03-01"));
+        assert!(out.contains("- [Rust <2.0> & \\[friends\\]](https://example.com/rust) (hn)"));
+        assert!(out.contains("- **rust**: 4 articles (+50%)"));
+        assert!(out.contains("- **cloud** is busy"));
+    }
+
+    #[test]
+    fn test_render_html_escapes_values() {
+        let out = render_briefing(&briefing(), RenderFormat::Html, &BriefingTemplate::for_format(RenderFormat::Html));
+
+        assert!(out.starts_with("<!DOCTYPE html>"));
+        assert!(out.contains("Rust &lt;2.0&gt; &amp; [friends]"));
+        assert!(!out.contains("<2.0>"));
+        assert!(out.contains("<style>")); // Self-contained, no external assets
+    }
+
+    #[test]
+    fn test_render_text_and_empty_sections() {
+        let mut empty = briefing();
+        empty.stories.clear();
+        empty.nudges.clear();
+        let out = render_briefing(&empty, RenderFormat::Text, &BriefingTemplate::for_format(RenderFormat::Text));
+
+        assert!(out.contains("TOP STORIES\nNothing today."));
+        assert!(out.contains("* rust: 4 articles (+50%)"));
+    }
+
+    #[test]
+    fn test_custom_document_template() {
+        let template = BriefingTemplate::for_format(RenderFormat::Text)
+            .with_document("{{user}} on {{date}}: {{trends}} {{unknown}}");
+        let out = render_briefing(&briefing(), RenderFormat::Text, &template);
+
+        assert_eq!(out, "u1 on 2024-03-01: * rust: 4 articles (+50%) {{unknown}}");
+    }
+
+    #[test]
+    fn test_email_composer() {
+        let composer = BriefingEmailComposer::default();
+
+        let message = composer.compose(&briefing(), "ada@example.com", true);
+        assert_eq!(message.subject, "Your Tech Pulse briefing for 2024-03-01");
+        assert!(message.text_body.starts_with("TECH PULSE BRIEFING"));
+        assert!(message.html_body.unwrap().starts_with("<!DOCTYPE html>"));
+
+        assert!(composer.compose(&briefing(), "ada@example.com", false).html_body.is_none());
+    }
+
+    #[test]
+    fn test_fill_does_not_expand_substituted_values() {
+        let out = fill("{{a}} {{b", &[("a", "{{a}}".to_string())]);
+        assert_eq!(out, "{{a}} {{b");
+    }
+
+    #[test]
+    fn test_format_roundtrip() {
+        for format in [RenderFormat::Markdown, RenderFormat::Html, RenderFormat::Text] {
+            assert_eq!(format.as_str().parse::<RenderFormat>().unwrap(), format);
+        }
+        assert_eq!("md".parse::<RenderFormat>().unwrap(), RenderFormat::Markdown);
+        assert!("pdf".parse::<RenderFormat>().is_err());
+    }
+}
@@ -46,6 +46,15 @@ impl ArticleId {
         
         Ok(Self(format!("{}-{}", source, native_id)))
     }
+    /// Parse an ID received from a client (e.g. in a URL path).
+    /// Only the `<source>-<native id>` shape is checked; the article may not exist.
+    pub fn parse(s: &str) -> Result<Self, DomainError> {
+        match s.rsplit_once('-') {
+            Some((source, native)) if !source.is_empty() && !native.is_empty() => Ok(Self(s.to_string())),
+            _ => Err(DomainError::Validation(format!("Malformed article ID '{}'", s))),
+        }
+    }
+
     /// Reconstruct from a previously-validated stored string.
     /// Only use for DB deserialization — not for creating new IDs.
     pub fn from_persisted(s: String) -> Self {
@@ -94,6 +103,14 @@ pub struct Article {
     pub tags: HashSet<String>,
     pub comment_count: u32,
     pub is_hot_on_source: bool,
+    #[serde(default)]
+    pub text: String, // Self-post body or extracted page text, empty when unknown
+    #[serde(default)]
+    pub first_seen_at: i64, // When an ingest first stored it, 0 when unknown
+    #[serde(default)]
+    pub last_seen_at: i64,
+    #[serde(default)]
+    pub times_seen: u32, // Ingests that returned it
 }
 
 impl Article {
@@ -115,9 +132,52 @@ impl Article {
             tags: HashSet::new(),
             comment_count: 0,
             is_hot_on_source: false,
+            text: String::new(),
+            first_seen_at: 0,
+            last_seen_at: 0,
+            times_seen: 0,
         })
     }
 
+    /// Marks the article as fetched at `now`, before it is saved.
+    pub fn seen_at(mut self, now: i64) -> Self {
+        self.first_seen_at = now;
+        self.last_seen_at = now;
+        self.times_seen = 1;
+        self
+    }
+
+    /// This stored article after another sighting of it. What readers know it by (title, URL,
+    /// author, source, publish time) stays as first seen, tags and text are only filled in when
+    /// missing, and engagement takes the fresh values.
+    pub fn merged_with(&self, fresh: &Article) -> Article {
+        Article {
+            tags: if self.tags.is_empty() { fresh.tags.clone() } else { self.tags.clone() },
+            text: if self.text.is_empty() { fresh.text.clone() } else { self.text.clone() },
+            score: fresh.score,
+            comment_count: fresh.comment_count,
+            is_hot_on_source: fresh.is_hot_on_source,
+            last_seen_at: self.last_seen_at.max(fresh.last_seen_at),
+            times_seen: self.times_seen.max(1) + 1,
+            ..self.clone()
+        }
+    }
+
+    /// Publish time or last ingest, whichever is later; retention counts from here.
+    pub fn last_activity(&self) -> i64 {
+        self.timestamp.max(self.last_seen_at)
+    }
+
+    /// Lower-cased host of the URL without port, credentials or a leading "www.".
+    pub fn domain(&self) -> String {
+        let url = self.url.as_str();
+        let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let host = rest.split(['/', '?', '#']).next().unwrap_or("");
+        let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+        let host = host.split(':').next().unwrap_or("").to_lowercase();
+        host.strip_prefix("www.").map(str::to_string).unwrap_or(host)
+    }
+
     // Simple decaying score calculation example
     pub fn calculate_score(&self, now: i64) -> f64 {
         // Clamp age to 0 to prevent future-dated articles from getting infinite/huge scores
@@ -134,6 +194,35 @@ impl Article {
     }
 }
 
+/// Whether saving an article stored a new one or merged into an existing one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    Inserted,
+    Updated,
+}
+
+/// Source-reported metrics of an article as seen by one ingest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArticleSnapshot {
+    pub article_id: ArticleId,
+    pub captured_at: i64,
+    pub score: f64,
+    pub comment_count: u32,
+    pub is_hot_on_source: bool,
+}
+
+impl ArticleSnapshot {
+    pub fn of(article: &Article, captured_at: i64) -> Self {
+        Self {
+            article_id: article.id.clone(),
+            captured_at,
+            score: article.score,
+            comment_count: article.comment_count,
+            is_hot_on_source: article.is_hot_on_source,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +253,15 @@ mod tests {
         assert!(ArticleId::new(&Source::Reddit("sub-reddit".into()), "123").is_err());
     }
 
+    #[test]
+    fn test_article_id_parse() {
+        assert_eq!(ArticleId::parse("hn-123").unwrap(), ArticleId::new(&Source::HackerNews, "123").unwrap());
+        assert_eq!(ArticleId::parse("rd-rust-9").unwrap().to_string(), "rd-rust-9");
+        assert!(ArticleId::parse("hn").is_err());
+        assert!(ArticleId::parse("hn-").is_err());
+        assert!(ArticleId::parse("-123").is_err());
+    }
+
     #[test]
     fn test_score_decay() {
         let now = 1700000000;
@@ -214,4 +312,34 @@ mod tests {
         
         assert!(hot_score > boosted_score);
     }
+
+    #[test]
+    fn test_merge_keeps_identity_and_updates_engagement() {
+        let mut stored = Article::new(Source::HackerNews, "1", "Show HN: Foo".into(), "https://foo.dev".into(), 100)
+            .unwrap()
+            .seen_at(1000);
+        stored.tags.insert("rust".into());
+
+        let mut fresh = Article::new(Source::HackerNews, "1", "Show HN: Foo (2024)".into(), "https://foo.dev/v2".into(), 150)
+            .unwrap()
+            .seen_at(2000);
+        fresh.tags.insert("tools".into());
+        fresh.text = "Foo is a tool".into();
+        fresh.score = 80.0;
+        fresh.comment_count = 42;
+        fresh.is_hot_on_source = true;
+
+        let merged = stored.merged_with(&fresh);
+        assert_eq!(merged.title, "Show HN: Foo");
+        assert_eq!(merged.url, "https://foo.dev");
+        assert_eq!(merged.timestamp, 100);
+        assert_eq!(merged.tags, stored.tags);
+        assert_eq!(merged.text, "Foo is a tool");
+        assert_eq!((merged.score, merged.comment_count, merged.is_hot_on_source), (80.0, 42, true));
+        assert_eq!((merged.first_seen_at, merged.last_seen_at, merged.times_seen), (1000, 2000, 2));
+
+        // An out-of-order sighting never moves last_seen_at back
+        let late = merged.merged_with(&fresh.clone().seen_at(1500));
+        assert_eq!((late.last_seen_at, late.times_seen), (2000, 3));
+    }
 }
@@ -0,0 +1,98 @@
+// Domain entities for blind-spot discovery
+use crate::article::ArticleId;
+use crate::user::{KnowledgeMap, KnowledgeState};
+use serde::{Deserialize, Serialize};
+
+/// A high-activity topic the user does not know yet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlindSpot {
+    pub topic: String,
+    pub state: Option<KnowledgeState>, // None if the user never interacted with the topic
+    pub volume: u32,                   // Articles in the current window
+    pub velocity: f64,                 // Relative change against the previous window
+    pub severity: f64,
+    pub related_articles: Vec<ArticleId>, // Highest scoring first
+}
+
+/// Decides which active topics count as blind spots and how to rank them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlindSpotPolicy {
+    pub min_volume: u32,
+    pub velocity_weight: f64,
+    pub untouched_factor: f64,   // Topic absent from the knowledge map
+    pub never_seen_factor: f64,
+    pub heard_of_factor: f64,
+}
+
+impl Default for BlindSpotPolicy {
+    fn default() -> Self {
+        Self {
+            min_volume: 3,
+            velocity_weight: 0.5,
+            untouched_factor: 1.0,
+            never_seen_factor: 1.0,
+            heard_of_factor: 0.6,
+        }
+    }
+}
+
+impl BlindSpotPolicy {
+    /// Weight of a knowledge state, or None when the topic is already covered.
+    pub fn state_factor(&self, state: Option<&KnowledgeState>) -> Option<f64> {
+        match state {
+            None => Some(self.untouched_factor),
+            Some(KnowledgeState::NeverSeen) => Some(self.never_seen_factor),
+            Some(KnowledgeState::HeardOf) => Some(self.heard_of_factor),
+            Some(KnowledgeState::KnowIt) | Some(KnowledgeState::WantToLearn) => None,
+        }
+    }
+
+    /// Log-damped volume boosted by growth; shrinking topics are not penalized below their volume.
+    pub fn activity(&self, volume: u32, velocity: f64) -> f64 {
+        (1.0 + volume as f64).ln() * (1.0 + self.velocity_weight * velocity.max(0.0))
+    }
+
+    /// Severity of `topic` for this knowledge map, or None if it is not a blind spot.
+    pub fn severity(&self, knowledge: &KnowledgeMap, topic: &str, volume: u32, velocity: f64) -> Option<f64> {
+        if volume < self.min_volume.max(1) {
+            return None;
+        }
+        let state = knowledge.topics.get(topic).map(|k| &k.state);
+        self.state_factor(state)
+            .map(|factor| factor * self.activity(volume, velocity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user::{UserId, UserProfile};
+
+    #[test]
+    fn test_known_topics_are_not_blind_spots() {
+        let policy = BlindSpotPolicy::default();
+        let mut user = UserProfile::new(UserId::from("u1"));
+        user.update_knowledge("rust", KnowledgeState::KnowIt, 0);
+        user.update_knowledge("llm", KnowledgeState::WantToLearn, 0);
+        user.update_knowledge("go", KnowledgeState::HeardOf, 0);
+
+        assert!(policy.severity(&user.knowledge, "rust", 10, 0.0).is_none());
+        assert!(policy.severity(&user.knowledge, "llm", 10, 0.0).is_none());
+
+        let heard_of = policy.severity(&user.knowledge, "go", 10, 0.0).unwrap();
+        let untouched = policy.severity(&user.knowledge, "zig", 10, 0.0).unwrap();
+        assert!(untouched > heard_of);
+    }
+
+    #[test]
+    fn test_severity_grows_with_volume_and_velocity() {
+        let policy = BlindSpotPolicy::default();
+        let knowledge = KnowledgeMap::default();
+
+        assert!(policy.severity(&knowledge, "zig", 2, 0.0).is_none()); // Below min_volume
+        let base = policy.severity(&knowledge, "zig", 5, 0.0).unwrap();
+        assert!(policy.severity(&knowledge, "zig", 20, 0.0).unwrap() > base);
+        assert!(policy.severity(&knowledge, "zig", 5, 2.0).unwrap() > base);
+        assert_eq!(policy.severity(&knowledge, "zig", 5, -0.5).unwrap(), base);
+    }
+}
@@ -0,0 +1,61 @@
+// Domain entities for the daily briefing ("Today" view)
+use crate::article::ArticleId;
+use crate::user::UserId;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Snapshot of an article as shown in a briefing; later edits to the article do not change it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BriefingStory {
+    pub article_id: ArticleId,
+    pub title: String,
+    pub url: String,
+    pub source: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BriefingTrend {
+    pub topic: String,
+    pub volume: u32,
+    pub velocity: f64,
+}
+
+/// A blind spot surfaced in the briefing, with one story to start from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BriefingNudge {
+    pub topic: String,
+    pub severity: f64,
+    pub story: Option<BriefingStory>,
+}
+
+/// One user's briefing for one day. Generated once, then served unchanged all day.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Briefing {
+    pub user_id: UserId,
+    pub date: NaiveDate,
+    pub generated_at: i64,
+    pub stories: Vec<BriefingStory>,
+    pub trends: Vec<BriefingTrend>,
+    pub nudges: Vec<BriefingNudge>,
+    #[serde(default)]
+    pub muted: usize, // Stories hidden by the user's mute rules
+}
+
+/// How much goes into a briefing; it is meant to be read in a few minutes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BriefingLimits {
+    pub stories: usize,
+    pub trends: usize,
+    pub nudges: usize,
+}
+
+impl Default for BriefingLimits {
+    fn default() -> Self {
+        Self {
+            stories: 5,
+            trends: 3,
+            nudges: 2,
+        }
+    }
+}
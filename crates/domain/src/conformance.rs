@@ -0,0 +1,677 @@
+//! Behaviour every repository implementation must share, written once so the in-memory, SQLite
+//! and Postgres repos cannot drift apart on ordering, limits, merges or missing rows. There is one
+//! entry point per repository trait; each takes a factory for an empty repository and gives every
+//! check a fresh one. Checks panic on failure. Built with the `conformance` feature.
+use crate::article::{Article, ArticleId, ArticleSnapshot, SaveOutcome, Source};
+use crate::briefing::{Briefing, BriefingStory};
+use crate::cooccurrence::CooccurrenceGraph;
+use crate::delivery::{BriefingDelivery, DeliveryStatus, RetryPolicy};
+use crate::interaction::{InteractionEvent, InteractionKind};
+use crate::mute::{MuteKind, MuteRule};
+use crate::query::ArticleQuery;
+use crate::repository::{
+    AlertRepo, ArticleRepo, BriefingRepo, CooccurrenceRepo, DeliveryRepo, InteractionRepo, SnapshotRepo, TimelineRepo,
+    TopicRepo, TrendRepo, UserRepo, WebhookRepo,
+};
+use crate::search::{SearchHit, SearchQuery, HIGHLIGHT_CLOSE, HIGHLIGHT_OPEN};
+use crate::time::TimeWindow;
+use crate::topic::{Topic, TopicCategory, TopicSlug};
+use crate::trend::{TimelineEvent, TimelineEventId, Trend, TrendReport};
+use crate::user::{KnowledgeState, UserId, UserProfile};
+use crate::watchlist::{WatchAlert, WatchRule};
+use crate::webhook::{WebhookData, WebhookDelivery, WebhookEvent, WebhookEventType, WebhookSubscription};
+use chrono::{Datelike, NaiveDate};
+use std::collections::{BTreeSet, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+
+pub async fn conform_article_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn ArticleRepo>>,
+{
+    article_round_trip(new_repo().await.as_ref()).await;
+    article_save_merges(new_repo().await.as_ref()).await;
+    article_latest_order_and_limit(new_repo().await.as_ref()).await;
+    article_query_filters_and_pages(new_repo().await.as_ref()).await;
+    article_search(new_repo().await.as_ref()).await;
+    article_prune(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_snapshot_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn SnapshotRepo>>,
+{
+    snapshot_series_and_prune(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_trend_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn TrendRepo>>,
+{
+    trend_latest_report(new_repo().await.as_ref()).await;
+    trend_prune_keeps_latest(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_topic_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn TopicRepo>>,
+{
+    topic_upsert_and_order(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_cooccurrence_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn CooccurrenceRepo>>,
+{
+    cooccurrence_graph_per_window(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_timeline_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn TimelineRepo>>,
+{
+    timeline_round_trip(new_repo().await.as_ref()).await;
+    timeline_order_and_delete(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_user_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn UserRepo>>,
+{
+    user_round_trip(new_repo().await.as_ref()).await;
+    user_save_replaces_and_lists(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_interaction_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn InteractionRepo>>,
+{
+    interaction_order_and_bookmarks(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_briefing_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn BriefingRepo>>,
+{
+    briefing_per_day(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_delivery_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn DeliveryRepo>>,
+{
+    delivery_log(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_webhook_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn WebhookRepo>>,
+{
+    webhook_subscriptions_and_deliveries(new_repo().await.as_ref()).await;
+}
+
+pub async fn conform_alert_repo<F, Fut>(new_repo: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn AlertRepo>>,
+{
+    alert_first_match_wins(new_repo().await.as_ref()).await;
+}
+
+fn article(id: &str, title: &str, timestamp: i64) -> Article {
+    Article::new(Source::HackerNews, id, title.into(), format!("https://example.com/{}", id), timestamp).unwrap()
+}
+
+fn ids(articles: &[Article]) -> Vec<String> {
+    articles.iter().map(|a| a.id.to_string()).collect()
+}
+
+fn hit_ids(hits: &[SearchHit]) -> Vec<String> {
+    hits.iter().map(|h| h.article.id.to_string()).collect()
+}
+
+fn article_id(id: &str) -> ArticleId {
+    ArticleId::parse(id).unwrap()
+}
+
+async fn article_round_trip(repo: &dyn ArticleRepo) {
+    let sources = [
+        Source::HackerNews,
+        Source::GitHub,
+        Source::ProductHunt,
+        Source::ArXiv,
+        Source::Reddit("rust".into()),
+        Source::Custom("lobsters".into()),
+    ];
+    for (i, source) in sources.into_iter().enumerate() {
+        let mut stored = Article::new(source, &i.to_string(), "Title".into(), "".into(), 100).unwrap().seen_at(500);
+        stored.score = 12.5;
+        stored.author = "ada".into();
+        stored.comment_count = 7;
+        stored.is_hot_on_source = true;
+        stored.text = "Body".into();
+        stored.tags = ["rust".to_string(), "async".to_string()].into_iter().collect();
+        assert_eq!(repo.save(&stored).await.unwrap(), SaveOutcome::Inserted);
+
+        let found = repo.find_by_id(&stored.id).await.unwrap().expect("saved article is found");
+        assert_eq!((&found.id, &found.source, &found.title, &found.url), (&stored.id, &stored.source, &stored.title, &stored.url));
+        assert_eq!((found.score, found.author.as_str(), found.timestamp), (12.5, "ada", 100));
+        assert_eq!((found.comment_count, found.is_hot_on_source), (7, true));
+        assert_eq!((found.text.as_str(), &found.tags), ("Body", &stored.tags));
+        assert_eq!((found.first_seen_at, found.last_seen_at, found.times_seen), (500, 500, 1));
+    }
+    assert!(repo.find_by_id(&article_id("hn-404")).await.unwrap().is_none());
+
+    // Articles stored without a sighting still count as seen once
+    let unseen = article("u", "Unseen", 100);
+    repo.save(&unseen).await.unwrap();
+    assert_eq!(repo.find_by_id(&unseen.id).await.unwrap().unwrap().times_seen, 1);
+}
+
+async fn article_save_merges(repo: &dyn ArticleRepo) {
+    let first = article("1", "Show HN: Foo", 100).seen_at(1000);
+    let mut again = article("1", "Show HN: Foo v2", 150).seen_at(2000);
+    again.score = 55.0;
+    again.comment_count = 31;
+    again.is_hot_on_source = true;
+    again.text = "Foo is a tool".into();
+    again.tags.insert("tools".into());
+    let older_sighting = again.clone().seen_at(1500);
+
+    let outcomes = repo
+        .save_many(&[first.clone(), article("2", "Bar", 100), again.clone(), older_sighting.clone()])
+        .await
+        .unwrap();
+    assert_eq!(outcomes, vec![SaveOutcome::Inserted, SaveOutcome::Inserted, SaveOutcome::Updated, SaveOutcome::Updated]);
+    assert!(repo.save_many(&[]).await.unwrap().is_empty());
+    // A new article is inserted whatever sightings it carries
+    let mut resighted = article("3", "Baz", 100).seen_at(1000);
+    resighted.times_seen = 4;
+    assert_eq!(repo.save(&resighted).await.unwrap(), SaveOutcome::Inserted);
+    assert_eq!(repo.save(&resighted).await.unwrap(), SaveOutcome::Updated);
+
+    let stored = repo.find_by_id(&first.id).await.unwrap().unwrap();
+    let expected = first.merged_with(&again).merged_with(&older_sighting);
+    assert_eq!((stored.title.as_str(), stored.url.as_str(), stored.timestamp), ("Show HN: Foo", first.url.as_str(), 100));
+    assert_eq!((stored.score, &stored.text, &stored.tags), (expected.score, &expected.text, &expected.tags));
+    assert_eq!((stored.comment_count, stored.is_hot_on_source), (31, true));
+    assert_eq!((stored.first_seen_at, stored.last_seen_at, stored.times_seen), (1000, 2000, 3));
+    assert_eq!(stored.times_seen, expected.times_seen);
+}
+
+async fn article_latest_order_and_limit(repo: &dyn ArticleRepo) {
+    assert!(repo.find_latest(10).await.unwrap().is_empty());
+    repo.save_many(&[article("1", "A", 200), article("3", "B", 300), article("2", "C", 300), article("4", "D", 100)])
+        .await
+        .unwrap();
+
+    // Newest first, ties broken by descending ID like ArticleRepo::query
+    assert_eq!(ids(&repo.find_latest(10).await.unwrap()), vec!["hn-3", "hn-2", "hn-1", "hn-4"]);
+    assert_eq!(ids(&repo.find_latest(2).await.unwrap()), vec!["hn-3", "hn-2"]);
+    assert!(repo.find_latest(0).await.unwrap().is_empty());
+}
+
+async fn article_query_filters_and_pages(repo: &dyn ArticleRepo) {
+    let fixtures = [
+        (Source::HackerNews, "1", "Rust 2.0 released", "https://blog.rust-lang.org/2.0", 500, 80.0, "rust"),
+        (Source::Reddit("rust".into()), "2", "Async Rust patterns", "https://www.example.com/async", 400, 40.0, "async"),
+        (Source::Reddit("golang".into()), "3", "Go 1.23", "https://go.dev/blog", 300, 60.0, "go"),
+        (Source::HackerNews, "4", "SQLite 100%_fast", "https://sqlite.org", 300, 20.0, "db"),
+        (Source::GitHub, "5", "tokio", "http://user@docs.Example.com:8080/x", 100, 90.0, "Async"),
+    ];
+    let mut articles = Vec::new();
+    for (source, id, title, url, timestamp, score, tag) in fixtures {
+        let mut article = Article::new(source, id, title.into(), url.into(), timestamp).unwrap();
+        article.score = score;
+        article.tags.insert(tag.to_string());
+        articles.push(article);
+    }
+    repo.save_many(&articles).await.unwrap();
+
+    // Keyset pages; the last page has no cursor even when it is exactly full
+    for (limit, expected) in [
+        (2, vec![vec!["hn-1", "rd-rust-2"], vec!["rd-golang-3", "hn-4"], vec!["gh-5"]]),
+        (5, vec![vec!["hn-1", "rd-rust-2", "rd-golang-3", "hn-4", "gh-5"]]),
+    ] {
+        let mut query = ArticleQuery::latest(limit);
+        let mut pages = Vec::new();
+        loop {
+            let page = repo.query(&query).await.unwrap();
+            pages.push(ids(&page.articles));
+            match page.next_cursor {
+                Some(cursor) => query.cursor = Some(cursor),
+                None => break,
+            }
+        }
+        assert_eq!(pages, expected, "pages of {}", limit);
+    }
+
+    let filtered = |f: fn(&mut ArticleQuery)| {
+        let mut query = ArticleQuery::latest(10);
+        f(&mut query);
+        let query = query.normalized().unwrap();
+        async move { ids(&repo.query(&query).await.unwrap().articles) }
+    };
+    assert_eq!(filtered(|q| q.sources = vec!["rd".into()]).await, vec!["rd-rust-2", "rd-golang-3"]);
+    assert_eq!(filtered(|q| q.sources = vec!["rd-golang".into(), "gh".into()]).await, vec!["rd-golang-3", "gh-5"]);
+    assert_eq!(filtered(|q| q.tags = vec!["ASYNC".into()]).await, vec!["rd-rust-2", "gh-5"]);
+    assert_eq!(filtered(|q| { q.since = Some(300); q.until = Some(500); }).await, vec!["rd-rust-2", "rd-golang-3", "hn-4"]);
+    assert_eq!(filtered(|q| q.min_score = Some(60.0)).await, vec!["hn-1", "rd-golang-3", "gh-5"]);
+    assert_eq!(filtered(|q| q.domain = Some("example.com".into())).await, vec!["rd-rust-2", "gh-5"]);
+    assert_eq!(filtered(|q| q.text = Some("rust".into())).await, vec!["hn-1", "rd-rust-2"]);
+    assert_eq!(filtered(|q| q.text = Some("100%_".into())).await, vec!["hn-4"]);
+    assert!(filtered(|q| q.domain = Some("e_ample.com".into())).await.is_empty());
+    assert!(filtered(|q| q.tags = vec!["rust".into(), "go".into()]).await.is_empty());
+}
+
+async fn article_search(repo: &dyn ArticleRepo) {
+    let search = |q: &'static str, limit: usize| async move {
+        let query: SearchQuery = q.parse().unwrap();
+        repo.search(&query, limit).await.unwrap()
+    };
+    assert!(search("tokio", 10).await.is_empty());
+
+    let mut rust = article("1", "Async Rust in production", 100);
+    rust.text = "We moved every service to tokio and never looked back.".into();
+    let mut sqlite = article("2", "SQLite internals", 200);
+    sqlite.tags.insert("databases".into());
+    sqlite.author = "drh".into();
+    let mut body_only = article("3", "Release notes", 300);
+    body_only.text = "Internals of the new SQLite query planner".into();
+    let mut newer_body_only = article("4", "Changelog", 400);
+    newer_body_only.text = body_only.text.clone();
+    let mut markup = article("5", "Escaping", 50);
+    markup.text = "Beware <img src=x onerror=alert(1)> & xss payloads".into();
+    repo.save_many(&[rust.clone(), sqlite, body_only, newer_body_only, markup]).await.unwrap();
+
+    let hits = search("tokio", 10).await;
+    assert_eq!(hit_ids(&hits), vec!["hn-1"]);
+    let highlighted = format!("{}tokio{}", HIGHLIGHT_OPEN, HIGHLIGHT_CLOSE);
+    assert!(hits[0].snippet.contains(&highlighted), "{}", hits[0].snippet);
+    assert_eq!(hit_ids(&search("\"async rust\"", 10).await), vec!["hn-1"]);
+    assert!(search("\"rust async\"", 10).await.is_empty());
+    assert_eq!(hit_ids(&search("datab*", 10).await), vec!["hn-2"]);
+    assert_eq!(hit_ids(&search("DRH", 10).await), vec!["hn-2"]);
+    assert!(search("sqlite tokio", 10).await.is_empty());
+    // Only the highlight tags in a snippet are markup
+    let hits = search("xss", 10).await;
+    assert_eq!(hit_ids(&hits), vec!["hn-5"]);
+    let highlighted = format!("{}xss{}", HIGHLIGHT_OPEN, HIGHLIGHT_CLOSE);
+    assert!(hits[0].snippet.contains(&highlighted), "{}", hits[0].snippet);
+    assert!(!hits[0].snippet.replace(&highlighted, "").contains(['<', '>']), "{}", hits[0].snippet);
+    // Query syntax of the underlying engines is not interpreted
+    assert!(search("NEAR(sqlite internals) OR", 10).await.is_empty());
+
+    // Title matches outrank body matches; equally relevant hits come newest first
+    let hits = search("sqlite", 10).await;
+    assert_eq!(hit_ids(&hits), vec!["hn-2", "hn-4", "hn-3"]);
+    assert!(hits[0].relevance > hits[1].relevance);
+    assert_eq!(hit_ids(&search("sqlite", 2).await), vec!["hn-2", "hn-4"]);
+    assert!(search("sqlite", 0).await.is_empty());
+
+    // What a merge fills in becomes searchable
+    rust.tags.insert("concurrency".into());
+    repo.save(&rust).await.unwrap();
+    assert_eq!(hit_ids(&search("concurrency", 10).await), vec!["hn-1"]);
+}
+
+async fn article_prune(repo: &dyn ArticleRepo) {
+    assert_eq!(repo.prune(1000, &HashSet::new()).await.unwrap(), 0);
+
+    // hn-3 was published long ago but is still being ingested
+    let mut still_seen = article("3", "Old story", 100);
+    still_seen.last_seen_at = 5000;
+    repo.save_many(&[article("1", "Old story", 100), article("2", "Old story", 100), still_seen, article("4", "New story", 1000)])
+        .await
+        .unwrap();
+
+    let keep: HashSet<ArticleId> = [article_id("hn-2")].into_iter().collect();
+    assert_eq!(repo.prune(1000, &keep).await.unwrap(), 1);
+    assert_eq!(ids(&repo.find_latest(10).await.unwrap()), vec!["hn-4", "hn-3", "hn-2"]);
+    assert_eq!(repo.search(&"story".parse().unwrap(), 10).await.unwrap().len(), 3);
+    assert_eq!(repo.prune(1000, &keep).await.unwrap(), 0);
+}
+
+fn report(timestamp: i64, keyword: &str) -> TrendReport {
+    let trend = Trend {
+        keyword: keyword.into(),
+        score: 1.5,
+        volume: 10,
+        velocity: 0.25,
+        related_articles: vec![article_id("hn-1")],
+    };
+    TrendReport {
+        timestamp,
+        trends: vec![trend],
+        metadata: [("window".to_string(), "24h".to_string())].into_iter().collect(),
+    }
+}
+
+async fn trend_latest_report(repo: &dyn TrendRepo) {
+    assert!(repo.find_latest_report().await.unwrap().is_none());
+
+    // Latest by timestamp, not by insertion; a tie goes to the report saved last
+    for (timestamp, keyword) in [(200, "first"), (300, "tied"), (100, "older"), (300, "rerun")] {
+        repo.save_report(&report(timestamp, keyword)).await.unwrap();
+    }
+    let latest = repo.find_latest_report().await.unwrap().unwrap();
+    assert_eq!((latest.timestamp, latest.trends[0].keyword.as_str()), (300, "rerun"));
+    assert_eq!(latest.trends[0].related_articles, vec![article_id("hn-1")]);
+    assert_eq!((latest.trends[0].volume, latest.trends[0].velocity), (10, 0.25));
+    assert_eq!(latest.metadata, report(0, "").metadata);
+}
+
+async fn trend_prune_keeps_latest(repo: &dyn TrendRepo) {
+    assert_eq!(repo.prune(1000).await.unwrap(), 0);
+    for (timestamp, keyword) in [(100, "a"), (200, "b"), (200, "c"), (2000, "d")] {
+        repo.save_report(&report(timestamp, keyword)).await.unwrap();
+    }
+    assert_eq!(repo.prune(1000).await.unwrap(), 3);
+    assert_eq!(repo.find_latest_report().await.unwrap().unwrap().trends[0].keyword, "d");
+
+    // Everything is past the cutoff, but the latest report always stays
+    assert_eq!(repo.prune(5000).await.unwrap(), 0);
+    assert_eq!(repo.find_latest_report().await.unwrap().unwrap().timestamp, 2000);
+}
+
+fn event(id: &str, date: (i32, u32, u32)) -> TimelineEvent {
+    TimelineEvent {
+        id: TimelineEventId::from(id),
+        title: id.to_uppercase(),
+        date: NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap(),
+        description: "".into(),
+        category: "".into(),
+        importance_score: 1.0,
+        url: None,
+        article_id: None,
+    }
+}
+
+async fn timeline_round_trip(repo: &dyn TimelineRepo) {
+    assert!(repo.list_events().await.unwrap().is_empty());
+    assert!(repo.find_event(&TimelineEventId::from("e1")).await.unwrap().is_none());
+
+    let linked = TimelineEvent {
+        description: "First".into(),
+        category: "ai".into(),
+        importance_score: 80.5,
+        url: Some("http://example.com/e1".into()),
+        article_id: Some(article_id("hn-1")),
+        ..event("e1", (2023, 1, 1))
+    };
+    repo.save_event(&linked).await.unwrap();
+    let found = repo.find_event(&linked.id).await.unwrap().unwrap();
+    assert_eq!((&found.title, found.date, &found.description), (&linked.title, linked.date, &linked.description));
+    assert_eq!((&found.category, found.importance_score), (&linked.category, 80.5));
+    assert_eq!((&found.url, &found.article_id), (&linked.url, &linked.article_id));
+
+    // Saving an existing ID replaces it, including clearing the links
+    let edited = TimelineEvent { title: "Edited".into(), ..event("e1", (2022, 6, 1)) };
+    repo.save_event(&edited).await.unwrap();
+    let events = repo.list_events().await.unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!((events[0].title.as_str(), events[0].date, &events[0].url, &events[0].article_id), ("Edited", edited.date, &None, &None));
+}
+
+async fn timeline_order_and_delete(repo: &dyn TimelineRepo) {
+    for e in [event("b", (2024, 1, 1)), event("c", (2023, 1, 1)), event("a", (2024, 1, 1)), event("d", (2024, 2, 1))] {
+        repo.save_event(&e).await.unwrap();
+    }
+    // Newest first, same-day events by ascending ID
+    let listed = |events: Vec<TimelineEvent>| events.iter().map(|e| e.id.to_string()).collect::<Vec<_>>();
+    assert_eq!(listed(repo.list_events().await.unwrap()), vec!["d", "a", "b", "c"]);
+
+    repo.delete_event(&TimelineEventId::from("a")).await.unwrap();
+    assert!(repo.find_event(&TimelineEventId::from("a")).await.unwrap().is_none());
+    // Deleting a missing event is not an error
+    repo.delete_event(&TimelineEventId::from("a")).await.unwrap();
+    assert_eq!(listed(repo.list_events().await.unwrap()), vec!["d", "b", "c"]);
+}
+
+async fn user_round_trip(repo: &dyn UserRepo) {
+    assert!(repo.find_by_id(&UserId::new("nobody")).await.unwrap().is_none());
+
+    let mut user = UserProfile::new(UserId::new("u1"));
+    user.settings.preferred_sources = vec!["hn".into(), "gh".into()];
+    user.settings.theme = "dark".into();
+    user.update_knowledge("rust", KnowledgeState::KnowIt, 1000);
+    user.update_knowledge("rust", KnowledgeState::KnowIt, 2000);
+    user.update_knowledge("webassembly", KnowledgeState::WantToLearn, 1500);
+    user.add_watch_rule(WatchRule::new("sqlite", "domain:sqlite.org", 10).unwrap()).unwrap();
+    user.add_mute(MuteRule::new(MuteKind::Regex, r"bitcoin.*price", Some(3600), 10).unwrap(), 10).unwrap();
+    repo.save(&user).await.unwrap();
+
+    let found = repo.find_by_id(&user.id).await.unwrap().unwrap();
+    assert_eq!(found.id, user.id);
+    assert_eq!(found.settings.preferred_sources, user.settings.preferred_sources);
+    assert_eq!(found.settings.theme, "dark");
+    assert_eq!((&found.watchlist, &found.mutes), (&user.watchlist, &user.mutes));
+    assert_eq!(found.knowledge.topics.len(), 2);
+    let rust = &found.knowledge.topics["rust"];
+    assert_eq!(rust.state, KnowledgeState::KnowIt);
+    assert_eq!((rust.first_seen, rust.last_interaction, rust.interaction_count), (1000, 2000, 2));
+    assert_eq!(found.knowledge.topics["webassembly"].state, KnowledgeState::WantToLearn);
+}
+
+async fn user_save_replaces_and_lists(repo: &dyn UserRepo) {
+    assert!(repo.list_all().await.unwrap().is_empty());
+
+    let mut user = UserProfile::new(UserId::new("u2"));
+    user.update_knowledge("rust", KnowledgeState::KnowIt, 1000);
+    user.update_knowledge("go", KnowledgeState::KnowIt, 1000);
+    user.add_watch_rule(WatchRule::new("sqlite", "domain:sqlite.org", 10).unwrap()).unwrap();
+    repo.save(&user).await.unwrap();
+    repo.save(&UserProfile::new(UserId::new("u1"))).await.unwrap();
+
+    // Saving again replaces the knowledge map and rules instead of merging into them
+    user.knowledge.topics.remove("go");
+    user.watchlist.clear();
+    user.settings.theme = "light".into();
+    repo.save(&user).await.unwrap();
+
+    let users = repo.list_all().await.unwrap();
+    assert_eq!(users.iter().map(|u| u.id.to_string()).collect::<Vec<_>>(), vec!["u1", "u2"]);
+    assert_eq!(users[1].knowledge.topics.keys().collect::<Vec<_>>(), vec!["rust"]);
+    assert!(users[1].watchlist.is_empty());
+    assert_eq!(users[1].settings.theme, "light");
+}
+
+async fn snapshot_series_and_prune(repo: &dyn SnapshotRepo) {
+    assert!(repo.list_for_article(&article_id("hn-1")).await.unwrap().is_empty());
+
+    let taken: Vec<ArticleSnapshot> = ["1", "2"]
+        .iter()
+        .flat_map(|id| [5000, 100].map(|at| ArticleSnapshot::of(&article(id, "Story", 0), at)))
+        .collect();
+    repo.append(&taken).await.unwrap();
+    let captured = |series: Vec<ArticleSnapshot>| series.iter().map(|s| s.captured_at).collect::<Vec<_>>();
+    assert_eq!(captured(repo.list_for_article(&article_id("hn-2")).await.unwrap()), vec![100, 5000]);
+
+    let keep: HashSet<ArticleId> = [article_id("hn-2")].into_iter().collect();
+    assert_eq!(repo.prune(1000, &keep).await.unwrap(), 1);
+    assert_eq!(captured(repo.list_for_article(&article_id("hn-1")).await.unwrap()), vec![5000]);
+    assert_eq!(captured(repo.list_for_article(&article_id("hn-2")).await.unwrap()), vec![100, 5000]);
+}
+
+async fn topic_upsert_and_order(repo: &dyn TopicRepo) {
+    let topic = Topic::new(TopicSlug::new("webassembly").unwrap(), "WebAssembly", TopicCategory::Web).with_aliases(&["wasm"]);
+    repo.save(&topic).await.unwrap();
+    repo.save(&Topic::new(TopicSlug::new("ai").unwrap(), "AI", TopicCategory::Ai)).await.unwrap();
+    assert_eq!(repo.find_by_slug(&topic.slug).await.unwrap(), Some(topic.clone()));
+    assert!(repo.find_by_slug(&TopicSlug::new("zig").unwrap()).await.unwrap().is_none());
+
+    // Saving an existing slug replaces it
+    repo.save(&Topic { display_name: "Wasm".into(), ..topic.clone() }).await.unwrap();
+    let all = repo.list_all().await.unwrap();
+    assert_eq!(all.iter().map(|t| t.slug.as_str()).collect::<Vec<_>>(), vec!["ai", "webassembly"]);
+    assert_eq!((all[1].display_name.as_str(), &all[1].aliases), ("Wasm", &topic.aliases));
+}
+
+async fn cooccurrence_graph_per_window(repo: &dyn CooccurrenceRepo) {
+    assert!(repo.find_latest_graph().await.unwrap().is_none());
+
+    let docs: Vec<BTreeSet<String>> = vec![["rust", "webassembly"].iter().map(|s| s.to_string()).collect(); 2];
+    let window = TimeWindow::new(1000, 2000).unwrap();
+    let graph = CooccurrenceGraph::build(window, docs, 1);
+    repo.save_graph(&graph).await.unwrap();
+    assert_eq!(repo.find_graph(&window).await.unwrap(), Some(graph));
+
+    // One graph per window: rebuilding replaces it
+    repo.save_graph(&CooccurrenceGraph::build(window, vec![], 1)).await.unwrap();
+    let later = CooccurrenceGraph::build(TimeWindow::new(2000, 3000).unwrap(), vec![], 1);
+    repo.save_graph(&later).await.unwrap();
+    assert_eq!(repo.find_graph(&window).await.unwrap().unwrap().document_count, 0);
+    assert_eq!(repo.find_latest_graph().await.unwrap(), Some(later));
+    assert!(repo.find_graph(&TimeWindow::new(1000, 3000).unwrap()).await.unwrap().is_none());
+}
+
+async fn interaction_order_and_bookmarks(repo: &dyn InteractionRepo) {
+    assert!(repo.bookmarked_articles().await.unwrap().is_empty());
+
+    let event = |user: &str, id: &str, kind: InteractionKind, timestamp: i64| InteractionEvent {
+        user_id: UserId::new(user),
+        article_id: article_id(id),
+        kind,
+        timestamp,
+    };
+    repo.append(&[
+        event("u1", "hn-1", InteractionKind::Dwell { seconds: 42 }, 300),
+        event("u1", "hn-1", InteractionKind::Open, 100),
+        event("u2", "hn-1", InteractionKind::Dismiss, 150),
+        event("u1", "hn-1", InteractionKind::MarkKnown, 300),
+    ])
+    .await
+    .unwrap();
+    repo.append(&[]).await.unwrap();
+
+    // Oldest first from `since` on; ties on timestamp keep insertion order
+    let kinds: Vec<InteractionKind> = repo.list_for_user(&UserId::new("u1"), 200).await.unwrap().iter().map(|e| e.kind).collect();
+    assert_eq!(kinds, vec![InteractionKind::Dwell { seconds: 42 }, InteractionKind::MarkKnown]);
+    assert_eq!(repo.list_for_user(&UserId::new("u1"), 0).await.unwrap().len(), 3);
+    assert!(repo.bookmarked_articles().await.unwrap().is_empty());
+
+    repo.append(&[event("u2", "hn-2", InteractionKind::WantToLearn, 400)]).await.unwrap();
+    assert_eq!(repo.bookmarked_articles().await.unwrap(), [article_id("hn-2")].into_iter().collect());
+}
+
+fn briefing(user: &str, day: u32) -> Briefing {
+    Briefing {
+        user_id: UserId::new(user),
+        date: NaiveDate::from_ymd_opt(2024, 3, day).unwrap(),
+        generated_at: day as i64,
+        stories: vec![BriefingStory {
+            article_id: article_id("hn-1"),
+            title: "Story".into(),
+            url: "http://a".into(),
+            source: "hn".into(),
+            score: 1.5,
+        }],
+        trends: vec![],
+        nudges: vec![],
+        muted: 0,
+    }
+}
+
+async fn briefing_per_day(repo: &dyn BriefingRepo) {
+    for (user, day) in [("u1", 1), ("u1", 3), ("u1", 2), ("u2", 3)] {
+        repo.save(&briefing(user, day)).await.unwrap();
+    }
+    let mut regenerated = briefing("u1", 2);
+    regenerated.generated_at = 99;
+    repo.save(&regenerated).await.unwrap();
+
+    let u1 = UserId::new("u1");
+    assert_eq!(repo.find(&u1, regenerated.date).await.unwrap(), Some(regenerated));
+    assert!(repo.find(&u1, NaiveDate::from_ymd_opt(2024, 3, 9).unwrap()).await.unwrap().is_none());
+    // Newest day first
+    let days: Vec<u32> = repo.list_for_user(&u1, 2).await.unwrap().iter().map(|b| b.date.day()).collect();
+    assert_eq!(days, vec![3, 2]);
+}
+
+async fn delivery_log(repo: &dyn DeliveryRepo) {
+    let u1 = UserId::new("u1");
+    let day = |d: u32| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+    assert!(repo.find(&u1, day(1)).await.unwrap().is_none());
+
+    let mut first = BriefingDelivery::new(u1.clone(), day(1), "ada@example.com");
+    first.state.record_success(100);
+    let mut second = BriefingDelivery::new(u1.clone(), day(2), "ada@example.com");
+    second.state.record_failure("SMTP error: timeout", 200, &RetryPolicy::default());
+    repo.save(&first).await.unwrap();
+    repo.save(&second).await.unwrap();
+    assert_eq!(repo.find(&u1, day(2)).await.unwrap(), Some(second.clone()));
+
+    // Saving the same user and day records the latest attempt
+    second.state.record_success(600);
+    repo.save(&second).await.unwrap();
+    let log = repo.list_for_user(&u1, 10).await.unwrap();
+    assert_eq!(log, vec![second, first]);
+    assert_eq!(log[0].state.status, DeliveryStatus::Sent);
+    assert_eq!(repo.list_for_user(&u1, 1).await.unwrap().len(), 1);
+}
+
+async fn webhook_subscriptions_and_deliveries(repo: &dyn WebhookRepo) {
+    assert!(repo.list_subscriptions().await.unwrap().is_empty());
+
+    let subscription = WebhookSubscription::new(
+        "https://hooks.example.com/tp",
+        "0123456789abcdef",
+        vec![WebhookEventType::TrendSpike, WebhookEventType::ArticleMatched],
+        &["Rust".to_string()],
+        100,
+    )
+    .unwrap();
+    repo.save_subscription(&subscription).await.unwrap();
+    assert_eq!(repo.list_subscriptions().await.unwrap(), vec![subscription.clone()]);
+    assert_eq!(repo.find_subscription(&subscription.id).await.unwrap(), Some(subscription.clone()));
+
+    let event = WebhookEvent::new(WebhookData::TrendSpike { keyword: "rust".into(), volume: 8, previous_volume: 2 }, 150);
+    let mut pending = WebhookDelivery::new(subscription.id.clone(), event.clone(), 200);
+    let mut retrying = WebhookDelivery::new(subscription.id.clone(), event, 210);
+    retrying.response_status = Some(503);
+    retrying.state.record_failure("HTTP 503", 210, &RetryPolicy::default());
+    repo.save_delivery(&pending).await.unwrap();
+    repo.save_delivery(&retrying).await.unwrap();
+
+    // Due deliveries exclude those waiting for a retry, and respect the limit
+    assert_eq!(repo.list_due_deliveries(300, 10).await.unwrap(), vec![pending.clone()]);
+    let later = retrying.state.next_attempt_at.unwrap();
+    assert_eq!(repo.list_due_deliveries(later, 1).await.unwrap().len(), 1);
+
+    pending.state.record_success(301);
+    pending.response_status = Some(200);
+    repo.save_delivery(&pending).await.unwrap();
+    assert_eq!(repo.list_deliveries(&subscription.id, 10).await.unwrap(), vec![retrying, pending]);
+
+    repo.delete_subscription(&subscription.id).await.unwrap();
+    assert!(repo.find_subscription(&subscription.id).await.unwrap().is_none());
+    repo.delete_subscription(&subscription.id).await.unwrap();
+}
+
+async fn alert_first_match_wins(repo: &dyn AlertRepo) {
+    let user = UserId::new("u1");
+    assert!(repo.list_for_user(&user, 10).await.unwrap().is_empty());
+
+    let rule = WatchRule::new("sqlite", "domain:sqlite.org", 10).unwrap();
+    let sqlite4 = Article::new(Source::HackerNews, "1", "SQLite 4".into(), "https://sqlite.org".into(), 0).unwrap();
+    let sqlite5 = Article::new(Source::HackerNews, "2", "SQLite 5".into(), "https://sqlite.org/5".into(), 0).unwrap();
+    assert!(repo.save(&WatchAlert::new(user.clone(), &rule, &sqlite4, 100)).await.unwrap());
+    assert!(repo.save(&WatchAlert::new(user.clone(), &rule, &sqlite5, 200)).await.unwrap());
+    // The first match of a rule and article wins
+    assert!(!repo.save(&WatchAlert::new(user.clone(), &rule, &sqlite4, 300)).await.unwrap());
+
+    let alerts = repo.list_for_user(&user, 10).await.unwrap();
+    assert_eq!(alerts.iter().map(|a| a.matched_at).collect::<Vec<_>>(), vec![200, 100]);
+    assert_eq!(repo.list_for_user(&user, 1).await.unwrap().len(), 1);
+}
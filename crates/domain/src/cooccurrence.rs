@@ -0,0 +1,184 @@
+// Domain entities for the topic co-occurrence graph
+use crate::time::TimeWindow;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// Undirected edge between two topics; `source` always sorts before `target`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CooccurrenceEdge {
+    pub source: String,
+    pub target: String,
+    pub count: u32, // Number of articles mentioning both topics
+    pub pmi: f64,   // Pointwise mutual information, ln(p(a,b) / (p(a) * p(b)))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicNeighbor {
+    pub topic: String,
+    pub count: u32,
+    pub pmi: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CooccurrenceGraph {
+    pub window: TimeWindow,
+    pub document_count: u32,
+    pub topic_counts: HashMap<String, u32>,
+    pub edges: Vec<CooccurrenceEdge>,
+}
+
+impl CooccurrenceGraph {
+    /// Builds the graph from one topic set per article.
+    /// Pairs seen in fewer than `min_count` articles are dropped as noise.
+    pub fn build<I>(window: TimeWindow, documents: I, min_count: u32) -> Self
+    where
+        I: IntoIterator<Item = BTreeSet<String>>,
+    {
+        let mut document_count = 0u32;
+        let mut topic_counts: HashMap<String, u32> = HashMap::new();
+        let mut pair_counts: HashMap<(String, String), u32> = HashMap::new();
+
+        for topics in documents {
+            if topics.is_empty() {
+                continue;
+            }
+            document_count += 1;
+
+            let topics: Vec<String> = topics.into_iter().collect();
+            for (i, a) in topics.iter().enumerate() {
+                *topic_counts.entry(a.clone()).or_insert(0) += 1;
+                // BTreeSet iteration is sorted, so (a, b) is already canonical
+                for b in &topics[i + 1..] {
+                    *pair_counts.entry((a.clone(), b.clone())).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let n = document_count as f64;
+        let mut edges: Vec<CooccurrenceEdge> = pair_counts
+            .into_iter()
+            .filter(|(_, count)| *count >= min_count.max(1))
+            .map(|((source, target), count)| {
+                let ca = topic_counts[&source] as f64;
+                let cb = topic_counts[&target] as f64;
+                CooccurrenceEdge {
+                    pmi: (count as f64 * n / (ca * cb)).ln(),
+                    source,
+                    target,
+                    count,
+                }
+            })
+            .collect();
+
+        edges.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.source.cmp(&b.source))
+                .then_with(|| a.target.cmp(&b.target))
+        });
+
+        Self {
+            window,
+            document_count,
+            topic_counts,
+            edges,
+        }
+    }
+
+    /// Topics connected to `topic`, strongest association (PMI, then count) first.
+    pub fn neighbors(&self, topic: &str, limit: usize) -> Vec<TopicNeighbor> {
+        let mut neighbors: Vec<TopicNeighbor> = self
+            .edges
+            .iter()
+            .filter_map(|edge| {
+                let other = if edge.source == topic {
+                    &edge.target
+                } else if edge.target == topic {
+                    &edge.source
+                } else {
+                    return None;
+                };
+                Some(TopicNeighbor {
+                    topic: other.clone(),
+                    count: edge.count,
+                    pmi: edge.pmi,
+                })
+            })
+            .collect();
+
+        neighbors.sort_by(|a, b| {
+            b.pmi
+                .total_cmp(&a.pmi)
+                .then_with(|| b.count.cmp(&a.count))
+                .then_with(|| a.topic.cmp(&b.topic))
+        });
+        neighbors.truncate(limit);
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(topics: &[&str]) -> BTreeSet<String> {
+        topics.iter().map(|t| t.to_string()).collect()
+    }
+
+    fn window() -> TimeWindow {
+        TimeWindow::new(0, 100).unwrap()
+    }
+
+    #[test]
+    fn test_build_counts_and_pmi() {
+        let docs = vec![
+            doc(&["rust", "webassembly"]),
+            doc(&["rust", "webassembly"]),
+            doc(&["rust", "llm"]),
+            doc(&["llm", "agents"]),
+            doc(&[]), // Ignored
+        ];
+        let graph = CooccurrenceGraph::build(window(), docs, 1);
+
+        assert_eq!(graph.document_count, 4);
+        assert_eq!(graph.topic_counts["rust"], 3);
+        assert_eq!(graph.edges.len(), 3);
+
+        let edge = &graph.edges[0];
+        assert_eq!((edge.source.as_str(), edge.target.as_str()), ("rust", "webassembly"));
+        assert_eq!(edge.count, 2);
+        // p(a,b) = 2/4, p(rust) = 3/4, p(wasm) = 2/4
+        assert!((edge.pmi - (2.0f64 * 4.0 / (3.0 * 2.0)).ln()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_min_count_filters_edges() {
+        let docs = vec![doc(&["rust", "webassembly"]), doc(&["rust", "webassembly"]), doc(&["rust", "llm"])];
+        let graph = CooccurrenceGraph::build(window(), docs, 2);
+
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.topic_counts["llm"], 1); // Node counts are kept regardless
+    }
+
+    #[test]
+    fn test_neighbors_ranked_by_pmi() {
+        let docs = vec![
+            doc(&["agents", "llm"]),
+            doc(&["agents", "llm"]),
+            doc(&["ai", "llm"]),
+            doc(&["ai", "llm"]),
+            doc(&["ai", "rust"]),
+            doc(&["ai"]),
+        ];
+        let graph = CooccurrenceGraph::build(window(), docs, 1);
+
+        let neighbors = graph.neighbors("llm", 10);
+        assert_eq!(neighbors.len(), 2);
+        // agents only ever appears with llm, so it is the stronger association
+        assert_eq!(neighbors[0].topic, "agents");
+        assert_eq!(neighbors[1].topic, "ai");
+
+        assert_eq!(graph.neighbors("llm", 1).len(), 1);
+        assert!(graph.neighbors("zig", 10).is_empty());
+    }
+}
@@ -0,0 +1,100 @@
+// Domain rules for knowledge going stale
+use crate::user::{KnowledgeState, TopicKnowledge};
+use serde::{Deserialize, Serialize};
+
+const DAY_SECS: f64 = 24.0 * 3600.0;
+
+/// Confidence that a known topic is still known, halving every `half_life_days` without engagement.
+/// Fast-moving topics decay faster: the half-life is divided by `1 + velocity_weight * velocity`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecayPolicy {
+    pub half_life_days: f64,
+    pub velocity_weight: f64,
+    pub review_below: f64,    // KnowIt topics under this confidence are worth revisiting
+    pub downgrade_below: f64, // KnowIt topics under this confidence fall back to HeardOf
+}
+
+impl Default for DecayPolicy {
+    fn default() -> Self {
+        Self {
+            half_life_days: 180.0,
+            velocity_weight: 1.0,
+            review_below: 0.6,
+            downgrade_below: 0.25,
+        }
+    }
+}
+
+impl DecayPolicy {
+    /// 0-1; 1.0 right after the last interaction.
+    pub fn confidence(&self, knowledge: &TopicKnowledge, velocity: f64, now: i64) -> f64 {
+        let idle_days = (now - knowledge.last_interaction).max(0) as f64 / DAY_SECS;
+        let half_life = self.half_life_days / (1.0 + self.velocity_weight * velocity.max(0.0));
+        if half_life <= 0.0 {
+            return 0.0;
+        }
+        0.5f64.powf(idle_days / half_life)
+    }
+
+    /// State the topic should decay to, or None if it stays as is. Only KnowIt decays.
+    pub fn decayed_state(&self, knowledge: &TopicKnowledge, velocity: f64, now: i64) -> Option<KnowledgeState> {
+        (knowledge.state == KnowledgeState::KnowIt
+            && self.confidence(knowledge, velocity, now) < self.downgrade_below)
+            .then_some(KnowledgeState::HeardOf)
+    }
+
+    pub fn needs_review(&self, knowledge: &TopicKnowledge, velocity: f64, now: i64) -> bool {
+        knowledge.state == KnowledgeState::KnowIt && self.confidence(knowledge, velocity, now) < self.review_below
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn known(last_interaction: i64) -> TopicKnowledge {
+        TopicKnowledge {
+            topic: "rust".into(),
+            state: KnowledgeState::KnowIt,
+            first_seen: 0,
+            last_interaction,
+            interaction_count: 1,
+        }
+    }
+
+    #[test]
+    fn test_confidence_halves_every_half_life() {
+        let policy = DecayPolicy::default();
+        let now = (360.0 * DAY_SECS) as i64;
+
+        assert_eq!(policy.confidence(&known(now), 0.0, now), 1.0);
+        assert!((policy.confidence(&known(now - (180.0 * DAY_SECS) as i64), 0.0, now) - 0.5).abs() < 1e-9);
+        assert!((policy.confidence(&known(0), 0.0, now) - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_fast_moving_topics_decay_faster() {
+        let policy = DecayPolicy::default();
+        let now = (90.0 * DAY_SECS) as i64;
+        let entry = known(0);
+
+        assert!(policy.confidence(&entry, 3.0, now) < policy.confidence(&entry, 0.0, now));
+        // Shrinking topics decay at the base rate
+        assert_eq!(policy.confidence(&entry, -0.5, now), policy.confidence(&entry, 0.0, now));
+        assert!(policy.needs_review(&entry, 3.0, now));
+        assert!(!policy.needs_review(&entry, 0.0, now));
+    }
+
+    #[test]
+    fn test_only_known_topics_decay() {
+        let policy = DecayPolicy::default();
+        let now = (1000.0 * DAY_SECS) as i64;
+
+        assert_eq!(policy.decayed_state(&known(0), 0.0, now), Some(KnowledgeState::HeardOf));
+        assert_eq!(policy.decayed_state(&known(now), 0.0, now), None);
+
+        let heard_of = TopicKnowledge { state: KnowledgeState::HeardOf, ..known(0) };
+        assert_eq!(policy.decayed_state(&heard_of, 0.0, now), None);
+        assert!(!policy.needs_review(&heard_of, 0.0, now));
+    }
+}
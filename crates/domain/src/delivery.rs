@@ -0,0 +1,227 @@
+// Domain entities for emailing briefings
+use crate::briefing::Briefing;
+use crate::error::DomainError;
+use crate::user::UserId;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: Option<String>, // Sent as multipart/alternative when present
+}
+
+/// Turns a briefing into an email; implemented next to the renderers.
+pub trait BriefingComposer: Send + Sync {
+    fn compose(&self, briefing: &Briefing, to: &str, html: bool) -> EmailMessage;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryStatus {
+    Sent,
+    Retrying,
+    Failed, // Out of attempts
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Sent => "sent",
+            DeliveryStatus::Retrying => "retrying",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sent" => Ok(DeliveryStatus::Sent),
+            "retrying" => Ok(DeliveryStatus::Retrying),
+            "failed" => Ok(DeliveryStatus::Failed),
+            other => Err(DomainError::Validation(format!("Unknown delivery status '{}'", other))),
+        }
+    }
+}
+
+/// Exponential backoff between failed attempts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay_secs: i64,
+    pub max_delay_secs: i64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 4,
+            base_delay_secs: 5 * 60,
+            max_delay_secs: 2 * 3600,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait before the next attempt once `attempts` attempts have failed.
+    pub fn delay_after(&self, attempts: u32) -> i64 {
+        let exponent = attempts.saturating_sub(1).min(30);
+        self.base_delay_secs
+            .saturating_mul(1_i64 << exponent)
+            .min(self.max_delay_secs)
+    }
+}
+
+/// Attempt bookkeeping shared by every kind of outgoing delivery.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeliveryState {
+    pub status: DeliveryStatus,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub last_attempt_at: i64,
+    pub next_attempt_at: Option<i64>, // Only while retrying
+}
+
+impl Default for DeliveryState {
+    fn default() -> Self {
+        Self {
+            status: DeliveryStatus::Retrying,
+            attempts: 0,
+            last_error: None,
+            last_attempt_at: 0,
+            next_attempt_at: None,
+        }
+    }
+}
+
+impl DeliveryState {
+    pub fn is_due(&self, now: i64) -> bool {
+        self.status == DeliveryStatus::Retrying && self.next_attempt_at.is_none_or(|at| at <= now)
+    }
+
+    pub fn record_success(&mut self, now: i64) {
+        self.attempts += 1;
+        self.status = DeliveryStatus::Sent;
+        self.last_error = None;
+        self.last_attempt_at = now;
+        self.next_attempt_at = None;
+    }
+
+    pub fn record_failure(&mut self, error: impl Into<String>, now: i64, policy: &RetryPolicy) {
+        self.attempts += 1;
+        self.last_error = Some(error.into());
+        self.last_attempt_at = now;
+        if self.attempts >= policy.max_attempts {
+            self.status = DeliveryStatus::Failed;
+            self.next_attempt_at = None;
+        } else {
+            self.status = DeliveryStatus::Retrying;
+            self.next_attempt_at = Some(now + policy.delay_after(self.attempts));
+        }
+    }
+
+    /// Gives up without counting an attempt, e.g. when the destination no longer exists.
+    pub fn abandon(&mut self, error: impl Into<String>, now: i64) {
+        self.status = DeliveryStatus::Failed;
+        self.last_error = Some(error.into());
+        self.last_attempt_at = now;
+        self.next_attempt_at = None;
+    }
+}
+
+/// Delivery log entry: one per user and local date, updated on every attempt.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BriefingDelivery {
+    pub user_id: UserId,
+    pub date: NaiveDate,
+    pub email: String,
+    pub state: DeliveryState,
+}
+
+impl BriefingDelivery {
+    pub fn new(user_id: UserId, date: NaiveDate, email: impl Into<String>) -> Self {
+        Self {
+            user_id,
+            date,
+            email: email.into(),
+            state: DeliveryState::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delivery() -> DeliveryState {
+        BriefingDelivery::new(UserId::from("u1"), NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(), "ada@example.com").state
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            base_delay_secs: 60,
+            max_delay_secs: 300,
+        };
+        assert_eq!(policy.delay_after(1), 60);
+        assert_eq!(policy.delay_after(2), 120);
+        assert_eq!(policy.delay_after(3), 240);
+        assert_eq!(policy.delay_after(4), 300);
+        assert_eq!(policy.delay_after(40), 300);
+    }
+
+    #[test]
+    fn test_failures_retry_then_give_up() {
+        let policy = RetryPolicy {
+            max_attempts: 2,
+            base_delay_secs: 60,
+            max_delay_secs: 600,
+        };
+        let mut d = delivery();
+        assert!(d.is_due(0));
+
+        d.record_failure("connection refused", 1000, &policy);
+        assert_eq!(d.status, DeliveryStatus::Retrying);
+        assert_eq!(d.next_attempt_at, Some(1060));
+        assert!(!d.is_due(1059));
+        assert!(d.is_due(1060));
+
+        d.record_failure("connection refused", 1060, &policy);
+        assert_eq!(d.status, DeliveryStatus::Failed);
+        assert_eq!(d.attempts, 2);
+        assert!(!d.is_due(i64::MAX));
+    }
+
+    #[test]
+    fn test_success_clears_error() {
+        let mut d = delivery();
+        d.record_failure("timeout", 10, &RetryPolicy::default());
+        d.record_success(400);
+
+        assert_eq!(d.status, DeliveryStatus::Sent);
+        assert_eq!(d.attempts, 2);
+        assert_eq!(d.last_error, None);
+        assert!(!d.is_due(500));
+    }
+
+    #[test]
+    fn test_status_roundtrip() {
+        for status in [DeliveryStatus::Sent, DeliveryStatus::Retrying, DeliveryStatus::Failed] {
+            assert_eq!(status.as_str().parse::<DeliveryStatus>().unwrap(), status);
+        }
+        assert!("queued".parse::<DeliveryStatus>().is_err());
+    }
+}
@@ -0,0 +1,204 @@
+// Domain entities for story clusters ("events")
+use crate::article::{Article, ArticleId};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EventClusterId(String);
+
+impl EventClusterId {
+    /// Events are named after their earliest article so the ID survives new members joining.
+    pub fn for_seed(article_id: &ArticleId) -> Self {
+        Self(format!("evt-{}", article_id))
+    }
+}
+
+impl From<&str> for EventClusterId {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl fmt::Display for EventClusterId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventLifecycle {
+    Emerging,
+    Peaking,
+    Fading,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventCluster {
+    pub id: EventClusterId,
+    pub headline: String,
+    pub representative: ArticleId,
+    pub url: String, // Link of the representative article
+    pub articles: Vec<ArticleId>, // Highest scoring first
+    pub sources: Vec<String>,
+    pub topics: BTreeSet<String>,
+    pub first_seen: i64,
+    pub last_seen: i64,
+    pub score: f64, // Sum of member scores at clustering time
+    pub lifecycle: EventLifecycle,
+}
+
+/// Thresholds deciding when two articles describe the same event.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClusteringPolicy {
+    pub max_time_gap_secs: i64,
+    pub min_shared_topics: usize,
+    pub min_title_similarity: f64, // Jaccard over significant title words, 0-1
+    pub activity_window_secs: i64,
+}
+
+impl Default for ClusteringPolicy {
+    fn default() -> Self {
+        Self {
+            max_time_gap_secs: 48 * 3600,
+            min_shared_topics: 2,
+            min_title_similarity: 0.35,
+            activity_window_secs: 12 * 3600,
+        }
+    }
+}
+
+/// What `ClusteringPolicy::are_related` compares, computed once per article.
+#[derive(Debug, Clone)]
+pub struct StoryFeatures {
+    pub timestamp: i64,
+    pub topics: BTreeSet<String>,
+    pub title_words: HashSet<String>,
+}
+
+impl StoryFeatures {
+    pub fn new(article: &Article, topics: BTreeSet<String>) -> Self {
+        Self {
+            timestamp: article.timestamp,
+            topics,
+            title_words: significant_words(&article.title),
+        }
+    }
+}
+
+impl ClusteringPolicy {
+    pub fn are_related(&self, a: &StoryFeatures, b: &StoryFeatures) -> bool {
+        if (a.timestamp - b.timestamp).abs() > self.max_time_gap_secs {
+            return false;
+        }
+        let shared = a.topics.intersection(&b.topics).count();
+        shared >= self.min_shared_topics.max(1)
+            || jaccard(&a.title_words, &b.title_words) >= self.min_title_similarity
+    }
+
+    /// Emerging while young and active, peaking while activity holds up, fading once it drops.
+    pub fn lifecycle(&self, timestamps: &[i64], now: i64) -> EventLifecycle {
+        let window = self.activity_window_secs;
+        let recent = timestamps.iter().filter(|&&t| t > now - window).count();
+        let previous = timestamps
+            .iter()
+            .filter(|&&t| t > now - 2 * window && t <= now - window)
+            .count();
+        let first_seen = timestamps.iter().copied().min().unwrap_or(now);
+
+        if recent == 0 {
+            EventLifecycle::Fading
+        } else if first_seen > now - window {
+            EventLifecycle::Emerging
+        } else if recent >= previous {
+            EventLifecycle::Peaking
+        } else {
+            EventLifecycle::Fading
+        }
+    }
+}
+
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "from", "that", "this", "are", "was", "you", "your", "how",
+    "why", "what", "new", "now", "its", "our", "has", "have", "will", "into", "about", "show",
+    "ask", "hn",
+];
+
+fn significant_words(title: &str) -> HashSet<String> {
+    title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.len() > 2 && !STOPWORDS.contains(w))
+        .map(|w| w.to_string())
+        .collect()
+}
+
+/// Jaccard similarity of the significant words of two titles.
+pub fn title_similarity(a: &str, b: &str) -> f64 {
+    jaccard(&significant_words(a), &significant_words(b))
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let intersection = a.intersection(b).count() as f64;
+    let union = a.union(b).count() as f64;
+    intersection / union
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::article::Source;
+
+    fn story(title: &str, timestamp: i64, topics: &[&str]) -> StoryFeatures {
+        let article = Article::new(Source::HackerNews, "1", title.into(), "".into(), timestamp).unwrap();
+        StoryFeatures::new(&article, topics.iter().map(|t| t.to_string()).collect())
+    }
+
+    #[test]
+    fn test_title_similarity() {
+        let sim = title_similarity("OpenAI releases GPT-5", "GPT-5 released by OpenAI today");
+        assert!(sim > 0.3, "similarity was {}", sim);
+        assert_eq!(title_similarity("The and for", "Rust 2.0"), 0.0);
+        assert!(title_similarity("Rust 2.0 announced", "Go 2.0 announced") < 1.0);
+    }
+
+    #[test]
+    fn test_related_requires_time_proximity() {
+        let policy = ClusteringPolicy::default();
+        let a = story("OpenAI releases GPT-5", 0, &[]);
+        let b = story("OpenAI releases GPT-5 to everyone", 3600, &[]);
+        let far = story("OpenAI releases GPT-5 to everyone", 10 * 24 * 3600, &[]);
+
+        assert!(policy.are_related(&a, &b));
+        assert!(!policy.are_related(&a, &far));
+    }
+
+    #[test]
+    fn test_related_by_shared_topics() {
+        let policy = ClusteringPolicy::default();
+        let a = story("Announcing the new toolchain", 0, &["rust", "webassembly"]);
+        let b = story("Compiler deep dive", 60, &["rust", "webassembly", "llm"]);
+        assert!(policy.are_related(&a, &b));
+
+        let a = story("Announcing the new toolchain", 0, &["rust"]);
+        let b = story("Compiler deep dive", 60, &["rust"]);
+        assert!(!policy.are_related(&a, &b));
+    }
+
+    #[test]
+    fn test_lifecycle() {
+        let policy = ClusteringPolicy {
+            activity_window_secs: 100,
+            ..Default::default()
+        };
+        let now = 1000;
+
+        assert_eq!(policy.lifecycle(&[950, 990], now), EventLifecycle::Emerging);
+        assert_eq!(policy.lifecycle(&[850, 950, 990], now), EventLifecycle::Peaking);
+        assert_eq!(policy.lifecycle(&[820, 850, 880, 950], now), EventLifecycle::Fading);
+        assert_eq!(policy.lifecycle(&[500], now), EventLifecycle::Fading);
+    }
+}
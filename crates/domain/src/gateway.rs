@@ -1,8 +1,22 @@
 use async_trait::async_trait;
 use crate::article::Article;
+use crate::delivery::EmailMessage;
 use crate::error::DomainError;
+use crate::webhook::{WebhookDelivery, WebhookSubscription};
 
 #[async_trait]
 pub trait ArticleGateway: Send + Sync {
     async fn fetch_top_articles(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
 }
+
+#[async_trait]
+pub trait EmailGateway: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> Result<(), DomainError>;
+}
+
+#[async_trait]
+pub trait WebhookGateway: Send + Sync {
+    /// Posts the signed delivery payload and returns the HTTP status of the response.
+    /// Errors are reserved for requests that got no response at all.
+    async fn post(&self, subscription: &WebhookSubscription, delivery: &WebhookDelivery) -> Result<u16, DomainError>;
+}
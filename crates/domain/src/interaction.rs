@@ -0,0 +1,176 @@
+// Domain entities for user interactions (personalization signals)
+use crate::article::ArticleId;
+use crate::error::DomainError;
+use crate::user::{KnowledgeState, UserId, UserProfile};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InteractionKind {
+    Open,
+    Dwell { seconds: u32 }, // Time spent reading, reported when the reader leaves
+    Dismiss,
+    MarkKnown,
+    WantToLearn,
+}
+
+impl InteractionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InteractionKind::Open => "open",
+            InteractionKind::Dwell { .. } => "dwell",
+            InteractionKind::Dismiss => "dismiss",
+            InteractionKind::MarkKnown => "mark_known",
+            InteractionKind::WantToLearn => "want_to_learn",
+        }
+    }
+
+    pub fn dwell_secs(&self) -> Option<u32> {
+        match self {
+            InteractionKind::Dwell { seconds } => Some(*seconds),
+            _ => None,
+        }
+    }
+
+    /// Rebuilds a kind from its name and optional dwell time, as sent by clients or stored.
+    pub fn from_parts(kind: &str, dwell_secs: Option<u32>) -> Result<Self, DomainError> {
+        match kind {
+            "open" => Ok(InteractionKind::Open),
+            "dwell" => dwell_secs
+                .map(|seconds| InteractionKind::Dwell { seconds })
+                .ok_or_else(|| DomainError::Validation("Dwell interaction requires dwell_secs".to_string())),
+            "dismiss" => Ok(InteractionKind::Dismiss),
+            "mark_known" => Ok(InteractionKind::MarkKnown),
+            "want_to_learn" => Ok(InteractionKind::WantToLearn),
+            other => Err(DomainError::Validation(format!("Unknown interaction kind '{}'", other))),
+        }
+    }
+}
+
+impl fmt::Display for InteractionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single user action on an article. Events are append-only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionEvent {
+    pub user_id: UserId,
+    pub article_id: ArticleId,
+    pub kind: InteractionKind,
+    pub timestamp: i64,
+}
+
+/// Rules folding interactions into a user's knowledge map.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InteractionPolicy {
+    pub long_read_secs: u32,     // Dwell at least this long counts as an engaged read
+    pub reads_for_heard_of: u32, // Engaged reads on a topic before it becomes HeardOf
+}
+
+impl Default for InteractionPolicy {
+    fn default() -> Self {
+        Self {
+            long_read_secs: 30,
+            reads_for_heard_of: 3,
+        }
+    }
+}
+
+impl InteractionPolicy {
+    /// Applies `event` to every topic of the article it refers to.
+    /// Explicit actions set the state; engaged reads only ever promote NeverSeen to HeardOf.
+    pub fn apply(&self, profile: &mut UserProfile, topics: &BTreeSet<String>, event: &InteractionEvent) {
+        for topic in topics {
+            match event.kind {
+                InteractionKind::MarkKnown => {
+                    profile.update_knowledge(topic, KnowledgeState::KnowIt, event.timestamp)
+                }
+                InteractionKind::WantToLearn => {
+                    profile.update_knowledge(topic, KnowledgeState::WantToLearn, event.timestamp)
+                }
+                InteractionKind::Dwell { seconds } if seconds >= self.long_read_secs => {
+                    let entry = profile.record_interaction(topic, event.timestamp);
+                    if entry.state == KnowledgeState::NeverSeen
+                        && entry.interaction_count >= self.reads_for_heard_of
+                    {
+                        entry.state = KnowledgeState::HeardOf;
+                    }
+                }
+                InteractionKind::Open => profile.touch(topic, event.timestamp),
+                // Short reads and dismissals say nothing about what the user knows
+                InteractionKind::Dwell { .. } | InteractionKind::Dismiss => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: InteractionKind, timestamp: i64) -> InteractionEvent {
+        InteractionEvent {
+            user_id: UserId::from("u1"),
+            article_id: ArticleId::from_persisted("hn-1".into()),
+            kind,
+            timestamp,
+        }
+    }
+
+    fn topics(list: &[&str]) -> BTreeSet<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn test_kind_roundtrip() {
+        for kind in [
+            InteractionKind::Open,
+            InteractionKind::Dwell { seconds: 45 },
+            InteractionKind::Dismiss,
+            InteractionKind::MarkKnown,
+            InteractionKind::WantToLearn,
+        ] {
+            assert_eq!(InteractionKind::from_parts(kind.as_str(), kind.dwell_secs()).unwrap(), kind);
+        }
+        assert!(InteractionKind::from_parts("dwell", None).is_err());
+        assert!(InteractionKind::from_parts("like", None).is_err());
+    }
+
+    #[test]
+    fn test_repeated_long_reads_promote_to_heard_of() {
+        let policy = InteractionPolicy::default();
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        let rust = topics(&["rust"]);
+
+        policy.apply(&mut profile, &rust, &event(InteractionKind::Dwell { seconds: 5 }, 10));
+        assert!(profile.knowledge.topics.is_empty()); // Short read ignored
+
+        policy.apply(&mut profile, &rust, &event(InteractionKind::Open, 20));
+        policy.apply(&mut profile, &rust, &event(InteractionKind::Dwell { seconds: 60 }, 30));
+        policy.apply(&mut profile, &rust, &event(InteractionKind::Dwell { seconds: 60 }, 40));
+        assert_eq!(profile.knowledge.topics["rust"].state, KnowledgeState::NeverSeen);
+
+        policy.apply(&mut profile, &rust, &event(InteractionKind::Dwell { seconds: 60 }, 50));
+        let entry = &profile.knowledge.topics["rust"];
+        assert_eq!(entry.state, KnowledgeState::HeardOf);
+        assert_eq!(entry.first_seen, 20);
+        assert_eq!(entry.last_interaction, 50);
+    }
+
+    #[test]
+    fn test_reads_never_downgrade_explicit_state() {
+        let policy = InteractionPolicy {
+            reads_for_heard_of: 1,
+            ..Default::default()
+        };
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        let rust = topics(&["rust"]);
+
+        policy.apply(&mut profile, &rust, &event(InteractionKind::MarkKnown, 10));
+        policy.apply(&mut profile, &rust, &event(InteractionKind::Dwell { seconds: 60 }, 20));
+        assert_eq!(profile.knowledge.topics["rust"].state, KnowledgeState::KnowIt);
+    }
+}
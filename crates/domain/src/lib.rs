@@ -1,6 +1,24 @@
 pub mod article;
+pub mod query;
+pub mod search;
 pub mod user;
 pub mod trend;
+pub mod topic;
+pub mod cooccurrence;
+pub mod event;
+pub mod blindspot;
+pub mod interaction;
+pub mod decay;
+pub mod personalization;
+pub mod briefing;
+pub mod delivery;
+pub mod webhook;
+pub mod watchlist;
+pub mod mute;
+pub mod retention;
+pub mod time;
 pub mod repository;
 pub mod error;
 pub mod gateway;
+#[cfg(feature = "conformance")]
+pub mod conformance;
@@ -0,0 +1,279 @@
+// Domain entities for per-user mute rules hiding noise from the feed
+use crate::article::Article;
+use crate::error::DomainError;
+use crate::watchlist::{MatchField, MatchTerm};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+pub const MAX_MUTE_RULES: usize = 100;
+pub const MAX_PATTERN_LEN: usize = 200;
+const REGEX_SIZE_LIMIT: usize = 1 << 16;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MuteRuleId(String);
+
+impl MuteRuleId {
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+}
+
+impl From<&str> for MuteRuleId {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl fmt::Display for MuteRuleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MuteKind {
+    Keyword, // Title words or tags, like a bare watch term
+    Regex,   // Case-insensitive, over the title
+    Domain,  // Includes subdomains
+    Source,
+    Author,
+}
+
+impl MuteKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MuteKind::Keyword => "keyword",
+            MuteKind::Regex => "regex",
+            MuteKind::Domain => "domain",
+            MuteKind::Source => "source",
+            MuteKind::Author => "author",
+        }
+    }
+}
+
+impl fmt::Display for MuteKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MuteKind {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keyword" => Ok(MuteKind::Keyword),
+            "regex" => Ok(MuteKind::Regex),
+            "domain" => Ok(MuteKind::Domain),
+            "source" => Ok(MuteKind::Source),
+            "author" => Ok(MuteKind::Author),
+            other => Err(DomainError::Validation(format!("Unknown mute kind '{}'", other))),
+        }
+    }
+}
+
+/// Parses a mute duration such as "7d", "12h" or "30m" into seconds.
+pub fn parse_mute_duration(s: &str) -> Result<i64, DomainError> {
+    let s = s.trim();
+    let invalid = || DomainError::Validation(format!("Invalid mute duration '{}', expected e.g. 7d, 12h or 30m", s));
+    let (amount, unit_secs) = [("d", 24 * 3600), ("h", 3600), ("m", 60)]
+        .into_iter()
+        .find_map(|(unit, secs)| s.strip_suffix(unit).map(|amount| (amount, secs)))
+        .ok_or_else(invalid)?;
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    if amount <= 0 || amount > 365 * 24 * 3600 / unit_secs {
+        return Err(invalid());
+    }
+    Ok(amount * unit_secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MuteRule {
+    pub id: MuteRuleId,
+    pub kind: MuteKind,
+    pub pattern: String,
+    pub created_at: i64,
+    pub expires_at: Option<i64>, // None mutes until the rule is removed
+}
+
+impl MuteRule {
+    pub fn new(kind: MuteKind, pattern: &str, duration_secs: Option<i64>, now: i64) -> Result<Self, DomainError> {
+        let pattern = pattern.trim();
+        if pattern.is_empty() {
+            return Err(DomainError::Validation("Mute pattern cannot be empty".to_string()));
+        }
+        if pattern.len() > MAX_PATTERN_LEN {
+            return Err(DomainError::Validation(format!(
+                "Mute pattern is longer than {} characters",
+                MAX_PATTERN_LEN
+            )));
+        }
+        if kind == MuteKind::Regex {
+            compile(pattern)?;
+        }
+        if duration_secs.is_some_and(|d| d <= 0) {
+            return Err(DomainError::Validation("Mute duration must be positive".to_string()));
+        }
+
+        Ok(Self {
+            id: MuteRuleId::generate(),
+            kind,
+            pattern: pattern.to_string(),
+            created_at: now,
+            expires_at: duration_secs.map(|d| now + d),
+        })
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        self.expires_at.is_none_or(|t| t > now)
+    }
+
+    /// Same kind and pattern, ignoring case for everything but regexes.
+    pub fn same_target(&self, other: &MuteRule) -> bool {
+        self.kind == other.kind
+            && match self.kind {
+                MuteKind::Regex => self.pattern == other.pattern,
+                _ => self.pattern.eq_ignore_ascii_case(&other.pattern),
+            }
+    }
+}
+
+fn compile(pattern: &str) -> Result<Regex, DomainError> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(REGEX_SIZE_LIMIT)
+        .build()
+        .map_err(|e| DomainError::Validation(format!("Invalid mute regex: {}", e)))
+}
+
+enum Matcher {
+    Term(MatchTerm),
+    Regex(Regex),
+}
+
+/// The active mute rules of a user, compiled once per request.
+pub struct MuteFilter {
+    rules: Vec<(MuteRule, Matcher)>,
+}
+
+impl MuteFilter {
+    pub fn new(rules: &[MuteRule], now: i64) -> Self {
+        let rules = rules
+            .iter()
+            .filter(|r| r.is_active(now))
+            .filter_map(|r| {
+                let field = match r.kind {
+                    MuteKind::Regex => return compile(&r.pattern).ok().map(|re| (r.clone(), Matcher::Regex(re))),
+                    MuteKind::Keyword => MatchField::Any,
+                    MuteKind::Domain => MatchField::Domain,
+                    MuteKind::Source => MatchField::Source,
+                    MuteKind::Author => MatchField::Author,
+                };
+                let term = MatchTerm { field, value: r.pattern.clone() };
+                Some((r.clone(), Matcher::Term(term)))
+            })
+            .collect();
+        Self { rules }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The first rule hiding `article`, if any.
+    pub fn muted_by(&self, article: &Article) -> Option<&MuteRule> {
+        self.rules
+            .iter()
+            .find(|(_, matcher)| match matcher {
+                Matcher::Term(term) => term.matches(article),
+                Matcher::Regex(re) => re.is_match(&article.title),
+            })
+            .map(|(rule, _)| rule)
+    }
+}
+
+/// How many items mute rules hid from a response, per rule in first-hit order.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct MuteReport {
+    pub hidden: usize,
+    pub by_rule: Vec<(MuteRuleId, usize)>,
+}
+
+impl MuteReport {
+    pub fn record(&mut self, rule: &MuteRule) {
+        self.hidden += 1;
+        match self.by_rule.iter_mut().find(|(id, _)| id == &rule.id) {
+            Some((_, count)) => *count += 1,
+            None => self.by_rule.push((rule.id.clone(), 1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::article::Source;
+
+    fn article(title: &str, url: &str) -> Article {
+        Article::new(Source::Reddit("cryptocurrency".into()), "1", title.into(), url.into(), 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_mute_duration() {
+        assert_eq!(parse_mute_duration("7d").unwrap(), 7 * 24 * 3600);
+        assert_eq!(parse_mute_duration(" 12h ").unwrap(), 12 * 3600);
+        assert_eq!(parse_mute_duration("30m").unwrap(), 1800);
+        for bad in ["", "d", "7", "0d", "-1d", "7w", "1000d", "7日", "日", "7дh"] {
+            assert!(parse_mute_duration(bad).is_err(), "'{}' should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn test_rule_validation_and_expiry() {
+        assert!(MuteRule::new(MuteKind::Keyword, "  ", None, 0).is_err());
+        assert!(MuteRule::new(MuteKind::Regex, "bitcoin (price", None, 0).is_err());
+        assert!(MuteRule::new(MuteKind::Domain, "spam.example", Some(0), 0).is_err());
+
+        let rule = MuteRule::new(MuteKind::Domain, "spam.example", Some(100), 1000).unwrap();
+        assert!(rule.is_active(1099));
+        assert!(!rule.is_active(1100));
+        assert!(MuteRule::new(MuteKind::Author, "bot", None, 0).unwrap().is_active(i64::MAX));
+    }
+
+    #[test]
+    fn test_filter_matches_each_kind() {
+        let now = 1000;
+        let rules = vec![
+            MuteRule::new(MuteKind::Regex, r"(bitcoin|eth)\b.*\bprice", None, 0).unwrap(),
+            MuteRule::new(MuteKind::Domain, "spam.example", None, 0).unwrap(),
+            MuteRule::new(MuteKind::Keyword, "NFT", Some(10), 0).unwrap(), // Expired
+        ];
+        let filter = MuteFilter::new(&rules, now);
+
+        let price = article("Bitcoin hits new price record", "https://news.example/btc");
+        assert_eq!(filter.muted_by(&price).map(|r| r.kind), Some(MuteKind::Regex));
+        let spam = article("Great deals", "https://www.cdn.spam.example/deal");
+        assert_eq!(filter.muted_by(&spam).map(|r| r.kind), Some(MuteKind::Domain));
+        assert!(filter.muted_by(&article("NFT market update", "")).is_none());
+        assert!(filter.muted_by(&article("Ethereum roadmap", "")).is_none());
+
+        let by_source = MuteFilter::new(&[MuteRule::new(MuteKind::Source, "rd", None, 0).unwrap()], now);
+        assert!(by_source.muted_by(&price).is_some());
+        assert!(MuteFilter::new(&[], now).is_empty());
+    }
+
+    #[test]
+    fn test_report_counts_per_rule() {
+        let a = MuteRule::new(MuteKind::Keyword, "crypto", None, 0).unwrap();
+        let b = MuteRule::new(MuteKind::Author, "bot", None, 0).unwrap();
+        let mut report = MuteReport::default();
+        report.record(&a);
+        report.record(&b);
+        report.record(&a);
+        assert_eq!(report.hidden, 3);
+        assert_eq!(report.by_rule, vec![(a.id.clone(), 2), (b.id.clone(), 1)]);
+    }
+}
@@ -0,0 +1,128 @@
+// Domain rules for per-user feed ranking
+use crate::user::{KnowledgeState, UserProfile};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Multipliers applied to an article's base score for a given user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersonalizationWeights {
+    pub want_to_learn_boost: f64,
+    pub blind_spot_boost: f64, // Topics the user never engaged with or only heard of
+    pub known_penalty: f64,    // Topics marked KnowIt that are not spiking
+    pub spike_velocity: f64,   // Velocity at which known topics are shown normally again (2.0 = tripled)
+    pub preferred_source_boost: f64,
+}
+
+impl Default for PersonalizationWeights {
+    fn default() -> Self {
+        Self {
+            want_to_learn_boost: 1.5,
+            blind_spot_boost: 1.2,
+            known_penalty: 0.5,
+            spike_velocity: 2.0,
+            preferred_source_boost: 1.25,
+        }
+    }
+}
+
+impl PersonalizationWeights {
+    fn topic_factor(&self, profile: &UserProfile, topic: &str, velocity: f64) -> f64 {
+        match profile.knowledge.topics.get(topic).map(|k| &k.state) {
+            Some(KnowledgeState::WantToLearn) => self.want_to_learn_boost,
+            Some(KnowledgeState::KnowIt) if velocity < self.spike_velocity => self.known_penalty,
+            Some(KnowledgeState::KnowIt) => 1.0,
+            Some(KnowledgeState::NeverSeen) | Some(KnowledgeState::HeardOf) | None => self.blind_spot_boost,
+        }
+    }
+
+    /// Score multiplier for an article with `topics` from `source`.
+    /// A topic the user wants to learn lifts the whole article. Otherwise its least interesting
+    /// topic decides, so a known topic is not promoted by an unfamiliar one next to it.
+    /// Articles without topics are neutral.
+    pub fn multiplier(
+        &self,
+        profile: &UserProfile,
+        topics: &BTreeSet<String>,
+        source: &str,
+        velocity_of: impl Fn(&str) -> f64,
+    ) -> f64 {
+        let wants_to_learn = topics
+            .iter()
+            .any(|t| profile.knowledge.topics.get(t).is_some_and(|k| k.state == KnowledgeState::WantToLearn));
+        let factors = topics.iter().map(|t| self.topic_factor(profile, t, velocity_of(t)));
+        let topic_factor = if wants_to_learn {
+            factors.reduce(f64::max)
+        } else {
+            factors.reduce(f64::min)
+        }
+        .unwrap_or(1.0);
+
+        let preferred = profile
+            .settings
+            .preferred_sources
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(source));
+        let source_factor = if preferred { self.preferred_source_boost } else { 1.0 };
+
+        topic_factor * source_factor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::user::UserId;
+
+    fn topics(list: &[&str]) -> BTreeSet<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    fn profile() -> UserProfile {
+        let mut user = UserProfile::new(UserId::from("u1"));
+        user.update_knowledge("rust", KnowledgeState::KnowIt, 0);
+        user.update_knowledge("llm", KnowledgeState::WantToLearn, 0);
+        user.settings.preferred_sources = vec!["gh".into()];
+        user
+    }
+
+    #[test]
+    fn test_topic_multipliers() {
+        let weights = PersonalizationWeights::default();
+        let user = profile();
+        let calm = |_: &str| 0.0;
+
+        assert_eq!(weights.multiplier(&user, &topics(&["llm"]), "hn", calm), 1.5);
+        assert_eq!(weights.multiplier(&user, &topics(&["rust"]), "hn", calm), 0.5);
+        assert_eq!(weights.multiplier(&user, &topics(&["zig"]), "hn", calm), 1.2);
+        assert_eq!(weights.multiplier(&user, &topics(&[]), "hn", calm), 1.0);
+        // A topic to learn wins
+        assert_eq!(weights.multiplier(&user, &topics(&["rust", "llm"]), "hn", calm), 1.5);
+        assert_eq!(weights.multiplier(&user, &topics(&["zig", "llm"]), "hn", calm), 1.5);
+    }
+
+    #[test]
+    fn test_known_topic_is_not_promoted_by_unmarked_one() {
+        let weights = PersonalizationWeights::default();
+        let user = profile();
+
+        assert_eq!(weights.multiplier(&user, &topics(&["rust", "zig"]), "hn", |_| 0.0), 0.5);
+        assert_eq!(weights.multiplier(&user, &topics(&["rust", "zig"]), "hn", |t| if t == "rust" { 2.0 } else { 0.0 }), 1.0);
+    }
+
+    #[test]
+    fn test_spiking_known_topics_are_not_demoted() {
+        let weights = PersonalizationWeights::default();
+        let user = profile();
+
+        assert_eq!(weights.multiplier(&user, &topics(&["rust"]), "hn", |_| 2.0), 1.0);
+    }
+
+    #[test]
+    fn test_preferred_sources_are_boosted() {
+        let weights = PersonalizationWeights::default();
+        let user = profile();
+
+        assert_eq!(weights.multiplier(&user, &topics(&[]), "gh", |_| 0.0), 1.25);
+        assert_eq!(weights.multiplier(&user, &topics(&["llm"]), "GH", |_| 0.0), 1.5 * 1.25);
+    }
+}
@@ -0,0 +1,255 @@
+// Domain entities for filtered, cursor-paginated article queries
+use crate::article::{Article, ArticleId};
+use crate::error::DomainError;
+use std::fmt;
+use std::str::FromStr;
+
+pub const MAX_QUERY_LIMIT: usize = 500;
+pub const MAX_TEXT_LEN: usize = 200;
+
+/// Position after the last article of a page. Articles are ordered newest first,
+/// ties broken by descending ID, so the position is stable while new articles arrive.
+/// Clients see it as an opaque token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArticleCursor {
+    pub timestamp: i64,
+    pub id: ArticleId,
+}
+
+impl ArticleCursor {
+    pub fn after(article: &Article) -> Self {
+        Self {
+            timestamp: article.timestamp,
+            id: article.id.clone(),
+        }
+    }
+
+    /// Whether `article` comes after this position in feed order.
+    pub fn precedes(&self, article: &Article) -> bool {
+        (article.timestamp, article.id.to_string()) < (self.timestamp, self.id.to_string())
+    }
+}
+
+impl fmt::Display for ArticleCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in format!("{}:{}", self.timestamp, self.id).bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ArticleCursor {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || DomainError::Validation("Invalid cursor".to_string());
+        if !s.len().is_multiple_of(2) || !s.is_ascii() {
+            return Err(invalid());
+        }
+        let bytes = (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
+            .collect::<Result<Vec<u8>, _>>()
+            .map_err(|_| invalid())?;
+        let decoded = String::from_utf8(bytes).map_err(|_| invalid())?;
+        let (timestamp, id) = decoded.split_once(':').ok_or_else(invalid)?;
+        Ok(Self {
+            timestamp: timestamp.parse().map_err(|_| invalid())?,
+            id: ArticleId::parse(id).map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// Filters over stored articles; every set filter must match.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArticleQuery {
+    pub sources: Vec<String>, // Any of; "rd" matches every subreddit, "rd-rust" only that one
+    pub tags: Vec<String>,    // All of, ignoring case
+    pub since: Option<i64>,   // Inclusive
+    pub until: Option<i64>,   // Exclusive
+    pub min_score: Option<f64>,
+    pub domain: Option<String>, // Includes subdomains
+    pub text: Option<String>,   // Case-insensitive substring of the title
+    pub cursor: Option<ArticleCursor>,
+    pub limit: usize,
+}
+
+impl ArticleQuery {
+    /// The newest `limit` articles, unfiltered.
+    pub fn latest(limit: usize) -> Self {
+        Self {
+            sources: Vec::new(),
+            tags: Vec::new(),
+            since: None,
+            until: None,
+            min_score: None,
+            domain: None,
+            text: None,
+            cursor: None,
+            limit,
+        }
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Checks bounds and lower-cases the filters so repositories can compare them directly.
+    pub fn normalized(mut self) -> Result<Self, DomainError> {
+        if self.limit == 0 || self.limit > MAX_QUERY_LIMIT {
+            return Err(DomainError::Validation(format!(
+                "Query limit must be between 1 and {}",
+                MAX_QUERY_LIMIT
+            )));
+        }
+        if let (Some(since), Some(until)) = (self.since, self.until) {
+            if since >= until {
+                return Err(DomainError::Validation("'since' must be before 'until'".to_string()));
+            }
+        }
+        if self.min_score.is_some_and(|s| !s.is_finite()) {
+            return Err(DomainError::Validation("Minimum score must be a number".to_string()));
+        }
+        if self.text.as_ref().is_some_and(|t| t.len() > MAX_TEXT_LEN) {
+            return Err(DomainError::Validation(format!(
+                "Text filter is longer than {} characters",
+                MAX_TEXT_LEN
+            )));
+        }
+
+        let clean = |values: Vec<String>| -> Vec<String> {
+            values
+                .into_iter()
+                .map(|v| v.trim().to_lowercase())
+                .filter(|v| !v.is_empty())
+                .collect()
+        };
+        self.sources = clean(self.sources);
+        self.tags = clean(self.tags);
+        self.domain = self
+            .domain
+            .map(|d| d.trim().to_lowercase())
+            .map(|d| d.strip_prefix("www.").map(str::to_string).unwrap_or(d))
+            .filter(|d| !d.is_empty());
+        self.text = self.text.map(|t| t.trim().to_lowercase()).filter(|t| !t.is_empty());
+        Ok(self)
+    }
+
+    /// Whether `article` passes the filters, ignoring the cursor. Expects a normalized query.
+    pub fn matches(&self, article: &Article) -> bool {
+        let source = article.source.to_string().to_lowercase();
+        let domain = article.domain();
+
+        (self.sources.is_empty()
+            || self
+                .sources
+                .iter()
+                .any(|s| source == *s || source.starts_with(&format!("{}-", s))))
+            && self
+                .tags
+                .iter()
+                .all(|tag| article.tags.iter().any(|t| t.to_lowercase() == *tag))
+            && self.since.is_none_or(|t| article.timestamp >= t)
+            && self.until.is_none_or(|t| article.timestamp < t)
+            && self.min_score.is_none_or(|s| article.score >= s)
+            && self
+                .domain
+                .as_ref()
+                .is_none_or(|d| domain == *d || domain.ends_with(&format!(".{}", d)))
+            && self
+                .text
+                .as_ref()
+                .is_none_or(|t| article.title.to_lowercase().contains(t.as_str()))
+    }
+}
+
+/// One page of query results, newest first.
+#[derive(Debug, Clone, Default)]
+pub struct ArticlePage {
+    pub articles: Vec<Article>,
+    pub next_cursor: Option<ArticleCursor>, // None on the last page
+}
+
+impl ArticlePage {
+    /// Builds a page from up to `limit + 1` ordered matches; the extra one only signals more results.
+    pub fn from_overfetch(mut articles: Vec<Article>, limit: usize) -> Self {
+        let next_cursor = if articles.len() > limit {
+            articles.truncate(limit);
+            articles.last().map(ArticleCursor::after)
+        } else {
+            None
+        };
+        Self { articles, next_cursor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::article::Source;
+
+    fn article(source: Source, id: &str, title: &str, url: &str, ts: i64) -> Article {
+        Article::new(source, id, title.into(), url.into(), ts).unwrap()
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = ArticleCursor::after(&article(Source::Reddit("rust".into()), "9", "T", "", 1700000000));
+        let token = cursor.to_string();
+        assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(token.parse::<ArticleCursor>().unwrap(), cursor);
+
+        for bad in ["", "abc", "zz", "3a", "31323a"] {
+            assert!(bad.parse::<ArticleCursor>().is_err(), "'{}' should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn test_cursor_orders_by_time_then_id() {
+        let cursor = ArticleCursor::after(&article(Source::HackerNews, "5", "T", "", 100));
+        assert!(cursor.precedes(&article(Source::HackerNews, "4", "T", "", 100)));
+        assert!(cursor.precedes(&article(Source::HackerNews, "9", "T", "", 99)));
+        assert!(!cursor.precedes(&article(Source::HackerNews, "5", "T", "", 100)));
+        assert!(!cursor.precedes(&article(Source::HackerNews, "6", "T", "", 100)));
+    }
+
+    #[test]
+    fn test_filters() {
+        let mut a = article(Source::Reddit("rust".into()), "1", "Async Rust in 2024", "https://blog.Example.com/x", 100);
+        a.score = 40.0;
+        a.tags.insert("Async".into());
+
+        let query = |f: fn(&mut ArticleQuery)| {
+            let mut q = ArticleQuery::latest(10);
+            f(&mut q);
+            q.normalized().unwrap()
+        };
+        assert!(query(|_| {}).matches(&a));
+        assert!(query(|q| q.sources = vec!["rd".into()]).matches(&a));
+        assert!(query(|q| q.sources = vec!["hn".into(), "RD-rust".into()]).matches(&a));
+        assert!(!query(|q| q.sources = vec!["rd-go".into()]).matches(&a));
+        assert!(query(|q| q.tags = vec!["async".into()]).matches(&a));
+        assert!(!query(|q| q.tags = vec!["async".into(), "tokio".into()]).matches(&a));
+        assert!(query(|q| q.since = Some(100)).matches(&a));
+        assert!(!query(|q| q.until = Some(100)).matches(&a));
+        assert!(!query(|q| q.min_score = Some(40.5)).matches(&a));
+        assert!(query(|q| q.domain = Some("www.example.com".into())).matches(&a));
+        assert!(!query(|q| q.domain = Some("ample.com".into())).matches(&a));
+        assert!(query(|q| q.text = Some(" RUST in ".into())).matches(&a));
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(ArticleQuery::latest(0).normalized().is_err());
+        assert!(ArticleQuery::latest(MAX_QUERY_LIMIT + 1).normalized().is_err());
+        let mut q = ArticleQuery::latest(10);
+        q.since = Some(10);
+        q.until = Some(10);
+        assert!(q.normalized().is_err());
+        let mut q = ArticleQuery::latest(10);
+        q.min_score = Some(f64::NAN);
+        assert!(q.normalized().is_err());
+    }
+}
@@ -1,31 +1,135 @@
 // Trait definitions for data access (ports)
-use crate::article::{Article, ArticleId};
+use crate::article::{Article, ArticleId, ArticleSnapshot, SaveOutcome};
+use crate::briefing::Briefing;
+use crate::cooccurrence::CooccurrenceGraph;
+use crate::delivery::BriefingDelivery;
 use crate::error::DomainError;
-use crate::trend::{TimelineEvent, TrendReport};
+use crate::interaction::InteractionEvent;
+use crate::query::{ArticlePage, ArticleQuery};
+use crate::search::{SearchHit, SearchQuery};
+use crate::time::TimeWindow;
+use crate::topic::{Topic, TopicSlug};
+use crate::trend::{TimelineEvent, TimelineEventId, TrendReport};
 use crate::user::{UserId, UserProfile};
+use crate::watchlist::WatchAlert;
+use crate::webhook::{WebhookDelivery, WebhookId, WebhookSubscription};
 use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::collections::HashSet;
 
 #[async_trait]
 pub trait ArticleRepo: Send + Sync {
-    async fn save(&self, article: &Article) -> Result<(), DomainError>;
+    /// Stores a new article, or merges a fresh sighting into the stored one (see `Article::merged_with`).
+    async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+    /// Saves a batch in one transaction: every article is stored or merged, or none is.
+    /// Outcomes are in input order.
+    async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
     async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
     async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+    /// Matching articles newest first, starting after the query's cursor. Expects a normalized query.
+    async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+    /// Full-text matches, most relevant first, with highlighted snippets.
+    async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+    /// Deletes articles whose last activity is before `before`, except those in `keep`. Returns how many.
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+}
+
+#[async_trait]
+pub trait SnapshotRepo: Send + Sync {
+    /// Appends snapshots; a snapshot of an article taken at the same time as a stored one replaces it.
+    async fn append(&self, snapshots: &[ArticleSnapshot]) -> Result<(), DomainError>;
+    /// Snapshots of `article_id`, oldest first.
+    async fn list_for_article(&self, article_id: &ArticleId) -> Result<Vec<ArticleSnapshot>, DomainError>;
+    /// Deletes snapshots captured before `before`, except those of articles in `keep`. Returns how many.
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
 }
 
 #[async_trait]
 pub trait TrendRepo: Send + Sync {
     async fn save_report(&self, report: &TrendReport) -> Result<(), DomainError>;
     async fn find_latest_report(&self) -> Result<Option<TrendReport>, DomainError>;
+    /// Deletes reports taken before `before`, never the latest one. Returns how many.
+    async fn prune(&self, before: i64) -> Result<usize, DomainError>;
 }
 
 #[async_trait]
 pub trait TimelineRepo: Send + Sync {
     async fn save_event(&self, event: &TimelineEvent) -> Result<(), DomainError>;
+    async fn find_event(&self, id: &TimelineEventId) -> Result<Option<TimelineEvent>, DomainError>;
     async fn list_events(&self) -> Result<Vec<TimelineEvent>, DomainError>;
+    async fn delete_event(&self, id: &TimelineEventId) -> Result<(), DomainError>;
 }
 
 #[async_trait]
 pub trait UserRepo: Send + Sync {
     async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
     async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+    async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+}
+
+#[async_trait]
+pub trait TopicRepo: Send + Sync {
+    async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+    async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+    async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+}
+
+#[async_trait]
+pub trait CooccurrenceRepo: Send + Sync {
+    /// Stores the graph for its window, replacing any graph previously built for the same period.
+    async fn save_graph(&self, graph: &CooccurrenceGraph) -> Result<(), DomainError>;
+    async fn find_graph(&self, window: &TimeWindow) -> Result<Option<CooccurrenceGraph>, DomainError>;
+    async fn find_latest_graph(&self) -> Result<Option<CooccurrenceGraph>, DomainError>;
+}
+
+#[async_trait]
+pub trait InteractionRepo: Send + Sync {
+    /// Appends events; stored events are never updated.
+    async fn append(&self, events: &[InteractionEvent]) -> Result<(), DomainError>;
+    /// Events of `user_id` at or after `since`, oldest first.
+    async fn list_for_user(&self, user_id: &UserId, since: i64) -> Result<Vec<InteractionEvent>, DomainError>;
+    /// Articles any user marked "want to learn", which serves as a bookmark.
+    async fn bookmarked_articles(&self) -> Result<HashSet<ArticleId>, DomainError>;
+}
+
+#[async_trait]
+pub trait BriefingRepo: Send + Sync {
+    /// Stores the briefing, replacing any briefing of the same user and date.
+    async fn save(&self, briefing: &Briefing) -> Result<(), DomainError>;
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<Briefing>, DomainError>;
+    /// Past briefings of `user_id`, newest first.
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<Briefing>, DomainError>;
+}
+
+#[async_trait]
+pub trait DeliveryRepo: Send + Sync {
+    /// Stores the log entry, replacing any entry of the same user and date.
+    async fn save(&self, delivery: &BriefingDelivery) -> Result<(), DomainError>;
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<BriefingDelivery>, DomainError>;
+    /// Log entries of `user_id`, newest date first.
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<BriefingDelivery>, DomainError>;
+}
+
+#[async_trait]
+pub trait WebhookRepo: Send + Sync {
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), DomainError>;
+    async fn find_subscription(&self, id: &WebhookId) -> Result<Option<WebhookSubscription>, DomainError>;
+    /// All subscriptions, oldest first.
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, DomainError>;
+    async fn delete_subscription(&self, id: &WebhookId) -> Result<(), DomainError>;
+    /// Stores the delivery, replacing any delivery with the same ID.
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), DomainError>;
+    /// Deliveries waiting for an attempt at `now`, oldest first.
+    async fn list_due_deliveries(&self, now: i64, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+    /// Delivery log of one subscription, newest first.
+    async fn list_deliveries(&self, subscription_id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+}
+
+#[async_trait]
+pub trait AlertRepo: Send + Sync {
+    /// Stores the alert unless one exists for the same user, rule and article.
+    /// Returns whether it was new.
+    async fn save(&self, alert: &WatchAlert) -> Result<bool, DomainError>;
+    /// Alerts of one user, newest first.
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<WatchAlert>, DomainError>;
 }
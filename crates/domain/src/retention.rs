@@ -0,0 +1,75 @@
+// Domain entities for data retention and pruning
+use crate::error::DomainError;
+use serde::{Deserialize, Serialize};
+
+const DAY: i64 = 24 * 3600;
+
+/// How long raw data is kept. Articles linked from a timeline event or bookmarked (marked
+/// "want to learn" by any user) are kept forever, along with their snapshots, and the latest
+/// trend report is never removed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    pub article_days: u32, // Counted from when an article was published or last ingested, whichever is later
+    pub trend_report_days: u32,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            article_days: 180,
+            trend_report_days: 2 * 365,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    pub fn new(article_days: u32, trend_report_days: u32) -> Result<Self, DomainError> {
+        if article_days == 0 || trend_report_days == 0 {
+            return Err(DomainError::Validation("Retention periods must be at least one day".to_string()));
+        }
+        Ok(Self {
+            article_days,
+            trend_report_days,
+        })
+    }
+
+    /// Articles and snapshots older than this are pruned.
+    pub fn article_cutoff(&self, now: i64) -> i64 {
+        now - self.article_days as i64 * DAY
+    }
+
+    /// Trend reports older than this are pruned.
+    pub fn trend_report_cutoff(&self, now: i64) -> i64 {
+        now - self.trend_report_days as i64 * DAY
+    }
+}
+
+/// Rows removed by one pruning run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct PruneReport {
+    pub articles: usize,
+    pub snapshots: usize,
+    pub trend_reports: usize,
+}
+
+impl PruneReport {
+    pub fn total(&self) -> usize {
+        self.articles + self.snapshots + self.trend_reports
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cutoffs() {
+        let policy = RetentionPolicy::default();
+        let now = 1_000 * DAY;
+        assert_eq!(policy.article_cutoff(now), 820 * DAY);
+        assert_eq!(policy.trend_report_cutoff(now), 270 * DAY);
+        assert!(RetentionPolicy::new(0, 30).is_err());
+        assert!(RetentionPolicy::new(30, 0).is_err());
+        assert_eq!(RetentionPolicy::new(7, 30).unwrap().article_cutoff(now), 993 * DAY);
+    }
+}
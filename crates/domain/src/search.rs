@@ -0,0 +1,369 @@
+// Domain entities for full-text article search
+use crate::article::Article;
+use crate::error::DomainError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+pub const MAX_QUERY_LEN: usize = 200;
+pub const MAX_SEARCH_TERMS: usize = 16;
+pub const HIGHLIGHT_OPEN: &str = "<mark>";
+pub const HIGHLIGHT_CLOSE: &str = "</mark>";
+pub const SNIPPET_ELLIPSIS: &str = "…";
+// Private-use code points the backends delimit matches with; `highlight` turns them into markup
+pub const MATCH_START: char = '\u{E000}';
+pub const MATCH_END: char = '\u{E001}';
+pub const SNIPPET_TOKENS: usize = 16;
+
+// Column weights, highest first: title, tags, author, text
+pub const COLUMN_WEIGHTS: [f64; 4] = [10.0, 5.0, 2.0, 1.0];
+
+/// A word, a `prefix*`, or a `"quoted phrase"` (which may also end in a prefix).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTerm {
+    pub words: Vec<String>, // Lower-cased
+    pub prefix: bool,       // The last word matches any word starting with it
+}
+
+impl SearchTerm {
+    /// Start positions of the term in a lower-cased token list.
+    fn positions(&self, tokens: &[String]) -> Vec<usize> {
+        let n = self.words.len();
+        if n == 0 || tokens.len() < n {
+            return Vec::new();
+        }
+        (0..=tokens.len() - n)
+            .filter(|&start| {
+                self.words.iter().enumerate().all(|(i, word)| {
+                    let token = &tokens[start + i];
+                    if self.prefix && i == n - 1 {
+                        token.starts_with(word.as_str())
+                    } else {
+                        token == word
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for SearchTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.words.as_slice() {
+            [word] => f.write_str(word)?,
+            words => write!(f, "\"{}\"", words.join(" "))?,
+        }
+        if self.prefix {
+            f.write_str("*")?;
+        }
+        Ok(())
+    }
+}
+
+/// Search input such as `tokio "async rust" sql*`; every term must match somewhere in the
+/// article's title, tags, author or text. Case and punctuation are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<SearchTerm>,
+}
+
+impl SearchQuery {
+    pub fn terms(&self) -> &[SearchTerm] {
+        &self.terms
+    }
+
+    /// FTS5 MATCH expression. Every word is quoted, so input cannot smuggle in FTS operators.
+    pub fn to_fts5(&self) -> String {
+        self.terms
+            .iter()
+            .map(|term| {
+                let quoted = format!("\"{}\"", term.words.join(" "));
+                if term.prefix {
+                    format!("{} *", quoted)
+                } else {
+                    quoted
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// PostgreSQL `to_tsquery` input: terms ANDed, phrase words joined with `<->`. Words are
+    /// alphanumeric runs, so quoting each one is enough to keep input out of the query syntax.
+    pub fn to_tsquery(&self) -> String {
+        self.terms
+            .iter()
+            .map(|term| {
+                let last = term.words.len() - 1;
+                let words: Vec<String> = term
+                    .words
+                    .iter()
+                    .enumerate()
+                    .map(|(i, word)| if term.prefix && i == last { format!("'{}':*", word) } else { format!("'{}'", word) })
+                    .collect();
+                words.join(" <-> ")
+            })
+            .collect::<Vec<_>>()
+            .join(" & ")
+    }
+
+    /// Weighted count of matching columns, or None unless every term matches.
+    /// Used where no FTS index is available; SQLite ranks with bm25 instead.
+    pub fn relevance(&self, article: &Article) -> Option<f64> {
+        let columns = columns(article).map(|text| lowercase(&tokens(&text)));
+        let mut relevance = 0.0;
+        for term in &self.terms {
+            let hits: f64 = columns
+                .iter()
+                .zip(COLUMN_WEIGHTS)
+                .map(|(column, weight)| weight * term.positions(column).len() as f64)
+                .sum();
+            if hits == 0.0 {
+                return None;
+            }
+            relevance += hits;
+        }
+        Some(relevance)
+    }
+
+    /// Up to `SNIPPET_TOKENS` words around the first match in the best column, as `highlight`
+    /// renders them. Mirrors FTS5 `snippet()` closely enough for tests.
+    pub fn snippet(&self, article: &Article) -> String {
+        for text in columns(article) {
+            let originals = tokens(&text);
+            let lowered = lowercase(&originals);
+            let mut marked = vec![false; lowered.len()];
+            for term in &self.terms {
+                for start in term.positions(&lowered) {
+                    marked[start..start + term.words.len()].fill(true);
+                }
+            }
+            let Some(first) = marked.iter().position(|m| *m) else {
+                continue;
+            };
+
+            let start = first.saturating_sub(SNIPPET_TOKENS / 4);
+            let end = (start + SNIPPET_TOKENS).min(originals.len());
+            let mut snippet = if start > 0 { SNIPPET_ELLIPSIS.to_string() } else { String::new() };
+            for i in start..end {
+                if i > start {
+                    snippet.push(' ');
+                }
+                if marked[i] {
+                    snippet.push(MATCH_START);
+                    snippet.push_str(originals[i]);
+                    snippet.push(MATCH_END);
+                } else {
+                    snippet.push_str(originals[i]);
+                }
+            }
+            if end < originals.len() {
+                snippet.push_str(SNIPPET_ELLIPSIS);
+            }
+            return highlight(&snippet);
+        }
+        String::new()
+    }
+}
+
+/// HTML-escapes a snippet and wraps the matches delimited by `MATCH_START`/`MATCH_END` in
+/// `HIGHLIGHT_OPEN`/`HIGHLIGHT_CLOSE`, so only the highlight tags are markup.
+pub fn highlight(snippet: &str) -> String {
+    let mut out = String::with_capacity(snippet.len());
+    for c in snippet.chars() {
+        match c {
+            MATCH_START => out.push_str(HIGHLIGHT_OPEN),
+            MATCH_END => out.push_str(HIGHLIGHT_CLOSE),
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn columns(article: &Article) -> [String; 4] {
+    let mut tags: Vec<&str> = article.tags.iter().map(String::as_str).collect();
+    tags.sort();
+    [article.title.clone(), tags.join(" "), article.author.clone(), article.text.clone()]
+}
+
+/// Alphanumeric runs, like the FTS5 unicode61 tokenizer.
+fn tokens(text: &str) -> Vec<&str> {
+    text.split(|c: char| !c.is_alphanumeric()).filter(|t| !t.is_empty()).collect()
+}
+
+fn lowercase(tokens: &[&str]) -> Vec<String> {
+    tokens.iter().map(|t| t.to_lowercase()).collect()
+}
+
+impl FromStr for SearchQuery {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() > MAX_QUERY_LEN {
+            return Err(DomainError::Validation(format!(
+                "Search query is longer than {} characters",
+                MAX_QUERY_LEN
+            )));
+        }
+
+        let mut terms = Vec::new();
+        let mut rest = s.trim_start();
+        while !rest.is_empty() {
+            let (raw, remainder) = match rest.strip_prefix('"') {
+                Some(quoted) => {
+                    let end = quoted
+                        .find('"')
+                        .ok_or_else(|| DomainError::Validation("Unterminated quote in search query".to_string()))?;
+                    (&quoted[..end], &quoted[end + 1..])
+                }
+                None => {
+                    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                    (&rest[..end], &rest[end..])
+                }
+            };
+            let prefix = remainder.starts_with('*') || raw.ends_with('*');
+            let remainder = remainder.strip_prefix('*').unwrap_or(remainder);
+            let words = lowercase(&tokens(raw));
+            if !words.is_empty() {
+                terms.push(SearchTerm { words, prefix });
+            }
+            rest = remainder.trim_start();
+        }
+
+        if terms.is_empty() {
+            return Err(DomainError::Validation("Search query has no words".to_string()));
+        }
+        if terms.len() > MAX_SEARCH_TERMS {
+            return Err(DomainError::Validation(format!(
+                "Search query has more than {} terms",
+                MAX_SEARCH_TERMS
+            )));
+        }
+        Ok(Self { terms })
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, term) in self.terms.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", term)?;
+        }
+        Ok(())
+    }
+}
+
+/// A matching article before recency blending.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub article: Article,
+    pub relevance: f64, // Higher is better
+    pub snippet: String,
+}
+
+/// Blends text relevance with freshness. An article keeps `1 - recency_weight` of its
+/// relevance however old it is; the rest halves every `half_life_secs`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchRanking {
+    pub half_life_secs: i64,
+    pub recency_weight: f64,
+}
+
+impl Default for SearchRanking {
+    fn default() -> Self {
+        Self {
+            half_life_secs: 3 * 24 * 3600,
+            recency_weight: 0.5,
+        }
+    }
+}
+
+impl SearchRanking {
+    pub fn score(&self, relevance: f64, timestamp: i64, now: i64) -> f64 {
+        let age = (now - timestamp).max(0) as f64;
+        let freshness = 0.5f64.powf(age / self.half_life_secs.max(1) as f64);
+        relevance * (1.0 - self.recency_weight + self.recency_weight * freshness)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::article::Source;
+
+    fn article(title: &str, text: &str) -> Article {
+        let mut a = Article::new(Source::HackerNews, "1", title.into(), "".into(), 0).unwrap();
+        a.text = text.into();
+        a
+    }
+
+    #[test]
+    fn test_parse_and_canonical_form() {
+        let query: SearchQuery = r#"  Tokio "Async  Rust" sql* "zero-copy"*  "#.parse().unwrap();
+        assert_eq!(query.to_string(), r#"tokio "async rust" sql* "zero copy"*"#);
+        assert_eq!(query.to_fts5(), r#""tokio" "async rust" "sql" * "zero copy" *"#);
+        assert_eq!(query.to_tsquery(), "'tokio' & 'async' <-> 'rust' & 'sql':* & 'zero' <-> 'copy':*");
+
+        // Operators and quotes in words are plain text to FTS5
+        let query: SearchQuery = "NEAR(a b) OR c:d".parse().unwrap();
+        assert_eq!(query.to_fts5(), r#""near a" "b" "or" "c d""#);
+
+        let too_many = "a ".repeat(MAX_SEARCH_TERMS + 1);
+        for bad in ["", "  ", "\"unterminated", "* -- ()", too_many.as_str()] {
+            assert!(bad.parse::<SearchQuery>().is_err(), "'{}' should not parse", bad);
+        }
+    }
+
+    #[test]
+    fn test_relevance_requires_every_term() {
+        let a = article("Async Rust in production", "We moved our services to tokio last year.");
+        let relevance = |q: &str| q.parse::<SearchQuery>().unwrap().relevance(&a);
+
+        assert_eq!(relevance("rust"), Some(10.0));
+        assert_eq!(relevance("tokio"), Some(1.0));
+        assert_eq!(relevance("\"async rust\" tok*"), Some(11.0));
+        assert!(relevance("\"rust async\"").is_none());
+        assert!(relevance("rust golang").is_none());
+        assert!(relevance("prod").is_none());
+    }
+
+    #[test]
+    fn test_snippet_highlights_matches() {
+        let a = article(
+            "Release notes",
+            "Today we announce that the new release of SQLite adds JSONB support and faster queries across the board for everyone out there",
+        );
+        let query: SearchQuery = "sqlite json*".parse().unwrap();
+        assert_eq!(
+            query.snippet(&a),
+            "…the new release of <mark>SQLite</mark> adds <mark>JSONB</mark> support and faster queries across the board for everyone…"
+        );
+        assert_eq!(query.snippet(&article("Nothing", "")), "");
+    }
+
+    #[test]
+    fn test_highlight_escapes_text() {
+        let raw = format!("<img src=x onerror='a&b'> {}\"SQLite\"{}", MATCH_START, MATCH_END);
+        assert_eq!(
+            highlight(&raw),
+            "&lt;img src=x onerror=&#39;a&amp;b&#39;&gt; <mark>&quot;SQLite&quot;</mark>"
+        );
+    }
+
+    #[test]
+    fn test_ranking_blends_recency() {
+        let ranking = SearchRanking::default();
+        let day = 24 * 3600;
+        assert_eq!(ranking.score(10.0, 0, 0), 10.0);
+        assert!((ranking.score(10.0, 0, 3 * day) - 7.5).abs() < 1e-9);
+        assert!((ranking.score(10.0, 0, 300 * day) - 5.0).abs() < 1e-9); // Floors at 1 - recency_weight
+        assert_eq!(ranking.score(10.0, 100, 0), 10.0); // Future-dated counts as fresh
+    }
+}
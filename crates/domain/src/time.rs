@@ -0,0 +1,66 @@
+// Time value objects
+use crate::error::DomainError;
+use serde::{Deserialize, Serialize};
+
+/// Half-open interval `[start, end)` of Unix timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TimeWindow {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl TimeWindow {
+    pub fn new(start: i64, end: i64) -> Result<Self, DomainError> {
+        if end <= start {
+            return Err(DomainError::Validation(
+                "Time window end must be after start".to_string(),
+            ));
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Window of `seconds` length ending at `end`.
+    pub fn ending_at(end: i64, seconds: i64) -> Result<Self, DomainError> {
+        Self::new(end - seconds, end)
+    }
+
+    /// Window of `seconds` length ending at the last multiple of `step` at or before `now`,
+    /// so every call within the same step yields the same window.
+    pub fn aligned_ending_at(now: i64, seconds: i64, step: i64) -> Result<Self, DomainError> {
+        if step <= 0 {
+            return Err(DomainError::Validation("Alignment step must be positive".to_string()));
+        }
+        Self::ending_at(now - now.rem_euclid(step), seconds)
+    }
+
+    pub fn contains(&self, timestamp: i64) -> bool {
+        timestamp >= self.start && timestamp < self.end
+    }
+
+    pub fn duration_secs(&self) -> i64 {
+        self.end - self.start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_bounds() {
+        let window = TimeWindow::new(100, 200).unwrap();
+        assert!(window.contains(100));
+        assert!(window.contains(199));
+        assert!(!window.contains(200)); // End is exclusive
+        assert!(!window.contains(99));
+        assert_eq!(window.duration_secs(), 100);
+
+        assert!(TimeWindow::new(200, 200).is_err());
+        assert_eq!(TimeWindow::ending_at(1000, 3600).unwrap().start, 1000 - 3600);
+
+        let aligned = TimeWindow::aligned_ending_at(7300, 3600, 3600).unwrap();
+        assert_eq!((aligned.start, aligned.end), (3600, 7200));
+        assert_eq!(TimeWindow::aligned_ending_at(10_799, 3600, 3600).unwrap(), aligned);
+        assert!(TimeWindow::aligned_ending_at(7300, 3600, 0).is_err());
+    }
+}
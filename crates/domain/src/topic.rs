@@ -0,0 +1,301 @@
+// Domain entities for Topics
+use crate::error::DomainError;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+/// Canonical identifier of a topic, e.g. `rust`, `llm`, `webassembly`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct TopicSlug(String);
+
+impl TopicSlug {
+    pub fn new(slug: &str) -> Result<Self, DomainError> {
+        let slug = slug.trim();
+        if slug.is_empty() {
+            return Err(DomainError::Validation("Topic slug cannot be empty".to_string()));
+        }
+        if !slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(DomainError::Validation(format!(
+                "Topic slug '{}' must be lowercase alphanumeric or '-'",
+                slug
+            )));
+        }
+        Ok(Self(slug.to_string()))
+    }
+
+    /// Reconstruct from a previously-validated stored string.
+    /// Only use for DB deserialization — not for creating new slugs.
+    pub fn from_persisted(s: String) -> Self {
+        Self(s)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TopicSlug {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Default)]
+pub enum TopicCategory {
+    Ai,
+    Web,
+    Hardware,
+    Science,
+    Security,
+    Cloud,
+    Languages,
+    DevTools,
+    Crypto,
+    #[default]
+    Other,
+}
+
+impl TopicCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TopicCategory::Ai => "ai",
+            TopicCategory::Web => "web",
+            TopicCategory::Hardware => "hardware",
+            TopicCategory::Science => "science",
+            TopicCategory::Security => "security",
+            TopicCategory::Cloud => "cloud",
+            TopicCategory::Languages => "languages",
+            TopicCategory::DevTools => "devtools",
+            TopicCategory::Crypto => "crypto",
+            TopicCategory::Other => "other",
+        }
+    }
+}
+
+impl fmt::Display for TopicCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TopicCategory {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "ai" => Ok(TopicCategory::Ai),
+            "web" => Ok(TopicCategory::Web),
+            "hardware" => Ok(TopicCategory::Hardware),
+            "science" => Ok(TopicCategory::Science),
+            "security" => Ok(TopicCategory::Security),
+            "cloud" => Ok(TopicCategory::Cloud),
+            "languages" => Ok(TopicCategory::Languages),
+            "devtools" => Ok(TopicCategory::DevTools),
+            "crypto" => Ok(TopicCategory::Crypto),
+            "other" => Ok(TopicCategory::Other),
+            other => Err(DomainError::Validation(format!("Unknown topic category '{}'", other))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Topic {
+    pub slug: TopicSlug,
+    pub display_name: String,
+    pub aliases: Vec<String>, // Normalized (lowercase) alternative spellings
+    pub category: TopicCategory,
+}
+
+impl Topic {
+    pub fn new(slug: TopicSlug, display_name: impl Into<String>, category: TopicCategory) -> Self {
+        Self {
+            slug,
+            display_name: display_name.into(),
+            aliases: Vec::new(),
+            category,
+        }
+    }
+
+    pub fn with_aliases(mut self, aliases: &[&str]) -> Self {
+        for alias in aliases {
+            let alias = normalize_term(alias);
+            if !alias.is_empty() && !self.aliases.contains(&alias) {
+                self.aliases.push(alias);
+            }
+        }
+        self
+    }
+
+    /// True if `term` is the slug, the display name or one of the aliases.
+    pub fn matches(&self, term: &str) -> bool {
+        let term = normalize_term(term);
+        term == self.slug.as_str()
+            || term == normalize_term(&self.display_name)
+            || self.aliases.contains(&term)
+    }
+}
+
+/// Lowercased, trimmed form used for all topic lookups.
+pub fn normalize_term(term: &str) -> String {
+    term.trim().to_lowercase()
+}
+
+/// Lookup structure resolving raw keywords and tags to canonical topics.
+#[derive(Debug, Clone, Default)]
+pub struct TopicTaxonomy {
+    topics: HashMap<TopicSlug, Topic>,
+    index: HashMap<String, TopicSlug>,
+}
+
+impl TopicTaxonomy {
+    pub fn new(topics: Vec<Topic>) -> Self {
+        let mut taxonomy = Self::default();
+        for topic in topics {
+            taxonomy.index.insert(topic.slug.as_str().to_string(), topic.slug.clone());
+            taxonomy
+                .index
+                .insert(normalize_term(&topic.display_name), topic.slug.clone());
+            for alias in &topic.aliases {
+                taxonomy.index.insert(alias.clone(), topic.slug.clone());
+            }
+            taxonomy.topics.insert(topic.slug.clone(), topic);
+        }
+        taxonomy
+    }
+
+    pub fn resolve(&self, term: &str) -> Option<&Topic> {
+        self.index
+            .get(&normalize_term(term))
+            .and_then(|slug| self.topics.get(slug))
+    }
+
+    /// Canonical slug for a known term, or the normalized term itself.
+    pub fn canonicalize(&self, term: &str) -> String {
+        match self.resolve(term) {
+            Some(topic) => topic.slug.as_str().to_string(),
+            None => normalize_term(term),
+        }
+    }
+
+    pub fn category_of(&self, term: &str) -> TopicCategory {
+        self.resolve(term).map(|t| t.category).unwrap_or_default()
+    }
+
+    pub fn topics(&self) -> impl Iterator<Item = &Topic> {
+        self.topics.values()
+    }
+
+    /// Canonical slugs of every known topic mentioned as a whole word in `text`.
+    pub fn detect(&self, text: &str) -> BTreeSet<String> {
+        let haystack = format!(" {} ", word_boundaries(text));
+        self.index
+            .iter()
+            .filter(|(term, _)| haystack.contains(&format!(" {} ", word_boundaries(term))))
+            .map(|(_, slug)| slug.as_str().to_string())
+            .collect()
+    }
+}
+
+// Lowercases and replaces punctuation with single spaces so terms match on word boundaries
+pub(crate) fn word_boundaries(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Built-in topics used to seed an empty repository.
+pub fn default_topics() -> Vec<Topic> {
+    let seed: &[(&str, &str, TopicCategory, &[&str])] = &[
+        ("ai", "AI", TopicCategory::Ai, &["artificial intelligence", "machine learning", "ml"]),
+        ("llm", "LLMs", TopicCategory::Ai, &["large language model", "gpt", "chatgpt", "claude"]),
+        ("agents", "AI Agents", TopicCategory::Ai, &["agent", "ai agent", "mcp"]),
+        ("rust", "Rust", TopicCategory::Languages, &["rustlang"]),
+        ("go", "Go", TopicCategory::Languages, &["golang"]),
+        ("python", "Python", TopicCategory::Languages, &[]),
+        ("javascript", "JavaScript", TopicCategory::Web, &["js", "typescript", "ts", "node", "nodejs"]),
+        ("webassembly", "WebAssembly", TopicCategory::Web, &["wasm"]),
+        ("browsers", "Browsers", TopicCategory::Web, &["chrome", "firefox", "safari"]),
+        ("gpu", "GPUs", TopicCategory::Hardware, &["nvidia", "cuda"]),
+        ("chips", "Chips", TopicCategory::Hardware, &["semiconductor", "risc-v", "arm"]),
+        ("quantum", "Quantum Computing", TopicCategory::Science, &["quantum computing"]),
+        ("space", "Space", TopicCategory::Science, &["nasa", "spacex"]),
+        ("security", "Security", TopicCategory::Security, &["vulnerability", "cve", "exploit"]),
+        ("cloud", "Cloud", TopicCategory::Cloud, &["aws", "gcp", "azure", "kubernetes", "k8s"]),
+        ("databases", "Databases", TopicCategory::DevTools, &["database", "sqlite", "postgres", "postgresql"]),
+        ("crypto", "Crypto", TopicCategory::Crypto, &["bitcoin", "ethereum", "blockchain"]),
+    ];
+
+    seed.iter()
+        .map(|(slug, name, category, aliases)| {
+            Topic::new(TopicSlug::from_persisted(slug.to_string()), *name, *category)
+                .with_aliases(aliases)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slug_validation() {
+        assert!(TopicSlug::new("rust").is_ok());
+        assert!(TopicSlug::new("risc-v").is_ok());
+        assert!(TopicSlug::new("").is_err());
+        assert!(TopicSlug::new("Rust").is_err());
+        assert!(TopicSlug::new("c++").is_err());
+    }
+
+    #[test]
+    fn test_category_roundtrip() {
+        for category in [TopicCategory::Ai, TopicCategory::DevTools, TopicCategory::Other] {
+            assert_eq!(category.as_str().parse::<TopicCategory>().unwrap(), category);
+        }
+        assert!("astrology".parse::<TopicCategory>().is_err());
+    }
+
+    #[test]
+    fn test_taxonomy_resolves_aliases() {
+        let taxonomy = TopicTaxonomy::new(default_topics());
+
+        assert_eq!(taxonomy.canonicalize("Rust"), "rust");
+        assert_eq!(taxonomy.canonicalize("WASM"), "webassembly");
+        assert_eq!(taxonomy.canonicalize(" golang "), "go");
+        assert_eq!(taxonomy.category_of("ChatGPT"), TopicCategory::Ai);
+
+        // Unknown terms are normalized but otherwise left alone
+        assert_eq!(taxonomy.canonicalize("Zig"), "zig");
+        assert_eq!(taxonomy.category_of("Zig"), TopicCategory::Other);
+    }
+
+    #[test]
+    fn test_detect_whole_words_only() {
+        let taxonomy = TopicTaxonomy::new(default_topics());
+
+        let found = taxonomy.detect("Compiling Rust to WASM for the browser, with a large language model");
+        let expected: BTreeSet<String> = ["rust", "webassembly", "llm"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(found, expected);
+
+        // "trust" must not match "rust", "goal" must not match "go"
+        assert!(taxonomy.detect("Trust and goals").is_empty());
+        assert!(taxonomy.detect("RISC-V boards are here").contains("chips"));
+    }
+
+    #[test]
+    fn test_topic_matches() {
+        let topic = Topic::new(TopicSlug::new("webassembly").unwrap(), "WebAssembly", TopicCategory::Web)
+            .with_aliases(&["WASM", "wasm"]);
+
+        assert_eq!(topic.aliases, vec!["wasm".to_string()]); // Normalized and deduplicated
+        assert!(topic.matches("webassembly"));
+        assert!(topic.matches("WebAssembly"));
+        assert!(topic.matches("Wasm"));
+        assert!(!topic.matches("javascript"));
+    }
+}
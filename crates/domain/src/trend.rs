@@ -1,5 +1,6 @@
 // Domain entities for Trends
 use crate::article::ArticleId;
+use crate::event::title_similarity;
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -36,6 +37,63 @@ pub struct TimelineEvent {
     pub description: String,
     pub category: String,
     pub importance_score: f64,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub article_id: Option<ArticleId>, // Source article for promoted or pinned milestones
+}
+
+/// Thresholds for promoting a story or event to the timeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MilestonePolicy {
+    pub top_percentile: f64, // 0-1, e.g. 0.95 promotes the top 5% by score
+    #[serde(default = "default_min_candidates")]
+    pub min_candidates: usize, // Fewer candidates than this rank too coarsely; only min_sources qualifies
+    pub min_sources: usize,    // Coverage by this many distinct sources also qualifies
+    pub dedup_days: i64,     // Similar titles within this many days are the same milestone
+    pub dedup_title_similarity: f64,
+}
+
+impl Default for MilestonePolicy {
+    fn default() -> Self {
+        Self {
+            top_percentile: 0.95,
+            min_candidates: default_min_candidates(),
+            min_sources: 3,
+            dedup_days: 7,
+            dedup_title_similarity: 0.5,
+        }
+    }
+}
+
+fn default_min_candidates() -> usize {
+    10
+}
+
+impl MilestonePolicy {
+    /// Fraction of `scores` strictly below `score`, normalized so the maximum ranks 1.0.
+    pub fn percentile_rank(score: f64, scores: &[f64]) -> f64 {
+        if scores.len() <= 1 {
+            return 1.0;
+        }
+        let below = scores.iter().filter(|&&s| s < score).count();
+        below as f64 / (scores.len() - 1) as f64
+    }
+
+    /// Whether a candidate ranked at `percentile` among `candidates` others is a milestone.
+    pub fn qualifies(&self, percentile: f64, candidates: usize, source_count: usize) -> bool {
+        (candidates >= self.min_candidates && percentile >= self.top_percentile) || source_count >= self.min_sources
+    }
+
+    /// 0-100 from the score percentile, plus 10 per additional covering source.
+    pub fn importance(&self, percentile: f64, source_count: usize) -> f64 {
+        percentile * 100.0 + 10.0 * source_count.saturating_sub(1) as f64
+    }
+
+    pub fn is_duplicate(&self, existing: &TimelineEvent, title: &str, date: NaiveDate) -> bool {
+        (existing.date - date).num_days().abs() <= self.dedup_days
+            && title_similarity(&existing.title, title) >= self.dedup_title_similarity
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -67,6 +125,52 @@ mod tests {
         assert_eq!(decoded.volume, 100);
     }
 
+    #[test]
+    fn test_milestone_percentile_and_qualification() {
+        let scores = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(MilestonePolicy::percentile_rank(5.0, &scores), 1.0);
+        assert_eq!(MilestonePolicy::percentile_rank(1.0, &scores), 0.0);
+        assert_eq!(MilestonePolicy::percentile_rank(3.0, &scores), 0.5);
+        assert_eq!(MilestonePolicy::percentile_rank(3.0, &[3.0]), 1.0);
+
+        let policy = MilestonePolicy::default();
+        assert!(policy.qualifies(0.96, 20, 1));
+        assert!(policy.qualifies(0.1, 20, 3)); // Multi-source coverage
+        assert!(!policy.qualifies(0.5, 20, 2));
+        assert!(policy.importance(1.0, 3) > policy.importance(1.0, 1));
+    }
+
+    #[test]
+    fn test_milestone_needs_enough_candidates_to_rank() {
+        let policy = MilestonePolicy::default();
+        // A lone low-score cluster ranks 1.0 but is no milestone
+        let percentile = MilestonePolicy::percentile_rank(0.5, &[0.5]);
+        assert!(!policy.qualifies(percentile, 1, 1));
+        assert!(!policy.qualifies(MilestonePolicy::percentile_rank(2.0, &[1.0, 2.0]), 2, 2));
+        // Small windows still promote widely covered stories
+        assert!(policy.qualifies(percentile, 1, 3));
+    }
+
+    #[test]
+    fn test_milestone_dedup() {
+        let policy = MilestonePolicy::default();
+        let date = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let existing = TimelineEvent {
+            id: TimelineEventId::from("tl-1"),
+            title: "OpenAI releases GPT-5".into(),
+            date,
+            description: "".into(),
+            category: "ai".into(),
+            importance_score: 90.0,
+            url: None,
+            article_id: None,
+        };
+
+        assert!(policy.is_duplicate(&existing, "GPT-5 released by OpenAI", date + chrono::Days::new(2)));
+        assert!(!policy.is_duplicate(&existing, "GPT-5 released by OpenAI", date + chrono::Days::new(30)));
+        assert!(!policy.is_duplicate(&existing, "Rust 2.0 announced", date));
+    }
+
     #[test]
     fn test_timeline_event_creation() {
         let date = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
@@ -77,6 +181,8 @@ mod tests {
             description: "Big launch".into(),
             category: "Tech".into(),
             importance_score: 10.0,
+            url: None,
+            article_id: None,
         };
         
         assert_eq!(event.date.year(), 2023);
@@ -1,7 +1,14 @@
 // Domain entities for Users
+use crate::article::Article;
+use crate::error::DomainError;
+use crate::mute::{MuteFilter, MuteRule, MuteRuleId, MAX_MUTE_RULES};
+use crate::watchlist::{WatchRule, WatchRuleId, MAX_WATCH_RULES};
+use chrono::{DateTime, NaiveDate, NaiveTime};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct UserId(String);
@@ -34,6 +41,37 @@ pub enum KnowledgeState {
     WantToLearn,
 }
 
+impl KnowledgeState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KnowledgeState::NeverSeen => "never_seen",
+            KnowledgeState::HeardOf => "heard_of",
+            KnowledgeState::KnowIt => "know_it",
+            KnowledgeState::WantToLearn => "want_to_learn",
+        }
+    }
+}
+
+impl fmt::Display for KnowledgeState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for KnowledgeState {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "never_seen" => Ok(KnowledgeState::NeverSeen),
+            "heard_of" => Ok(KnowledgeState::HeardOf),
+            "know_it" => Ok(KnowledgeState::KnowIt),
+            "want_to_learn" => Ok(KnowledgeState::WantToLearn),
+            other => Err(DomainError::Validation(format!("Unknown knowledge state '{}'", other))),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct KnowledgeMap {
     pub topics: HashMap<String, TopicKnowledge>,
@@ -53,12 +91,178 @@ pub struct UserProfile {
     pub id: UserId,
     pub knowledge: KnowledgeMap,
     pub settings: UserSettings,
+    #[serde(default)]
+    pub watchlist: Vec<WatchRule>,
+    #[serde(default)]
+    pub mutes: Vec<MuteRule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UserSettings {
     pub preferred_sources: Vec<String>,
     pub theme: String,
+    #[serde(default)]
+    pub time_zone: String, // IANA name, e.g. "Europe/Berlin"; empty means UTC
+    #[serde(default)]
+    pub delivery: DeliveryPreferences,
+}
+
+pub const THEMES: &[&str] = &["light", "dark", "system"];
+pub const EMAIL_FORMATS: &[&str] = &["html", "text"];
+pub const DEFAULT_SEND_AT: &str = "07:00";
+
+/// How and when the daily briefing is emailed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct DeliveryPreferences {
+    pub enabled: bool,
+    pub email: Option<String>,
+    pub send_at: String, // Local "HH:MM"; empty means DEFAULT_SEND_AT
+    pub format: String,  // One of EMAIL_FORMATS; empty means "html"
+}
+
+impl DeliveryPreferences {
+    pub fn validate(mut self) -> Result<Self, DomainError> {
+        self.email = self.email.map(|e| e.trim().to_string()).filter(|e| !e.is_empty());
+        if let Some(email) = &self.email {
+            let valid = email
+                .split_once('@')
+                .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'))
+                && !email.contains(char::is_whitespace);
+            if !valid {
+                return Err(DomainError::Validation(format!("Invalid email address '{}'", email)));
+            }
+        }
+        if self.enabled && self.email.is_none() {
+            return Err(DomainError::Validation(
+                "An email address is required to enable delivery".to_string(),
+            ));
+        }
+
+        self.send_at = self.send_at.trim().to_string();
+        if !self.send_at.is_empty() {
+            NaiveTime::parse_from_str(&self.send_at, "%H:%M").map_err(|_| {
+                DomainError::Validation(format!("Invalid send time '{}', expected HH:MM", self.send_at))
+            })?;
+        }
+
+        self.format = self.format.trim().to_lowercase();
+        if !self.format.is_empty() && !EMAIL_FORMATS.contains(&self.format.as_str()) {
+            return Err(DomainError::Validation(format!(
+                "Unknown email format '{}', expected one of {}",
+                self.format,
+                EMAIL_FORMATS.join(", ")
+            )));
+        }
+        Ok(self)
+    }
+
+    pub fn send_time(&self) -> NaiveTime {
+        let send_at = if self.send_at.is_empty() { DEFAULT_SEND_AT } else { &self.send_at };
+        NaiveTime::parse_from_str(send_at, "%H:%M").unwrap_or(NaiveTime::MIN)
+    }
+
+    pub fn wants_html(&self) -> bool {
+        self.format != "text"
+    }
+}
+
+/// Changes to a user's settings; fields left as None keep their stored value.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UserSettingsPatch {
+    pub preferred_sources: Option<Vec<String>>,
+    pub theme: Option<String>,
+    pub time_zone: Option<String>,
+    pub delivery: DeliveryPreferencesPatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DeliveryPreferencesPatch {
+    pub enabled: Option<bool>,
+    pub email: Option<String>,
+    pub send_at: Option<String>,
+    pub format: Option<String>,
+}
+
+impl UserSettings {
+    /// Normalizes source codes and checks the theme. An empty theme means "use the default".
+    pub fn validate(mut self) -> Result<Self, DomainError> {
+        self.theme = self.theme.trim().to_lowercase();
+        if !self.theme.is_empty() && !THEMES.contains(&self.theme.as_str()) {
+            return Err(DomainError::Validation(format!(
+                "Unknown theme '{}', expected one of {}",
+                self.theme,
+                THEMES.join(", ")
+            )));
+        }
+
+        let mut sources: Vec<String> = Vec::new();
+        for source in &self.preferred_sources {
+            let source = source.trim().to_lowercase();
+            if source.is_empty() {
+                return Err(DomainError::Validation("Preferred source cannot be empty".to_string()));
+            }
+            if !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        self.preferred_sources = sources;
+
+        self.time_zone = self.time_zone.trim().to_string();
+        if !self.time_zone.is_empty() {
+            self.time_zone.parse::<Tz>().map_err(|_| {
+                DomainError::Validation(format!("Unknown time zone '{}'", self.time_zone))
+            })?;
+        }
+        self.delivery = self.delivery.validate()?;
+        Ok(self)
+    }
+
+    /// Settings with the fields set in `patch` replaced; call `validate` on the result.
+    pub fn patched(mut self, patch: UserSettingsPatch) -> Self {
+        if let Some(sources) = patch.preferred_sources {
+            self.preferred_sources = sources;
+        }
+        if let Some(theme) = patch.theme {
+            self.theme = theme;
+        }
+        if let Some(time_zone) = patch.time_zone {
+            self.time_zone = time_zone;
+        }
+        let delivery = patch.delivery;
+        if let Some(enabled) = delivery.enabled {
+            self.delivery.enabled = enabled;
+        }
+        if let Some(email) = delivery.email {
+            self.delivery.email = Some(email); // Blank clears the address once validated
+        }
+        if let Some(send_at) = delivery.send_at {
+            self.delivery.send_at = send_at;
+        }
+        if let Some(format) = delivery.format {
+            self.delivery.format = format;
+        }
+        self
+    }
+
+    pub fn tz(&self) -> Tz {
+        self.time_zone.parse().unwrap_or(Tz::UTC)
+    }
+
+    /// The user's calendar date at `now`, in their time zone.
+    pub fn local_date(&self, now: i64) -> Result<NaiveDate, DomainError> {
+        DateTime::from_timestamp(now, 0)
+            .map(|dt| dt.with_timezone(&self.tz()).date_naive())
+            .ok_or_else(|| DomainError::Validation(format!("Timestamp {} out of range", now)))
+    }
+
+    /// Local date whose briefing should go out at `now`, once the local send time has passed.
+    pub fn delivery_due(&self, now: i64) -> Option<NaiveDate> {
+        if !self.delivery.enabled || self.delivery.email.is_none() {
+            return None;
+        }
+        let local = DateTime::from_timestamp(now, 0)?.with_timezone(&self.tz());
+        (local.time() >= self.delivery.send_time()).then(|| local.date_naive())
+    }
 }
 
 impl UserProfile {
@@ -67,12 +271,82 @@ impl UserProfile {
             id,
             knowledge: KnowledgeMap::default(),
             settings: UserSettings::default(),
+            watchlist: Vec::new(),
+            mutes: Vec::new(),
         }
     }
 
+    pub fn add_watch_rule(&mut self, rule: WatchRule) -> Result<(), DomainError> {
+        if self.watchlist.len() >= MAX_WATCH_RULES {
+            return Err(DomainError::Validation(format!(
+                "A watchlist holds at most {} rules",
+                MAX_WATCH_RULES
+            )));
+        }
+        if self.watchlist.iter().any(|r| r.name == rule.name) {
+            return Err(DomainError::AlreadyExists(format!("Watch rule '{}'", rule.name)));
+        }
+        self.watchlist.push(rule);
+        Ok(())
+    }
+
+    /// Returns false if no rule has this ID.
+    pub fn remove_watch_rule(&mut self, id: &WatchRuleId) -> bool {
+        let before = self.watchlist.len();
+        self.watchlist.retain(|r| &r.id != id);
+        self.watchlist.len() != before
+    }
+
+    /// Adds a mute rule, dropping expired ones. Muting the same target again replaces
+    /// the earlier rule, so "mute for 7 days" can be extended or made permanent.
+    pub fn add_mute(&mut self, rule: MuteRule, now: i64) -> Result<(), DomainError> {
+        self.mutes.retain(|r| r.is_active(now) && !r.same_target(&rule));
+        if self.mutes.len() >= MAX_MUTE_RULES {
+            return Err(DomainError::Validation(format!(
+                "At most {} mute rules are allowed",
+                MAX_MUTE_RULES
+            )));
+        }
+        self.mutes.push(rule);
+        Ok(())
+    }
+
+    /// Returns false if no rule has this ID.
+    pub fn remove_mute(&mut self, id: &MuteRuleId) -> bool {
+        let before = self.mutes.len();
+        self.mutes.retain(|r| &r.id != id);
+        self.mutes.len() != before
+    }
+
+    pub fn mute_filter(&self, now: i64) -> MuteFilter {
+        MuteFilter::new(&self.mutes, now)
+    }
+
+    pub fn watch_matches<'a>(&'a self, article: &'a Article) -> impl Iterator<Item = &'a WatchRule> + 'a {
+        self.watchlist.iter().filter(move |r| r.matches(article))
+    }
+
     pub fn update_knowledge(&mut self, topic: &str, state: KnowledgeState, now: i64) {
-        let entry = self
-            .knowledge
+        let entry = self.record_interaction(topic, now);
+        entry.state = state;
+    }
+
+    /// Counts an interaction with `topic` without changing its state.
+    pub fn record_interaction(&mut self, topic: &str, now: i64) -> &mut TopicKnowledge {
+        let entry = self.entry(topic, now);
+        entry.last_interaction = now;
+        entry.interaction_count += 1;
+        entry
+    }
+
+    /// Marks `topic` as seen at `now` without counting an interaction.
+    pub fn touch(&mut self, topic: &str, now: i64) {
+        let entry = self.entry(topic, now);
+        entry.last_interaction = entry.last_interaction.max(now);
+    }
+
+    fn entry(&mut self, topic: &str, now: i64) -> &mut TopicKnowledge {
+        self.knowledge
             .topics
             .entry(topic.to_string())
             .or_insert_with(|| TopicKnowledge {
@@ -81,11 +355,7 @@ impl UserProfile {
                 first_seen: now,
                 last_interaction: now,
                 interaction_count: 0,
-            });
-
-        entry.state = state;
-        entry.last_interaction = now;
-        entry.interaction_count += 1;
+            })
     }
 }
 
@@ -103,6 +373,117 @@ mod tests {
         assert_eq!(KnowledgeState::default(), KnowledgeState::NeverSeen);
     }
 
+    #[test]
+    fn test_knowledge_state_roundtrip() {
+        for state in [
+            KnowledgeState::NeverSeen,
+            KnowledgeState::HeardOf,
+            KnowledgeState::KnowIt,
+            KnowledgeState::WantToLearn,
+        ] {
+            assert_eq!(state.as_str().parse::<KnowledgeState>().unwrap(), state);
+        }
+        assert!("KnowIt".parse::<KnowledgeState>().is_err());
+    }
+
+    #[test]
+    fn test_settings_validation() {
+        let settings = UserSettings {
+            preferred_sources: vec![" HN ".into(), "hn".into(), "gh".into()],
+            theme: "Dark".into(),
+            time_zone: " Europe/Berlin ".into(),
+            ..Default::default()
+        }
+        .validate()
+        .unwrap();
+        assert_eq!(settings.preferred_sources, vec!["hn".to_string(), "gh".to_string()]);
+        assert_eq!(settings.theme, "dark");
+        assert_eq!(settings.time_zone, "Europe/Berlin");
+
+        let bad_theme = UserSettings { theme: "neon".into(), ..Default::default() };
+        assert!(bad_theme.validate().is_err());
+        let blank_source = UserSettings { preferred_sources: vec!["  ".into()], ..Default::default() };
+        assert!(blank_source.validate().is_err());
+        let bad_zone = UserSettings { time_zone: "Mars/Olympus".into(), ..Default::default() };
+        assert!(bad_zone.validate().is_err());
+    }
+
+    #[test]
+    fn test_settings_patch_keeps_unset_fields() {
+        let stored = UserSettings {
+            theme: "dark".into(),
+            time_zone: "Asia/Tokyo".into(),
+            delivery: DeliveryPreferences {
+                enabled: true,
+                email: Some("ada@example.com".into()),
+                send_at: "07:00".into(),
+                format: "text".into(),
+            },
+            ..Default::default()
+        };
+        let patch = UserSettingsPatch {
+            preferred_sources: Some(vec!["hn".into()]),
+            theme: Some("light".into()),
+            delivery: DeliveryPreferencesPatch { send_at: Some("06:00".into()), ..Default::default() },
+            ..Default::default()
+        };
+        let patched = stored.clone().patched(patch).validate().unwrap();
+        assert_eq!((patched.theme.as_str(), patched.preferred_sources.len()), ("light", 1));
+        assert_eq!(patched.time_zone, "Asia/Tokyo");
+        assert_eq!(patched.delivery, DeliveryPreferences { send_at: "06:00".into(), ..stored.delivery.clone() });
+
+        let clear_email = UserSettingsPatch {
+            delivery: DeliveryPreferencesPatch { enabled: Some(false), email: Some(" ".into()), ..Default::default() },
+            ..Default::default()
+        };
+        assert_eq!(stored.patched(clear_email).validate().unwrap().delivery.email, None);
+    }
+
+    #[test]
+    fn test_delivery_preferences_validation() {
+        let delivery = |email: Option<&str>, send_at: &str, format: &str| DeliveryPreferences {
+            enabled: true,
+            email: email.map(|e| e.to_string()),
+            send_at: send_at.into(),
+            format: format.into(),
+        };
+
+        let ok = delivery(Some(" ada@example.com "), "06:30", "Text").validate().unwrap();
+        assert_eq!(ok.email.as_deref(), Some("ada@example.com"));
+        assert_eq!(ok.send_time(), NaiveTime::from_hms_opt(6, 30, 0).unwrap());
+        assert!(!ok.wants_html());
+
+        assert!(delivery(None, "", "").validate().is_err()); // Enabled without address
+        assert!(delivery(Some("not-an-address"), "", "").validate().is_err());
+        assert!(delivery(Some("ada@example.com"), "7am", "").validate().is_err());
+        assert!(delivery(Some("ada@example.com"), "", "pdf").validate().is_err());
+    }
+
+    #[test]
+    fn test_delivery_due_in_local_time() {
+        let settings = UserSettings {
+            time_zone: "Asia/Tokyo".into(), // UTC+9, no DST
+            delivery: DeliveryPreferences {
+                enabled: true,
+                email: Some("ada@example.com".into()),
+                send_at: "07:00".into(),
+                format: String::new(),
+            },
+            ..Default::default()
+        };
+        let at = |h: u32, m: u32| {
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_opt(h, m, 0).unwrap().and_utc().timestamp()
+        };
+
+        assert_eq!(settings.delivery_due(at(21, 59)), None); // 06:59 on March 2nd in Tokyo
+        assert_eq!(settings.delivery_due(at(22, 0)), NaiveDate::from_ymd_opt(2024, 3, 2));
+        assert_eq!(settings.local_date(at(21, 59)).ok(), NaiveDate::from_ymd_opt(2024, 3, 2));
+        assert_eq!(UserSettings::default().local_date(at(21, 59)).ok(), NaiveDate::from_ymd_opt(2024, 3, 1));
+
+        let disabled = UserSettings { delivery: DeliveryPreferences::default(), ..settings };
+        assert_eq!(disabled.delivery_due(at(23, 0)), None);
+    }
+
     #[test]
     fn test_knowledge_update_flow() {
         let mut user = UserProfile::new(UserId::from("u1"));
@@ -128,4 +509,22 @@ mod tests {
         assert_eq!(entry_updated.last_interaction, later);
         assert_eq!(entry_updated.interaction_count, 2);
     }
+
+    #[test]
+    fn test_muting_again_replaces_the_rule_and_expired_rules_are_dropped() {
+        use crate::mute::MuteKind;
+
+        let mut user = UserProfile::new(UserId::from("u1"));
+        let week = MuteRule::new(MuteKind::Keyword, "Crypto", Some(7 * 24 * 3600), 0).unwrap();
+        user.add_mute(week, 0).unwrap();
+        user.add_mute(MuteRule::new(MuteKind::Author, "bot", Some(10), 0).unwrap(), 0).unwrap();
+
+        let forever = MuteRule::new(MuteKind::Keyword, "crypto", None, 100).unwrap();
+        user.add_mute(forever.clone(), 100).unwrap();
+        assert_eq!(user.mutes, vec![forever.clone()]);
+
+        assert!(!user.remove_mute(&MuteRuleId::from("missing")));
+        assert!(user.remove_mute(&forever.id));
+        assert!(user.mute_filter(100).is_empty());
+    }
 }
@@ -0,0 +1,508 @@
+// Domain entities for per-user watchlists and the alerts they raise
+use crate::article::{Article, ArticleId};
+use crate::error::DomainError;
+use crate::topic::{normalize_term, word_boundaries};
+use crate::user::UserId;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::iter::Peekable;
+use std::str::{Chars, FromStr};
+use uuid::Uuid;
+
+pub const MAX_EXPRESSION_LEN: usize = 500;
+pub const MAX_WATCH_RULES: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WatchRuleId(String);
+
+impl WatchRuleId {
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+}
+
+impl From<&str> for WatchRuleId {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl fmt::Display for WatchRuleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Which part of an article a term is matched against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchField {
+    Any, // Bare term: title words or tags
+    Title,
+    Tag,
+    Source,
+    Url,
+    Domain,
+    Author,
+}
+
+impl MatchField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchField::Any => "",
+            MatchField::Title => "title",
+            MatchField::Tag => "tag",
+            MatchField::Source => "source",
+            MatchField::Url => "url",
+            MatchField::Domain => "domain",
+            MatchField::Author => "author",
+        }
+    }
+}
+
+impl FromStr for MatchField {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "title" => Ok(MatchField::Title),
+            "tag" => Ok(MatchField::Tag),
+            "source" => Ok(MatchField::Source),
+            "url" => Ok(MatchField::Url),
+            "domain" => Ok(MatchField::Domain),
+            "author" => Ok(MatchField::Author),
+            other => Err(DomainError::Validation(format!("Unknown match field '{}'", other))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchTerm {
+    pub field: MatchField,
+    pub value: String,
+}
+
+impl MatchTerm {
+    pub fn matches(&self, article: &Article) -> bool {
+        let value = normalize_term(&self.value);
+        match self.field {
+            MatchField::Any => contains_phrase(&article.title, &value) || has_tag(article, &value),
+            MatchField::Title => contains_phrase(&article.title, &value),
+            MatchField::Tag => has_tag(article, &value),
+            MatchField::Source => {
+                // "rd" matches every subreddit, "rd-rust" only that one
+                let source = article.source.to_string().to_lowercase();
+                source == value || source.starts_with(&format!("{}-", value))
+            }
+            MatchField::Url => article.url.to_lowercase().contains(&value),
+            MatchField::Domain => {
+                let host = article.domain();
+                host == value || host.ends_with(&format!(".{}", value))
+            }
+            MatchField::Author => normalize_term(&article.author) == value,
+        }
+    }
+}
+
+fn contains_phrase(text: &str, phrase: &str) -> bool {
+    let phrase = word_boundaries(phrase);
+    !phrase.is_empty() && format!(" {} ", word_boundaries(text)).contains(&format!(" {} ", phrase))
+}
+
+fn has_tag(article: &Article, value: &str) -> bool {
+    article.tags.iter().any(|t| normalize_term(t) == value)
+}
+
+/// Boolean match expression, e.g. `tokio OR (domain:sqlite.org AND NOT tag:release) author:dang`.
+/// Operators are upper-case `AND`, `OR`, `NOT` with parentheses; adjacent terms are ANDed.
+/// Stored and serialized as its canonical text form.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum MatchExpr {
+    Term(MatchTerm),
+    Not(Box<MatchExpr>),
+    And(Vec<MatchExpr>),
+    Or(Vec<MatchExpr>),
+}
+
+impl MatchExpr {
+    pub fn matches(&self, article: &Article) -> bool {
+        match self {
+            MatchExpr::Term(term) => term.matches(article),
+            MatchExpr::Not(inner) => !inner.matches(article),
+            MatchExpr::And(all) => all.iter().all(|e| e.matches(article)),
+            MatchExpr::Or(any) => any.iter().any(|e| e.matches(article)),
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut fmt::Formatter<'_>, parenthesize: bool) -> fmt::Result {
+        if parenthesize {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for MatchExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchExpr::Term(term) => {
+                if term.field != MatchField::Any {
+                    write!(f, "{}:", term.field.as_str())?;
+                }
+                let plain = !term.value.is_empty()
+                    && !term.value.contains(|c: char| c.is_whitespace() || "()\":".contains(c))
+                    && !["AND", "OR", "NOT"].contains(&term.value.as_str());
+                if plain {
+                    f.write_str(&term.value)
+                } else {
+                    write!(f, "\"{}\"", term.value)
+                }
+            }
+            MatchExpr::Not(inner) => {
+                f.write_str("NOT ")?;
+                inner.fmt_operand(f, matches!(**inner, MatchExpr::And(_) | MatchExpr::Or(_)))
+            }
+            MatchExpr::And(all) => {
+                for (i, e) in all.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" AND ")?;
+                    }
+                    e.fmt_operand(f, matches!(e, MatchExpr::Or(_)))?;
+                }
+                Ok(())
+            }
+            MatchExpr::Or(any) => {
+                for (i, e) in any.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" OR ")?;
+                    }
+                    e.fmt(f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for MatchExpr {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(DomainError::Validation("Match expression cannot be empty".to_string()));
+        }
+        if s.len() > MAX_EXPRESSION_LEN {
+            return Err(DomainError::Validation(format!(
+                "Match expression is longer than {} characters",
+                MAX_EXPRESSION_LEN
+            )));
+        }
+        let tokens = tokenize(s)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(expr),
+            Some(token) => Err(DomainError::Validation(format!("Unexpected {} in match expression", token))),
+        }
+    }
+}
+
+impl TryFrom<String> for MatchExpr {
+    type Error = DomainError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<MatchExpr> for String {
+    fn from(expr: MatchExpr) -> Self {
+        expr.to_string()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    Term(MatchTerm),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Open => f.write_str("'('"),
+            Token::Close => f.write_str("')'"),
+            Token::And => f.write_str("AND"),
+            Token::Or => f.write_str("OR"),
+            Token::Not => f.write_str("NOT"),
+            Token::Term(term) => write!(f, "'{}'", term.value),
+        }
+    }
+}
+
+fn read_quoted(chars: &mut Peekable<Chars<'_>>) -> Result<String, DomainError> {
+    chars.next(); // Opening quote
+    let mut value = String::new();
+    for c in chars.by_ref() {
+        if c == '"' {
+            return Ok(value);
+        }
+        value.push(c);
+    }
+    Err(DomainError::Validation("Unterminated quote in match expression".to_string()))
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, DomainError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '"' => {
+                let value = read_quoted(&mut chars)?;
+                tokens.push(Token::Term(MatchTerm { field: MatchField::Any, value }));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' || c == '"' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+
+                let token = match word.as_str() {
+                    "AND" => Token::And,
+                    "OR" => Token::Or,
+                    "NOT" => Token::Not,
+                    _ => match word.split_once(':') {
+                        Some((field, value)) => {
+                            let field: MatchField = field.parse()?;
+                            let value = if value.is_empty() && chars.peek() == Some(&'"') {
+                                read_quoted(&mut chars)?
+                            } else {
+                                value.to_string()
+                            };
+                            Token::Term(MatchTerm { field, value })
+                        }
+                        None => Token::Term(MatchTerm { field: MatchField::Any, value: word }),
+                    },
+                };
+                tokens.push(token);
+            }
+        }
+    }
+
+    for token in &tokens {
+        if let Token::Term(term) = token {
+            if term.value.trim().is_empty() {
+                return Err(DomainError::Validation("Empty term in match expression".to_string()));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+// Recursive descent: or := and (OR and)*, and := unary ([AND] unary)*, unary := NOT unary | ( or ) | term
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn parse_or(&mut self) -> Result<MatchExpr, DomainError> {
+        let mut any = vec![self.parse_and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            any.push(self.parse_and()?);
+        }
+        Ok(if any.len() == 1 { any.remove(0) } else { MatchExpr::Or(any) })
+    }
+
+    fn parse_and(&mut self) -> Result<MatchExpr, DomainError> {
+        let mut all = vec![self.parse_unary()?];
+        loop {
+            match self.peek() {
+                Some(Token::And) => {
+                    self.pos += 1;
+                    all.push(self.parse_unary()?);
+                }
+                Some(Token::Not | Token::Open | Token::Term(_)) => all.push(self.parse_unary()?),
+                _ => break,
+            }
+        }
+        Ok(if all.len() == 1 { all.remove(0) } else { MatchExpr::And(all) })
+    }
+
+    fn parse_unary(&mut self) -> Result<MatchExpr, DomainError> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        match token {
+            Some(Token::Not) => Ok(MatchExpr::Not(Box::new(self.parse_unary()?))),
+            Some(Token::Open) => {
+                let inner = self.parse_or()?;
+                if self.peek() != Some(&Token::Close) {
+                    return Err(DomainError::Validation("Missing ')' in match expression".to_string()));
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Some(Token::Term(term)) => Ok(MatchExpr::Term(term)),
+            Some(other) => Err(DomainError::Validation(format!("Unexpected {} in match expression", other))),
+            None => Err(DomainError::Validation("Match expression ends unexpectedly".to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchRule {
+    pub id: WatchRuleId,
+    pub name: String,
+    pub expression: MatchExpr,
+    pub created_at: i64,
+}
+
+impl WatchRule {
+    /// An empty `name` defaults to the expression text.
+    pub fn new(name: &str, expression: &str, now: i64) -> Result<Self, DomainError> {
+        let expression: MatchExpr = expression.parse()?;
+        let name = match name.trim() {
+            "" => expression.to_string(),
+            name => name.to_string(),
+        };
+        Ok(Self {
+            id: WatchRuleId::generate(),
+            name,
+            expression,
+            created_at: now,
+        })
+    }
+
+    pub fn matches(&self, article: &Article) -> bool {
+        self.expression.matches(article)
+    }
+}
+
+/// A watch rule matching an ingested article. Only the first match per rule and article is kept.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchAlert {
+    pub user_id: UserId,
+    pub rule_id: WatchRuleId,
+    pub rule_name: String,
+    pub article_id: ArticleId,
+    pub title: String,
+    pub url: String,
+    pub source: String,
+    pub matched_at: i64,
+}
+
+impl WatchAlert {
+    pub fn new(user_id: UserId, rule: &WatchRule, article: &Article, now: i64) -> Self {
+        Self {
+            user_id,
+            rule_id: rule.id.clone(),
+            rule_name: rule.name.clone(),
+            article_id: article.id.clone(),
+            title: article.title.clone(),
+            url: article.url.clone(),
+            source: article.source.to_string(),
+            matched_at: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::article::Source;
+
+    fn article(source: Source, title: &str, url: &str, author: &str, tags: &[&str]) -> Article {
+        let mut a = Article::new(source, "1", title.into(), url.into(), 0).unwrap();
+        a.author = author.into();
+        a.tags = tags.iter().map(|t| t.to_string()).collect();
+        a
+    }
+
+    fn matches(expression: &str, article: &Article) -> bool {
+        expression.parse::<MatchExpr>().unwrap().matches(article)
+    }
+
+    #[test]
+    fn test_field_matching() {
+        let a = article(
+            Source::Reddit("rust".into()),
+            "Tokio 1.40 released with io_uring support",
+            "https://www.tokio.rs/blog/2024-09-tokio-1-40",
+            "Carllerche",
+            &["async"],
+        );
+
+        assert!(matches("tokio", &a));
+        assert!(matches("\"io uring\"", &a)); // Phrases match across punctuation
+        assert!(!matches("tok", &a)); // Whole words only
+        assert!(matches("async", &a)); // Bare terms also match tags
+        assert!(matches("tag:Async", &a));
+        assert!(!matches("title:async", &a));
+        assert!(matches("source:rd", &a));
+        assert!(matches("source:rd-rust", &a));
+        assert!(!matches("source:hn", &a));
+        assert!(matches("domain:tokio.rs", &a));
+        assert!(!matches("domain:okio.rs", &a));
+        assert!(matches("url:/blog/", &a));
+        assert!(matches("author:carllerche", &a));
+    }
+
+    #[test]
+    fn test_boolean_operators() {
+        let a = article(Source::HackerNews, "SQLite 3.45 released", "https://sqlite.org/releaselog/3_45.html", "dang", &[]);
+
+        assert!(matches("sqlite postgres OR domain:sqlite.org", &a));
+        assert!(!matches("sqlite AND postgres", &a));
+        assert!(matches("sqlite NOT postgres", &a));
+        assert!(matches("(postgres OR sqlite) AND author:dang", &a));
+        assert!(!matches("NOT (sqlite OR postgres)", &a));
+        assert!(matches("title:\"sqlite 3.45\"", &a));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for bad in ["", "   ", "(tokio", "tokio)", "AND tokio", "tokio OR", "NOT", "lang:rust", "\"open", "title:"] {
+            assert!(bad.parse::<MatchExpr>().is_err(), "'{}' should not parse", bad);
+        }
+        assert!("x ".repeat(MAX_EXPRESSION_LEN).parse::<MatchExpr>().is_err());
+    }
+
+    #[test]
+    fn test_canonical_form_roundtrips() {
+        let expr: MatchExpr = "tokio  (domain:sqlite.org OR \"io uring\") NOT author:dang".parse().unwrap();
+        assert_eq!(expr.to_string(), "tokio AND (domain:sqlite.org OR \"io uring\") AND NOT author:dang");
+        assert_eq!(expr.to_string().parse::<MatchExpr>().unwrap(), expr);
+
+        let rule = WatchRule::new("", "tokio OR axum", 0).unwrap();
+        assert_eq!(rule.name, "tokio OR axum");
+        let json = serde_json::to_string(&rule).unwrap();
+        assert!(json.contains("\"expression\":\"tokio OR axum\""));
+        assert_eq!(serde_json::from_str::<WatchRule>(&json).unwrap(), rule);
+    }
+}
@@ -0,0 +1,372 @@
+// Domain entities for outbound webhooks
+use crate::delivery::DeliveryState;
+use crate::error::DomainError;
+use crate::topic::{normalize_term, word_boundaries};
+use crate::trend::TrendReport;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WebhookId(String);
+
+impl WebhookId {
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+}
+
+impl From<&str> for WebhookId {
+    fn from(s: &str) -> Self {
+        Self(s.to_string())
+    }
+}
+
+impl fmt::Display for WebhookId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WebhookEventType {
+    TrendSpike,
+    TopicEmerging,
+    ArticleMatched,
+}
+
+impl WebhookEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEventType::TrendSpike => "trend.spike",
+            WebhookEventType::TopicEmerging => "topic.emerging",
+            WebhookEventType::ArticleMatched => "article.matched",
+        }
+    }
+}
+
+impl fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WebhookEventType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "trend.spike" => Ok(WebhookEventType::TrendSpike),
+            "topic.emerging" => Ok(WebhookEventType::TopicEmerging),
+            "article.matched" => Ok(WebhookEventType::ArticleMatched),
+            other => Err(DomainError::Validation(format!("Unknown webhook event type '{}'", other))),
+        }
+    }
+}
+
+/// Event-specific part of the payload; serialized as `"type": ..., "data": {...}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum WebhookData {
+    #[serde(rename = "trend.spike")]
+    TrendSpike { keyword: String, volume: u32, previous_volume: u32 },
+    #[serde(rename = "topic.emerging")]
+    TopicEmerging { keyword: String, volume: u32 },
+    #[serde(rename = "article.matched")]
+    ArticleMatched {
+        article_id: String,
+        title: String,
+        url: String,
+        source: String,
+        tags: Vec<String>,
+    },
+}
+
+/// Something subscribers may want to hear about. This is exactly the JSON body that gets posted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub id: String,
+    pub occurred_at: i64,
+    #[serde(flatten)]
+    pub data: WebhookData,
+}
+
+impl WebhookEvent {
+    pub fn new(data: WebhookData, occurred_at: i64) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            occurred_at,
+            data,
+        }
+    }
+
+    pub fn event_type(&self) -> WebhookEventType {
+        match self.data {
+            WebhookData::TrendSpike { .. } => WebhookEventType::TrendSpike,
+            WebhookData::TopicEmerging { .. } => WebhookEventType::TopicEmerging,
+            WebhookData::ArticleMatched { .. } => WebhookEventType::ArticleMatched,
+        }
+    }
+
+    /// True if the event is about `term` (already normalized): the trend keyword,
+    /// or a tag or whole word of the article title.
+    pub fn mentions(&self, term: &str) -> bool {
+        match &self.data {
+            WebhookData::TrendSpike { keyword, .. } | WebhookData::TopicEmerging { keyword, .. } => {
+                normalize_term(keyword) == term
+            }
+            WebhookData::ArticleMatched { title, tags, .. } => {
+                tags.iter().any(|t| normalize_term(t) == term)
+                    || format!(" {} ", word_boundaries(title)).contains(&format!(" {} ", word_boundaries(term)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookSubscription {
+    pub id: WebhookId,
+    pub url: String,
+    pub secret: String, // HMAC key; never returned by the API
+    pub event_types: Vec<WebhookEventType>,
+    pub keywords: Vec<String>, // Normalized watch terms; empty means every trend event
+    pub active: bool,
+    pub created_at: i64,
+}
+
+pub const MIN_SECRET_LEN: usize = 16;
+
+impl WebhookSubscription {
+    pub fn new(
+        url: &str,
+        secret: &str,
+        event_types: Vec<WebhookEventType>,
+        keywords: &[String],
+        now: i64,
+    ) -> Result<Self, DomainError> {
+        let url = url.trim();
+        if !(url.starts_with("https://") || url.starts_with("http://")) {
+            return Err(DomainError::Validation(format!("Webhook URL '{}' must be http(s)", url)));
+        }
+        if secret.len() < MIN_SECRET_LEN {
+            return Err(DomainError::Validation(format!(
+                "Webhook secret must be at least {} characters",
+                MIN_SECRET_LEN
+            )));
+        }
+        if event_types.is_empty() {
+            return Err(DomainError::Validation("Subscribe to at least one event type".to_string()));
+        }
+
+        let mut types: Vec<WebhookEventType> = Vec::new();
+        for t in event_types {
+            if !types.contains(&t) {
+                types.push(t);
+            }
+        }
+        let mut terms: Vec<String> = Vec::new();
+        for keyword in keywords {
+            let term = normalize_term(keyword);
+            if !term.is_empty() && !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+        if types.contains(&WebhookEventType::ArticleMatched) && terms.is_empty() {
+            return Err(DomainError::Validation(
+                "article.matched needs at least one keyword".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            id: WebhookId::generate(),
+            url: url.to_string(),
+            secret: secret.to_string(),
+            event_types: types,
+            keywords: terms,
+            active: true,
+            created_at: now,
+        })
+    }
+
+    pub fn wants(&self, event: &WebhookEvent) -> bool {
+        self.active
+            && self.event_types.contains(&event.event_type())
+            && (self.keywords.is_empty() || self.keywords.iter().any(|k| event.mentions(k)))
+    }
+}
+
+/// One event queued for one subscription, with its attempt history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub subscription_id: WebhookId,
+    pub event: WebhookEvent,
+    pub response_status: Option<u16>, // Of the last attempt that got an HTTP response
+    pub created_at: i64,
+    pub state: DeliveryState,
+}
+
+impl WebhookDelivery {
+    pub fn new(subscription_id: WebhookId, event: WebhookEvent, now: i64) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            subscription_id,
+            event,
+            response_status: None,
+            created_at: now,
+            state: DeliveryState::default(),
+        }
+    }
+}
+
+/// When a keyword's volume between two trend reports counts as a spike.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpikePolicy {
+    pub min_volume: u32,
+    pub growth_factor: f64,
+}
+
+impl Default for SpikePolicy {
+    fn default() -> Self {
+        Self {
+            min_volume: 3,
+            growth_factor: 2.0,
+        }
+    }
+}
+
+impl SpikePolicy {
+    /// Spikes (volume grew by `growth_factor`) and emerging keywords (absent from the previous report).
+    pub fn detect(&self, previous: Option<&TrendReport>, current: &TrendReport) -> Vec<WebhookData> {
+        let mut events = Vec::new();
+        for trend in &current.trends {
+            if trend.volume < self.min_volume {
+                continue;
+            }
+            let before = previous
+                .and_then(|r| r.trends.iter().find(|t| t.keyword == trend.keyword))
+                .map(|t| t.volume)
+                .unwrap_or(0);
+            if before == 0 {
+                events.push(WebhookData::TopicEmerging {
+                    keyword: trend.keyword.clone(),
+                    volume: trend.volume,
+                });
+            } else if trend.volume as f64 >= before as f64 * self.growth_factor {
+                events.push(WebhookData::TrendSpike {
+                    keyword: trend.keyword.clone(),
+                    volume: trend.volume,
+                    previous_volume: before,
+                });
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trend::Trend;
+    use std::collections::HashMap;
+
+    fn report(volumes: &[(&str, u32)]) -> TrendReport {
+        TrendReport {
+            timestamp: 0,
+            trends: volumes
+                .iter()
+                .map(|(k, v)| Trend {
+                    keyword: k.to_string(),
+                    score: 0.0,
+                    volume: *v,
+                    velocity: 0.0,
+                    related_articles: vec![],
+                })
+                .collect(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    fn article_event(title: &str, tags: &[&str]) -> WebhookEvent {
+        WebhookEvent::new(
+            WebhookData::ArticleMatched {
+                article_id: "hn-1".into(),
+                title: title.into(),
+                url: "https://example.com".into(),
+                source: "hn".into(),
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+            },
+            0,
+        )
+    }
+
+    #[test]
+    fn test_subscription_validation() {
+        let types = vec![WebhookEventType::TrendSpike, WebhookEventType::TrendSpike];
+        let sub = WebhookSubscription::new("https://chat.example.com/hook", "0123456789abcdef", types, &[" Rust ".into()], 5)
+            .unwrap();
+        assert_eq!(sub.event_types, vec![WebhookEventType::TrendSpike]);
+        assert_eq!(sub.keywords, vec!["rust".to_string()]);
+
+        let secret = "0123456789abcdef";
+        assert!(WebhookSubscription::new("ftp://x", secret, vec![WebhookEventType::TrendSpike], &[], 0).is_err());
+        assert!(WebhookSubscription::new("https://x", "short", vec![WebhookEventType::TrendSpike], &[], 0).is_err());
+        assert!(WebhookSubscription::new("https://x", secret, vec![], &[], 0).is_err());
+        assert!(WebhookSubscription::new("https://x", secret, vec![WebhookEventType::ArticleMatched], &[], 0).is_err());
+    }
+
+    #[test]
+    fn test_subscription_matching() {
+        let secret = "0123456789abcdef";
+        let sub = WebhookSubscription::new(
+            "https://x",
+            secret,
+            vec![WebhookEventType::ArticleMatched, WebhookEventType::TrendSpike],
+            &["rust".into()],
+            0,
+        )
+        .unwrap();
+
+        assert!(sub.wants(&article_event("Rust 2.0 is out", &[])));
+        assert!(sub.wants(&article_event("Compiler news", &["Rust"])));
+        assert!(!sub.wants(&article_event("Trust in open source", &[]))); // Whole words only
+        let emerging = WebhookEvent::new(WebhookData::TopicEmerging { keyword: "rust".into(), volume: 3 }, 0);
+        assert!(!sub.wants(&emerging)); // Not subscribed to this type
+
+        let inactive = WebhookSubscription { active: false, ..sub };
+        assert!(!inactive.wants(&article_event("Rust 2.0 is out", &[])));
+    }
+
+    #[test]
+    fn test_spike_detection() {
+        let policy = SpikePolicy::default();
+        let previous = report(&[("rust", 2), ("go", 4)]);
+        let current = report(&[("rust", 5), ("go", 5), ("zig", 3), ("odin", 1)]);
+
+        let events = policy.detect(Some(&previous), &current);
+        assert_eq!(
+            events,
+            vec![
+                WebhookData::TrendSpike { keyword: "rust".into(), volume: 5, previous_volume: 2 },
+                WebhookData::TopicEmerging { keyword: "zig".into(), volume: 3 },
+            ]
+        );
+        assert_eq!(policy.detect(None, &report(&[("rust", 3)])).len(), 1);
+    }
+
+    #[test]
+    fn test_payload_shape() {
+        let event = WebhookEvent {
+            id: "evt-1".into(),
+            occurred_at: 42,
+            data: WebhookData::TopicEmerging { keyword: "zig".into(), volume: 3 },
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({"id": "evt-1", "occurred_at": 42, "type": "topic.emerging", "data": {"keyword": "zig", "volume": 3}})
+        );
+        assert_eq!(serde_json::from_value::<WebhookEvent>(json).unwrap(), event);
+    }
+}
@@ -0,0 +1,79 @@
+// Article write throughput: one statement per article versus one transaction per batch, against
+// an in-memory database and a file in a temp dir, where commits pay for the disk.
+// Run with `cargo bench -p techpulse-infra --bench article_writes`.
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use tempfile::TempDir;
+use techpulse_domain::article::{Article, Source};
+use techpulse_domain::repository::ArticleRepo;
+use techpulse_infra::repo::db::SqliteArticleRepo;
+use tokio::runtime::Runtime;
+
+fn articles(n: usize) -> Vec<Article> {
+    (0..n)
+        .map(|i| {
+            let mut a = Article::new(
+                Source::HackerNews,
+                &i.to_string(),
+                format!("Story number {} about Rust and SQLite", i),
+                format!("https://example{}.com/post/{}", i % 50, i),
+                1_700_000_000 + i as i64,
+            )
+            .unwrap()
+            .seen_at(1_700_100_000);
+            a.score = (i % 100) as f64;
+            a.tags.insert(format!("tag{}", i % 20));
+            a
+        })
+        .collect()
+}
+
+// The temp dir, if any, must outlive the repo
+async fn fresh_repo(on_disk: bool) -> (SqliteArticleRepo, Option<TempDir>) {
+    let dir = on_disk.then(|| TempDir::new().unwrap());
+    let options = match &dir {
+        Some(dir) => SqliteConnectOptions::new().filename(dir.path().join("bench.db")).create_if_missing(true),
+        None => "sqlite::memory:".parse().unwrap(),
+    };
+    // A single connection, so every query sees the same in-memory database
+    let pool = SqlitePoolOptions::new().max_connections(1).connect_with(options).await.unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+    (SqliteArticleRepo::new(pool), dir)
+}
+
+fn bench_article_writes(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("article_writes");
+    group.sample_size(10);
+
+    for (storage, on_disk) in [("memory", false), ("file", true)] {
+        for n in [1_000, 5_000] {
+            let batch = articles(n);
+            group.throughput(Throughput::Elements(n as u64));
+            group.bench_with_input(BenchmarkId::new(format!("save/{}", storage), n), &batch, |b, batch| {
+                b.iter_batched(
+                    || rt.block_on(fresh_repo(on_disk)),
+                    |(repo, _dir)| {
+                        rt.block_on(async {
+                            for article in batch {
+                                repo.save(article).await.unwrap();
+                            }
+                        })
+                    },
+                    BatchSize::PerIteration,
+                )
+            });
+            group.bench_with_input(BenchmarkId::new(format!("save_many/{}", storage), n), &batch, |b, batch| {
+                b.iter_batched(
+                    || rt.block_on(fresh_repo(on_disk)),
+                    |(repo, _dir)| rt.block_on(repo.save_many(batch)).unwrap(),
+                    BatchSize::PerIteration,
+                )
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_article_writes);
+criterion_main!(benches);
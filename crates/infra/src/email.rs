@@ -0,0 +1,109 @@
+// SMTP delivery for outgoing email
+use async_trait::async_trait;
+use lettre::message::{header::ContentType, Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use std::str::FromStr;
+use std::time::Duration;
+use techpulse_domain::delivery::EmailMessage;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::gateway::EmailGateway;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    StartTls,
+    Plain, // Unencrypted; for local relays and test sinks only
+}
+
+impl FromStr for SmtpSecurity {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "starttls" => Ok(SmtpSecurity::StartTls),
+            "plain" | "none" => Ok(SmtpSecurity::Plain),
+            other => Err(DomainError::Validation(format!("Unknown SMTP security '{}'", other))),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    pub credentials: Option<(String, String)>,
+    pub from: String,
+    pub timeout: Duration,
+}
+
+impl SmtpConfig {
+    pub fn new(host: impl Into<String>, from: impl Into<String>) -> Self {
+        Self {
+            host: host.into(),
+            port: 587,
+            security: SmtpSecurity::StartTls,
+            credentials: None,
+            from: from.into(),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SmtpEmailGateway {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailGateway {
+    pub fn new(config: SmtpConfig) -> Result<Self, DomainError> {
+        let from = config
+            .from
+            .parse::<Mailbox>()
+            .map_err(|e| DomainError::Validation(format!("Invalid sender '{}': {}", config.from, e)))?;
+
+        let builder = match config.security {
+            SmtpSecurity::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+                .map_err(|e| DomainError::Gateway(format!("SMTP TLS setup error: {}", e)))?,
+            SmtpSecurity::Plain => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        };
+        let mut builder = builder.port(config.port).timeout(Some(config.timeout));
+        if let Some((username, password)) = config.credentials {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailGateway for SmtpEmailGateway {
+    async fn send(&self, message: &EmailMessage) -> Result<(), DomainError> {
+        let to = message
+            .to
+            .parse::<Mailbox>()
+            .map_err(|e| DomainError::Validation(format!("Invalid recipient '{}': {}", message.to, e)))?;
+
+        let builder = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(message.subject.clone());
+        let email = match &message.html_body {
+            Some(html) => builder.multipart(MultiPart::alternative_plain_html(message.text_body.clone(), html.clone())),
+            None => builder
+                .header(ContentType::TEXT_PLAIN)
+                .body(message.text_body.clone()),
+        }
+        .map_err(|e| DomainError::Validation(format!("Invalid email: {}", e)))?;
+
+        self.transport
+            .send(email)
+            .await
+            .map_err(|e| DomainError::Gateway(format!("SMTP error: {}", e)))?;
+        Ok(())
+    }
+}
@@ -32,6 +32,17 @@ struct HnItem {
     title: Option<String>,
     url: Option<String>,
     time: Option<i64>,
+    text: Option<String>, // HTML body of Ask/Show HN posts
+    score: Option<u32>,       // Points
+    descendants: Option<u32>, // Total comment count
+}
+
+// Points at which a story reaches the top of the normalized 0-100 scale
+const HN_POINTS_CEILING: f64 = 1000.0;
+
+/// HN points on the 0-100 article scale; logarithmic, as points grow roughly exponentially on the front page.
+fn normalize_points(points: u32) -> f64 {
+    ((points as f64).ln_1p() / HN_POINTS_CEILING.ln_1p() * 100.0).min(100.0)
 }
 
 #[async_trait]
@@ -84,17 +95,66 @@ impl ArticleGateway for HackerNewsGateway {
                     .unwrap_or_else(|| format!("https://news.ycombinator.com/item?id={}", item.id));
                 let timestamp = item.time.unwrap_or(0);
 
-                Article::new(
+                let mut article = Article::new(
                     Source::HackerNews,
                     &item.id.to_string(),
                     title,
                     url,
                     timestamp,
                 )
-                .ok()
+                .ok()?;
+                article.text = item.text.as_deref().map(html_to_text).unwrap_or_default();
+                article.score = normalize_points(item.score.unwrap_or(0));
+                article.comment_count = item.descendants.unwrap_or(0);
+                Some(article)
             })
             .collect();
 
         Ok(articles)
     }
 }
+
+/// Plain text of an HN item body: tags dropped, common entities decoded, whitespace collapsed.
+fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => {
+                in_tag = false;
+                text.push(' ');
+            }
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    let text = text
+        .replace("&quot;", "\"")
+        .replace("&#x27;", "'")
+        .replace("&#39;", "'")
+        .replace("&#x2F;", "/")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text() {
+        let html = "I&#x27;m building a &quot;tiny&quot; DB.<p>See <a href=\"https:&#x2F;&#x2F;x.dev\">x.dev&#x2F;db</a> &amp; enjoy";
+        assert_eq!(html_to_text(html), "I'm building a \"tiny\" DB. See x.dev/db & enjoy");
+    }
+
+    #[test]
+    fn test_normalize_points() {
+        assert_eq!(normalize_points(0), 0.0);
+        assert!((normalize_points(1000) - 100.0).abs() < 1e-9);
+        assert_eq!(normalize_points(50_000), 100.0);
+        assert!(normalize_points(100) > normalize_points(10));
+    }
+}
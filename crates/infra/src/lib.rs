@@ -1,2 +1,4 @@
 pub mod gateway;
 pub mod repo;
+pub mod email;
+pub mod webhook;
@@ -0,0 +1,175 @@
+//! Picks the database behind the repositories at startup, so apps are wired against the traits
+//! rather than one backend's repo types.
+use super::db::{
+    SqliteAlertRepo, SqliteArticleRepo, SqliteBriefingRepo, SqliteCooccurrenceRepo, SqliteDeliveryRepo,
+    SqliteInteractionRepo, SqliteSnapshotRepo, SqliteTimelineRepo, SqliteTopicRepo, SqliteTrendRepo, SqliteUserRepo,
+    SqliteWebhookRepo,
+};
+#[cfg(feature = "postgres")]
+use super::pg::{
+    PgAlertRepo, PgArticleRepo, PgBriefingRepo, PgCooccurrenceRepo, PgDeliveryRepo, PgInteractionRepo,
+    PgSnapshotRepo, PgTimelineRepo, PgTopicRepo, PgTrendRepo, PgUserRepo, PgWebhookRepo,
+};
+#[cfg(feature = "postgres")]
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{
+    AlertRepo, ArticleRepo, BriefingRepo, CooccurrenceRepo, DeliveryRepo, InteractionRepo, SnapshotRepo,
+    TimelineRepo, TopicRepo, TrendRepo, UserRepo, WebhookRepo,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    Sqlite,
+    Postgres,
+}
+
+impl DatabaseBackend {
+    /// The backend a connection URL points at, for when `DATABASE_BACKEND` is not set.
+    pub fn from_url(url: &str) -> Self {
+        if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            DatabaseBackend::Postgres
+        } else {
+            DatabaseBackend::Sqlite
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DatabaseBackend::Sqlite => "sqlite",
+            DatabaseBackend::Postgres => "postgres",
+        }
+    }
+}
+
+impl fmt::Display for DatabaseBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DatabaseBackend {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "sqlite" => Ok(DatabaseBackend::Sqlite),
+            "postgres" | "postgresql" => Ok(DatabaseBackend::Postgres),
+            other => Err(DomainError::Validation(format!(
+                "Unknown database backend '{}', expected sqlite or postgres",
+                other
+            ))),
+        }
+    }
+}
+
+/// Every repository, sharing one connection pool.
+#[derive(Clone)]
+pub struct Repositories {
+    pub articles: Arc<dyn ArticleRepo>,
+    pub snapshots: Arc<dyn SnapshotRepo>,
+    pub trends: Arc<dyn TrendRepo>,
+    pub topics: Arc<dyn TopicRepo>,
+    pub cooccurrence: Arc<dyn CooccurrenceRepo>,
+    pub timeline: Arc<dyn TimelineRepo>,
+    pub users: Arc<dyn UserRepo>,
+    pub interactions: Arc<dyn InteractionRepo>,
+    pub briefings: Arc<dyn BriefingRepo>,
+    pub deliveries: Arc<dyn DeliveryRepo>,
+    pub webhooks: Arc<dyn WebhookRepo>,
+    pub alerts: Arc<dyn AlertRepo>,
+}
+
+impl Repositories {
+    /// Connects to `url` and brings the schema up to date with the backend's migrations.
+    pub async fn connect(backend: DatabaseBackend, url: &str, max_connections: u32) -> Result<Self, DomainError> {
+        match backend {
+            DatabaseBackend::Sqlite => {
+                let pool = SqlitePoolOptions::new()
+                    .max_connections(max_connections)
+                    .connect(url)
+                    .await
+                    .map_err(|e| DomainError::Repository(e.to_string()))?;
+                Self::sqlite(pool).await
+            }
+            #[cfg(feature = "postgres")]
+            DatabaseBackend::Postgres => {
+                let pool = PgPoolOptions::new()
+                    .max_connections(max_connections)
+                    .connect(url)
+                    .await
+                    .map_err(|e| DomainError::Repository(e.to_string()))?;
+                Self::postgres(pool).await
+            }
+            #[cfg(not(feature = "postgres"))]
+            DatabaseBackend::Postgres => Err(DomainError::Validation(
+                "Postgres support is not compiled in, rebuild with the postgres feature".into(),
+            )),
+        }
+    }
+
+    /// Migrates `pool` and builds the SQLite repos on it.
+    pub async fn sqlite(pool: SqlitePool) -> Result<Self, DomainError> {
+        sqlx::migrate!("../../migrations")
+            .run(&pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(Self {
+            articles: Arc::new(SqliteArticleRepo::new(pool.clone())),
+            snapshots: Arc::new(SqliteSnapshotRepo::new(pool.clone())),
+            trends: Arc::new(SqliteTrendRepo::new(pool.clone())),
+            topics: Arc::new(SqliteTopicRepo::new(pool.clone())),
+            cooccurrence: Arc::new(SqliteCooccurrenceRepo::new(pool.clone())),
+            timeline: Arc::new(SqliteTimelineRepo::new(pool.clone())),
+            users: Arc::new(SqliteUserRepo::new(pool.clone())),
+            interactions: Arc::new(SqliteInteractionRepo::new(pool.clone())),
+            briefings: Arc::new(SqliteBriefingRepo::new(pool.clone())),
+            deliveries: Arc::new(SqliteDeliveryRepo::new(pool.clone())),
+            webhooks: Arc::new(SqliteWebhookRepo::new(pool.clone())),
+            alerts: Arc::new(SqliteAlertRepo::new(pool)),
+        })
+    }
+
+    /// Migrates `pool` and builds the Postgres repos on it.
+    #[cfg(feature = "postgres")]
+    pub async fn postgres(pool: PgPool) -> Result<Self, DomainError> {
+        sqlx::migrate!("../../migrations/postgres")
+            .run(&pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(Self {
+            articles: Arc::new(PgArticleRepo::new(pool.clone())),
+            snapshots: Arc::new(PgSnapshotRepo::new(pool.clone())),
+            trends: Arc::new(PgTrendRepo::new(pool.clone())),
+            topics: Arc::new(PgTopicRepo::new(pool.clone())),
+            cooccurrence: Arc::new(PgCooccurrenceRepo::new(pool.clone())),
+            timeline: Arc::new(PgTimelineRepo::new(pool.clone())),
+            users: Arc::new(PgUserRepo::new(pool.clone())),
+            interactions: Arc::new(PgInteractionRepo::new(pool.clone())),
+            briefings: Arc::new(PgBriefingRepo::new(pool.clone())),
+            deliveries: Arc::new(PgDeliveryRepo::new(pool.clone())),
+            webhooks: Arc::new(PgWebhookRepo::new(pool.clone())),
+            alerts: Arc::new(PgAlertRepo::new(pool)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_from_config() {
+        assert_eq!(" Postgres ".parse::<DatabaseBackend>().unwrap(), DatabaseBackend::Postgres);
+        assert_eq!("sqlite".parse::<DatabaseBackend>().unwrap(), DatabaseBackend::Sqlite);
+        assert!("mysql".parse::<DatabaseBackend>().is_err());
+        assert_eq!(DatabaseBackend::from_url("postgresql://localhost/techpulse"), DatabaseBackend::Postgres);
+        assert_eq!(DatabaseBackend::from_url("sqlite://techpulse.db"), DatabaseBackend::Sqlite);
+    }
+}
@@ -1,9 +1,27 @@
 use async_trait::async_trait;
-use sqlx::{Pool, Sqlite, Row};
-use techpulse_domain::article::{Article, ArticleId, Source};
+use chrono::NaiveDate;
+use sqlx::query::Query;
+use sqlx::sqlite::{SqliteArguments, SqliteConnection};
+use sqlx::{Pool, QueryBuilder, Sqlite, Row};
+use techpulse_domain::article::{Article, ArticleId, ArticleSnapshot, SaveOutcome, Source};
+use techpulse_domain::briefing::Briefing;
+use techpulse_domain::delivery::{BriefingDelivery, DeliveryState, DeliveryStatus};
+use techpulse_domain::cooccurrence::{CooccurrenceEdge, CooccurrenceGraph};
 use techpulse_domain::error::DomainError;
-use techpulse_domain::repository::{ArticleRepo, TrendRepo};
-use techpulse_domain::trend::{TrendReport, Trend};
+use techpulse_domain::interaction::{InteractionEvent, InteractionKind};
+use techpulse_domain::query::{ArticlePage, ArticleQuery};
+use techpulse_domain::search::{highlight, SearchHit, SearchQuery, COLUMN_WEIGHTS, MATCH_END, MATCH_START, SNIPPET_ELLIPSIS, SNIPPET_TOKENS};
+use techpulse_domain::repository::{
+    ArticleRepo, BriefingRepo, CooccurrenceRepo, DeliveryRepo, InteractionRepo, TimelineRepo, TopicRepo,
+    TrendRepo, UserRepo, WebhookRepo, AlertRepo, SnapshotRepo,
+};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::{Topic, TopicCategory, TopicSlug};
+use techpulse_domain::trend::{TimelineEvent, TimelineEventId, TrendReport, Trend};
+use techpulse_domain::mute::MuteRule;
+use techpulse_domain::watchlist::{WatchAlert, WatchRule, WatchRuleId};
+use techpulse_domain::webhook::{WebhookDelivery, WebhookEvent, WebhookEventType, WebhookId, WebhookSubscription};
+use techpulse_domain::user::{KnowledgeMap, KnowledgeState, TopicKnowledge, UserId, UserProfile, UserSettings};
 use std::collections::{HashSet, HashMap};
 
 #[derive(Debug, Clone)]
@@ -19,31 +37,22 @@ impl SqliteArticleRepo {
 
 #[async_trait]
 impl ArticleRepo for SqliteArticleRepo {
-    async fn save(&self, article: &Article) -> Result<(), DomainError> {
-        let tags_json = serde_json::to_string(&article.tags)
-            .map_err(|e| DomainError::Repository(e.to_string()))?;
+    async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError> {
+        // A batch of one, so telling an insert from an update happens in one transaction
+        Ok(self.save_many(std::slice::from_ref(article)).await?.remove(0))
+    }
 
-        sqlx::query(
-            r#"
-            INSERT OR REPLACE INTO articles (id, title, url, source, score, author, timestamp, tags, comment_count, is_hot_on_source)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-            "#,
-        )
-        .bind(article.id.to_string())
-        .bind(&article.title)
-        .bind(&article.url)
-        .bind(article.source.to_string())
-        .bind(article.score)
-        .bind(&article.author)
-        .bind(article.timestamp)
-        .bind(tags_json)
-        .bind(article.comment_count as i64)
-        .bind(article.is_hot_on_source)
-        .execute(&self.pool)
-        .await
-        .map_err(|e| DomainError::Repository(e.to_string()))?;
+    async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError> {
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
 
-        Ok(())
+        let mut outcomes = Vec::with_capacity(articles.len());
+        for article in articles {
+            // Dropping the transaction on error rolls back the articles already written
+            outcomes.push(upsert_article(&mut tx, article).await?);
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(outcomes)
     }
 
     async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError> {
@@ -61,7 +70,7 @@ impl ArticleRepo for SqliteArticleRepo {
     }
 
     async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError> {
-        let rows = sqlx::query("SELECT * FROM articles ORDER BY timestamp DESC LIMIT ?")
+        let rows = sqlx::query("SELECT * FROM articles ORDER BY timestamp DESC, id DESC LIMIT ?")
             .bind(limit as i64)
             .fetch_all(&self.pool)
             .await
@@ -73,21 +82,206 @@ impl ArticleRepo for SqliteArticleRepo {
         }
         Ok(articles)
     }
+
+    async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError> {
+        let mut sql = QueryBuilder::<Sqlite>::new("SELECT * FROM articles WHERE 1 = 1");
+        if !query.sources.is_empty() {
+            sql.push(" AND (");
+            for (i, source) in query.sources.iter().enumerate() {
+                if i > 0 {
+                    sql.push(" OR ");
+                }
+                sql.push("lower(source) = ").push_bind(source.clone());
+                sql.push(" OR lower(source) LIKE ")
+                    .push_bind(format!("{}-%", escape_like(source)))
+                    .push(" ESCAPE '\\'");
+            }
+            sql.push(")");
+        }
+        for tag in &query.tags {
+            sql.push(" AND EXISTS (SELECT 1 FROM json_each(articles.tags) WHERE lower(json_each.value) = ")
+                .push_bind(tag.clone())
+                .push(")");
+        }
+        if let Some(since) = query.since {
+            sql.push(" AND timestamp >= ").push_bind(since);
+        }
+        if let Some(until) = query.until {
+            sql.push(" AND timestamp < ").push_bind(until);
+        }
+        if let Some(min_score) = query.min_score {
+            sql.push(" AND score >= ").push_bind(min_score);
+        }
+        if let Some(domain) = &query.domain {
+            sql.push(" AND (domain = ").push_bind(domain.clone());
+            sql.push(" OR domain LIKE ")
+                .push_bind(format!("%.{}", escape_like(domain)))
+                .push(" ESCAPE '\\')");
+        }
+        if let Some(text) = &query.text {
+            sql.push(" AND instr(lower(title), ").push_bind(text.clone()).push(") > 0");
+        }
+        if let Some(cursor) = &query.cursor {
+            sql.push(" AND (timestamp < ").push_bind(cursor.timestamp);
+            sql.push(" OR (timestamp = ").push_bind(cursor.timestamp);
+            sql.push(" AND id < ").push_bind(cursor.id.to_string()).push("))");
+        }
+        sql.push(" ORDER BY timestamp DESC, id DESC LIMIT ")
+            .push_bind(query.limit as i64 + 1);
+
+        let rows = sql
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut articles = Vec::new();
+        for row in rows {
+            articles.push(map_row_to_article(&row)?);
+        }
+        Ok(ArticlePage::from_overfetch(articles, query.limit))
+    }
+
+    async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError> {
+        let weights = COLUMN_WEIGHTS.map(|w| w.to_string()).join(", ");
+        let sql = format!(
+            r#"
+            SELECT articles.*,
+                -bm25(articles_fts, {weights}) AS relevance,
+                snippet(articles_fts, -1, ?, ?, ?, ?) AS snippet
+            FROM articles_fts JOIN articles ON articles.rowid = articles_fts.rowid
+            WHERE articles_fts MATCH ?
+            ORDER BY relevance DESC, articles.timestamp DESC
+            LIMIT ?
+            "#
+        );
+        let rows = sqlx::query(&sql)
+            .bind(MATCH_START.to_string())
+            .bind(MATCH_END.to_string())
+            .bind(SNIPPET_ELLIPSIS)
+            .bind(SNIPPET_TOKENS as i64)
+            .bind(query.to_fts5())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut hits = Vec::new();
+        for row in rows {
+            hits.push(SearchHit {
+                article: map_row_to_article(&row)?,
+                relevance: row.try_get("relevance").unwrap_or_default(),
+                snippet: highlight(&row.try_get::<String, _>("snippet").unwrap_or_default()),
+            });
+        }
+        Ok(hits)
+    }
+
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError> {
+        // The delete trigger drops pruned rows from the search index
+        let result = sqlx::query(
+            "DELETE FROM articles WHERE max(timestamp, last_seen_at) < ? AND id NOT IN (SELECT value FROM json_each(?))",
+        )
+        .bind(before)
+        .bind(id_list_json(keep)?)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
 }
 
-fn map_row_to_article(row: &sqlx::sqlite::SqliteRow) -> Result<Article, DomainError> {
-    let source_str: String = row.try_get("source")
-        .map_err(|e| DomainError::Repository(format!("Missing source: {}", e)))?;
-        
-    let source = match source_str.as_str() {
+pub(super) fn escape_like(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+/// A JSON array of IDs, bound as one parameter and expanded with `json_each` however many there are.
+fn id_list_json(ids: &HashSet<ArticleId>) -> Result<String, DomainError> {
+    let ids: Vec<String> = ids.iter().map(ArticleId::to_string).collect();
+    serde_json::to_string(&ids).map_err(|e| DomainError::Repository(e.to_string()))
+}
+
+// Inserting first tells a new article from a known one: that statement only writes if the row is
+// missing. Reading first instead would take a read lock that SQLite cannot always upgrade to a write.
+async fn upsert_article(conn: &mut SqliteConnection, article: &Article) -> Result<SaveOutcome, DomainError> {
+    let inserted = bind_article(sqlx::query(&format!("{} ON CONFLICT(id) DO NOTHING", INSERT_ARTICLE)), article)?
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?
+        .rows_affected()
+        == 1;
+    if inserted {
+        return Ok(SaveOutcome::Inserted);
+    }
+
+    // An upsert rather than INSERT OR REPLACE: replacing would delete the row without firing
+    // the delete trigger that keeps the search index in sync. The update mirrors Article::merged_with.
+    let merge = format!(
+        r#"
+        {} ON CONFLICT(id) DO UPDATE SET
+            score = excluded.score,
+            tags = CASE WHEN coalesce(articles.tags, '[]') IN ('', '[]') THEN excluded.tags ELSE articles.tags END,
+            comment_count = excluded.comment_count,
+            is_hot_on_source = excluded.is_hot_on_source,
+            text = CASE WHEN articles.text = '' THEN excluded.text ELSE articles.text END,
+            last_seen_at = max(articles.last_seen_at, excluded.last_seen_at),
+            times_seen = max(articles.times_seen, 1) + 1
+        "#,
+        INSERT_ARTICLE
+    );
+    bind_article(sqlx::query(&merge), article)?
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+    Ok(SaveOutcome::Updated)
+}
+
+const INSERT_ARTICLE: &str = r#"
+    INSERT INTO articles (id, title, url, domain, source, score, author, timestamp, tags, comment_count, is_hot_on_source, text, first_seen_at, last_seen_at, times_seen)
+    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, max(?, 1))
+"#;
+
+fn bind_article<'q>(
+    query: Query<'q, Sqlite, SqliteArguments<'q>>,
+    article: &'q Article,
+) -> Result<Query<'q, Sqlite, SqliteArguments<'q>>, DomainError> {
+    let tags_json = serde_json::to_string(&article.tags)
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+    Ok(query
+        .bind(article.id.to_string())
+        .bind(&article.title)
+        .bind(&article.url)
+        .bind(article.domain())
+        .bind(article.source.to_string())
+        .bind(article.score)
+        .bind(&article.author)
+        .bind(article.timestamp)
+        .bind(tags_json)
+        .bind(article.comment_count as i64)
+        .bind(article.is_hot_on_source)
+        .bind(&article.text)
+        .bind(article.first_seen_at)
+        .bind(article.last_seen_at)
+        .bind(article.times_seen as i64))
+}
+
+/// Inverse of `Source`'s `Display`, shared with the Postgres repos.
+pub(super) fn parse_source(source: String) -> Source {
+    match source.as_str() {
         "hn" => Source::HackerNews,
         "gh" => Source::GitHub,
         "ph" => Source::ProductHunt,
         "arxiv" => Source::ArXiv,
         s if s.starts_with("rd-") => Source::Reddit(s[3..].to_string()),
-        _ => Source::Custom(source_str),
-    };
-    
+        _ => Source::Custom(source),
+    }
+}
+
+fn map_row_to_article(row: &sqlx::sqlite::SqliteRow) -> Result<Article, DomainError> {
+    let source: String = row.try_get("source")
+        .map_err(|e| DomainError::Repository(format!("Missing source: {}", e)))?;
+
     let tags_str: String = row.try_get("tags").unwrap_or_else(|_| "[]".to_string());
     let tags: HashSet<String> = serde_json::from_str(&tags_str)
         .unwrap_or_default();
@@ -99,13 +293,92 @@ fn map_row_to_article(row: &sqlx::sqlite::SqliteRow) -> Result<Article, DomainEr
         id: ArticleId::from_persisted(id_str),
         title: row.try_get("title").map_err(|e| DomainError::Repository(format!("Missing title: {}", e)))?,
         url: row.try_get("url").unwrap_or_default(), // Can be empty per migration default
-        source,
+        source: parse_source(source),
         score: row.try_get("score").unwrap_or_default(),
         author: row.try_get("author").unwrap_or_default(),
         timestamp: row.try_get("timestamp").map_err(|e| DomainError::Repository(format!("Missing timestamp: {}", e)))?,
         tags,
         comment_count: row.try_get::<i64, _>("comment_count").unwrap_or_default() as u32,
         is_hot_on_source: row.try_get("is_hot_on_source").unwrap_or_default(),
+        text: row.try_get("text").unwrap_or_default(),
+        first_seen_at: row.try_get("first_seen_at").unwrap_or_default(),
+        last_seen_at: row.try_get("last_seen_at").unwrap_or_default(),
+        times_seen: row.try_get::<i64, _>("times_seen").unwrap_or_default() as u32,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteSnapshotRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteSnapshotRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SnapshotRepo for SqliteSnapshotRepo {
+    async fn append(&self, snapshots: &[ArticleSnapshot]) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        for snapshot in snapshots {
+            sqlx::query(
+                r#"
+                INSERT OR REPLACE INTO article_snapshots (article_id, captured_at, score, comment_count, is_hot_on_source)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(snapshot.article_id.to_string())
+            .bind(snapshot.captured_at)
+            .bind(snapshot.score)
+            .bind(snapshot.comment_count as i64)
+            .bind(snapshot.is_hot_on_source)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn list_for_article(&self, article_id: &ArticleId) -> Result<Vec<ArticleSnapshot>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM article_snapshots WHERE article_id = ? ORDER BY captured_at ASC")
+            .bind(article_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_snapshot).collect()
+    }
+
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError> {
+        let result = sqlx::query(
+            "DELETE FROM article_snapshots WHERE captured_at < ? AND article_id NOT IN (SELECT value FROM json_each(?))",
+        )
+        .bind(before)
+        .bind(id_list_json(keep)?)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+fn map_row_to_snapshot(row: &sqlx::sqlite::SqliteRow) -> Result<ArticleSnapshot, DomainError> {
+    let article_id: String = row.try_get("article_id")
+        .map_err(|e| DomainError::Repository(format!("Missing article_id: {}", e)))?;
+
+    Ok(ArticleSnapshot {
+        article_id: ArticleId::from_persisted(article_id),
+        captured_at: row.try_get("captured_at")
+            .map_err(|e| DomainError::Repository(format!("Missing captured_at: {}", e)))?,
+        score: row.try_get("score").unwrap_or_default(),
+        comment_count: row.try_get::<i64, _>("comment_count").unwrap_or_default() as u32,
+        is_hot_on_source: row.try_get("is_hot_on_source").unwrap_or_default(),
     })
 }
 
@@ -146,8 +419,8 @@ impl TrendRepo for SqliteTrendRepo {
     }
 
     async fn find_latest_report(&self) -> Result<Option<TrendReport>, DomainError> {
-        // Use timestamp index to find latest
-        let row = sqlx::query("SELECT * FROM trends ORDER BY timestamp DESC LIMIT 1")
+        // Use timestamp index to find latest; a rerun at the same timestamp supersedes the earlier report
+        let row = sqlx::query("SELECT * FROM trends ORDER BY timestamp DESC, id DESC LIMIT 1")
             .fetch_optional(&self.pool)
             .await
             .map_err(|e| DomainError::Repository(e.to_string()))?;
@@ -167,4 +440,871 @@ impl TrendRepo for SqliteTrendRepo {
             Ok(None)
         }
     }
+
+    async fn prune(&self, before: i64) -> Result<usize, DomainError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM trends
+            WHERE timestamp < ? AND id <> (SELECT id FROM trends ORDER BY timestamp DESC, id DESC LIMIT 1)
+            "#,
+        )
+        .bind(before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteTopicRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteTopicRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TopicRepo for SqliteTopicRepo {
+    async fn save(&self, topic: &Topic) -> Result<(), DomainError> {
+        let aliases_json = serde_json::to_string(&topic.aliases)
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO topics (slug, display_name, category, aliases)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(topic.slug.as_str())
+        .bind(&topic.display_name)
+        .bind(topic.category.as_str())
+        .bind(aliases_json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError> {
+        let row = sqlx::query("SELECT * FROM topics WHERE slug = ?")
+            .bind(slug.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_topic(&r)).transpose()
+    }
+
+    async fn list_all(&self) -> Result<Vec<Topic>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM topics ORDER BY slug ASC")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_topic).collect()
+    }
+}
+
+fn map_row_to_topic(row: &sqlx::sqlite::SqliteRow) -> Result<Topic, DomainError> {
+    let slug: String = row.try_get("slug")
+        .map_err(|e| DomainError::Repository(format!("Missing slug: {}", e)))?;
+    let category_str: String = row.try_get("category").unwrap_or_default();
+    let aliases_str: String = row.try_get("aliases").unwrap_or_else(|_| "[]".to_string());
+
+    Ok(Topic {
+        slug: TopicSlug::from_persisted(slug),
+        display_name: row.try_get("display_name").map_err(|e| DomainError::Repository(format!("Missing display_name: {}", e)))?,
+        aliases: serde_json::from_str(&aliases_str).unwrap_or_default(),
+        // Unknown categories (e.g. from a newer schema) fall back to Other
+        category: category_str.parse::<TopicCategory>().unwrap_or_default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteCooccurrenceRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteCooccurrenceRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CooccurrenceRepo for SqliteCooccurrenceRepo {
+    async fn save_graph(&self, graph: &CooccurrenceGraph) -> Result<(), DomainError> {
+        let topic_counts = serde_json::to_string(&graph.topic_counts)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let edges = serde_json::to_string(&graph.edges)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        // One graph per period: rebuilding a window replaces the previous row
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO cooccurrence_graphs (period_start, period_end, document_count, topic_counts, edges)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(graph.window.start)
+        .bind(graph.window.end)
+        .bind(graph.document_count as i64)
+        .bind(topic_counts)
+        .bind(edges)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_graph(&self, window: &TimeWindow) -> Result<Option<CooccurrenceGraph>, DomainError> {
+        let row = sqlx::query("SELECT * FROM cooccurrence_graphs WHERE period_start = ? AND period_end = ?")
+            .bind(window.start)
+            .bind(window.end)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_graph(&r)).transpose()
+    }
+
+    async fn find_latest_graph(&self) -> Result<Option<CooccurrenceGraph>, DomainError> {
+        let row = sqlx::query("SELECT * FROM cooccurrence_graphs ORDER BY period_end DESC, period_start DESC LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_graph(&r)).transpose()
+    }
+}
+
+fn map_row_to_graph(row: &sqlx::sqlite::SqliteRow) -> Result<CooccurrenceGraph, DomainError> {
+    let start: i64 = row.try_get("period_start").map_err(|e| DomainError::Repository(format!("Missing period_start: {}", e)))?;
+    let end: i64 = row.try_get("period_end").map_err(|e| DomainError::Repository(format!("Missing period_end: {}", e)))?;
+    let topic_counts_str: String = row.try_get("topic_counts").unwrap_or_else(|_| "{}".to_string());
+    let edges_str: String = row.try_get("edges").map_err(|e| DomainError::Repository(format!("Missing edges: {}", e)))?;
+    let edges: Vec<CooccurrenceEdge> = serde_json::from_str(&edges_str)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+    Ok(CooccurrenceGraph {
+        window: TimeWindow { start, end },
+        document_count: row.try_get::<i64, _>("document_count").unwrap_or_default() as u32,
+        topic_counts: serde_json::from_str(&topic_counts_str).unwrap_or_default(),
+        edges,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteTimelineRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteTimelineRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TimelineRepo for SqliteTimelineRepo {
+    async fn save_event(&self, event: &TimelineEvent) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO timeline_events (id, title, date, description, category, importance_score, url, article_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(event.id.to_string())
+        .bind(&event.title)
+        .bind(event.date.to_string())
+        .bind(&event.description)
+        .bind(&event.category)
+        .bind(event.importance_score)
+        .bind(&event.url)
+        .bind(event.article_id.as_ref().map(|id| id.to_string()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_event(&self, id: &TimelineEventId) -> Result<Option<TimelineEvent>, DomainError> {
+        let row = sqlx::query("SELECT * FROM timeline_events WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_timeline_event(&r)).transpose()
+    }
+
+    async fn list_events(&self) -> Result<Vec<TimelineEvent>, DomainError> {
+        // ISO dates sort lexicographically; newest first like the in-memory repo
+        let rows = sqlx::query("SELECT * FROM timeline_events ORDER BY date DESC, id ASC")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_timeline_event).collect()
+    }
+
+    async fn delete_event(&self, id: &TimelineEventId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM timeline_events WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+fn map_row_to_timeline_event(row: &sqlx::sqlite::SqliteRow) -> Result<TimelineEvent, DomainError> {
+    let id: String = row.try_get("id")
+        .map_err(|e| DomainError::Repository(format!("Missing id: {}", e)))?;
+    let date_str: String = row.try_get("date")
+        .map_err(|e| DomainError::Repository(format!("Missing date: {}", e)))?;
+    let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+        .map_err(|e| DomainError::Repository(format!("Invalid date '{}': {}", date_str, e)))?;
+
+    Ok(TimelineEvent {
+        id: TimelineEventId::from(id.as_str()),
+        title: row.try_get("title").map_err(|e| DomainError::Repository(format!("Missing title: {}", e)))?,
+        date,
+        description: row.try_get("description").unwrap_or_default(),
+        category: row.try_get("category").unwrap_or_default(),
+        importance_score: row.try_get("importance_score").unwrap_or_default(),
+        url: row.try_get("url").unwrap_or_default(),
+        article_id: row
+            .try_get::<Option<String>, _>("article_id")
+            .unwrap_or_default()
+            .map(ArticleId::from_persisted),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteUserRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteUserRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserRepo for SqliteUserRepo {
+    async fn save(&self, user: &UserProfile) -> Result<(), DomainError> {
+        let settings = serde_json::to_string(&user.settings)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let watchlist = serde_json::to_string(&user.watchlist)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let mutes = serde_json::to_string(&user.mutes)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        // Profile and knowledge map are written together so readers never see a partial map
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO users (id, settings, watchlist, mutes) VALUES (?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                settings = excluded.settings, watchlist = excluded.watchlist, mutes = excluded.mutes
+            "#,
+        )
+        .bind(user.id.to_string())
+        .bind(settings)
+        .bind(watchlist)
+        .bind(mutes)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        sqlx::query("DELETE FROM user_topic_knowledge WHERE user_id = ?")
+            .bind(user.id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        for knowledge in user.knowledge.topics.values() {
+            sqlx::query(
+                r#"
+                INSERT INTO user_topic_knowledge (user_id, topic, state, first_seen, last_interaction, interaction_count)
+                VALUES (?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(user.id.to_string())
+            .bind(&knowledge.topic)
+            .bind(knowledge.state.as_str())
+            .bind(knowledge.first_seen)
+            .bind(knowledge.last_interaction)
+            .bind(knowledge.interaction_count as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError> {
+        let row = sqlx::query("SELECT * FROM users WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let settings_str: String = row.try_get("settings").unwrap_or_else(|_| "{}".to_string());
+        let settings: UserSettings = serde_json::from_str(&settings_str)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+        let topic_rows = sqlx::query("SELECT * FROM user_topic_knowledge WHERE user_id = ?")
+            .bind(id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut knowledge = KnowledgeMap::default();
+        for topic_row in &topic_rows {
+            let entry = map_row_to_topic_knowledge(topic_row)?;
+            knowledge.topics.insert(entry.topic.clone(), entry);
+        }
+
+        let watchlist_str: String = row.try_get("watchlist").unwrap_or_else(|_| "[]".to_string());
+        let watchlist: Vec<WatchRule> = serde_json::from_str(&watchlist_str)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+        let mutes_str: String = row.try_get("mutes").unwrap_or_else(|_| "[]".to_string());
+        let mutes: Vec<MuteRule> = serde_json::from_str(&mutes_str)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+        Ok(Some(UserProfile {
+            id: id.clone(),
+            knowledge,
+            settings,
+            watchlist,
+            mutes,
+        }))
+    }
+
+    async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError> {
+        let ids: Vec<String> = sqlx::query_scalar("SELECT id FROM users ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut users = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(user) = self.find_by_id(&UserId::new(id)).await? {
+                users.push(user);
+            }
+        }
+        Ok(users)
+    }
+}
+
+fn map_row_to_topic_knowledge(row: &sqlx::sqlite::SqliteRow) -> Result<TopicKnowledge, DomainError> {
+    let state_str: String = row.try_get("state").unwrap_or_default();
+
+    Ok(TopicKnowledge {
+        topic: row.try_get("topic").map_err(|e| DomainError::Repository(format!("Missing topic: {}", e)))?,
+        state: state_str.parse::<KnowledgeState>().unwrap_or_default(),
+        first_seen: row.try_get("first_seen").unwrap_or_default(),
+        last_interaction: row.try_get("last_interaction").unwrap_or_default(),
+        interaction_count: row.try_get::<i64, _>("interaction_count").unwrap_or_default() as u32,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteInteractionRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteInteractionRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InteractionRepo for SqliteInteractionRepo {
+    async fn append(&self, events: &[InteractionEvent]) -> Result<(), DomainError> {
+        // A batch is stored all-or-nothing
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        for event in events {
+            sqlx::query(
+                r#"
+                INSERT INTO interactions (user_id, article_id, kind, dwell_secs, timestamp)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(event.user_id.to_string())
+            .bind(event.article_id.to_string())
+            .bind(event.kind.as_str())
+            .bind(event.kind.dwell_secs().map(|s| s as i64))
+            .bind(event.timestamp)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, since: i64) -> Result<Vec<InteractionEvent>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT * FROM interactions WHERE user_id = ? AND timestamp >= ? ORDER BY timestamp ASC, id ASC",
+        )
+        .bind(user_id.to_string())
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_interaction).collect()
+    }
+
+    async fn bookmarked_articles(&self) -> Result<HashSet<ArticleId>, DomainError> {
+        let ids: Vec<String> = sqlx::query_scalar("SELECT DISTINCT article_id FROM interactions WHERE kind = ?")
+            .bind(InteractionKind::WantToLearn.as_str())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(ids.into_iter().map(ArticleId::from_persisted).collect())
+    }
+}
+
+fn map_row_to_interaction(row: &sqlx::sqlite::SqliteRow) -> Result<InteractionEvent, DomainError> {
+    let kind: String = row.try_get("kind")
+        .map_err(|e| DomainError::Repository(format!("Missing kind: {}", e)))?;
+    let dwell_secs: Option<i64> = row.try_get("dwell_secs").unwrap_or_default();
+    let kind = InteractionKind::from_parts(&kind, dwell_secs.map(|s| s as u32))
+        .map_err(|e| DomainError::Repository(format!("Invalid interaction: {}", e)))?;
+    let user_id: String = row.try_get("user_id")
+        .map_err(|e| DomainError::Repository(format!("Missing user_id: {}", e)))?;
+    let article_id: String = row.try_get("article_id")
+        .map_err(|e| DomainError::Repository(format!("Missing article_id: {}", e)))?;
+
+    Ok(InteractionEvent {
+        user_id: UserId::new(user_id),
+        article_id: ArticleId::from_persisted(article_id),
+        kind,
+        timestamp: row.try_get("timestamp").unwrap_or_default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteBriefingRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteBriefingRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BriefingRepo for SqliteBriefingRepo {
+    async fn save(&self, briefing: &Briefing) -> Result<(), DomainError> {
+        // The whole briefing is a snapshot, so it is stored as one JSON document
+        let content = serde_json::to_string(briefing)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO briefings (user_id, date, generated_at, content)
+            VALUES (?, ?, ?, ?)
+            "#,
+        )
+        .bind(briefing.user_id.to_string())
+        .bind(briefing.date.to_string())
+        .bind(briefing.generated_at)
+        .bind(content)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<Briefing>, DomainError> {
+        let row = sqlx::query("SELECT content FROM briefings WHERE user_id = ? AND date = ?")
+            .bind(user_id.to_string())
+            .bind(date.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_briefing(&r)).transpose()
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<Briefing>, DomainError> {
+        let rows = sqlx::query("SELECT content FROM briefings WHERE user_id = ? ORDER BY date DESC LIMIT ?")
+            .bind(user_id.to_string())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_briefing).collect()
+    }
+}
+
+fn map_row_to_briefing(row: &sqlx::sqlite::SqliteRow) -> Result<Briefing, DomainError> {
+    let content: String = row.try_get("content")
+        .map_err(|e| DomainError::Repository(format!("Missing content: {}", e)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteDeliveryRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteDeliveryRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DeliveryRepo for SqliteDeliveryRepo {
+    async fn save(&self, delivery: &BriefingDelivery) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO briefing_deliveries
+                (user_id, date, email, status, attempts, last_error, last_attempt_at, next_attempt_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(delivery.user_id.to_string())
+        .bind(delivery.date.to_string())
+        .bind(&delivery.email)
+        .bind(delivery.state.status.as_str())
+        .bind(delivery.state.attempts as i64)
+        .bind(&delivery.state.last_error)
+        .bind(delivery.state.last_attempt_at)
+        .bind(delivery.state.next_attempt_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<BriefingDelivery>, DomainError> {
+        let row = sqlx::query("SELECT * FROM briefing_deliveries WHERE user_id = ? AND date = ?")
+            .bind(user_id.to_string())
+            .bind(date.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_delivery(&r)).transpose()
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<BriefingDelivery>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM briefing_deliveries WHERE user_id = ? ORDER BY date DESC LIMIT ?")
+            .bind(user_id.to_string())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_delivery).collect()
+    }
+}
+
+fn map_row_to_delivery(row: &sqlx::sqlite::SqliteRow) -> Result<BriefingDelivery, DomainError> {
+    let user_id: String = row.try_get("user_id")
+        .map_err(|e| DomainError::Repository(format!("Missing user_id: {}", e)))?;
+    let date: String = row.try_get("date")
+        .map_err(|e| DomainError::Repository(format!("Missing date: {}", e)))?;
+
+    Ok(BriefingDelivery {
+        user_id: UserId::new(user_id),
+        date: date
+            .parse::<NaiveDate>()
+            .map_err(|e| DomainError::Repository(format!("Invalid date '{}': {}", date, e)))?,
+        email: row.try_get("email").unwrap_or_default(),
+        state: map_row_to_delivery_state(row)?,
+    })
+}
+
+fn map_row_to_delivery_state(row: &sqlx::sqlite::SqliteRow) -> Result<DeliveryState, DomainError> {
+    let status: String = row.try_get("status")
+        .map_err(|e| DomainError::Repository(format!("Missing status: {}", e)))?;
+
+    Ok(DeliveryState {
+        status: status
+            .parse::<DeliveryStatus>()
+            .map_err(|e| DomainError::Repository(e.to_string()))?,
+        attempts: row.try_get::<i64, _>("attempts").unwrap_or_default() as u32,
+        last_error: row.try_get("last_error").unwrap_or_default(),
+        last_attempt_at: row.try_get("last_attempt_at").unwrap_or_default(),
+        next_attempt_at: row.try_get("next_attempt_at").unwrap_or_default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteWebhookRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteWebhookRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepo for SqliteWebhookRepo {
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), DomainError> {
+        let event_types: Vec<&str> = subscription.event_types.iter().map(|t| t.as_str()).collect();
+        let event_types = serde_json::to_string(&event_types)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let keywords = serde_json::to_string(&subscription.keywords)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO webhook_subscriptions (id, url, secret, event_types, keywords, active, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(subscription.id.to_string())
+        .bind(&subscription.url)
+        .bind(&subscription.secret)
+        .bind(event_types)
+        .bind(keywords)
+        .bind(subscription.active)
+        .bind(subscription.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_subscription(&self, id: &WebhookId) -> Result<Option<WebhookSubscription>, DomainError> {
+        let row = sqlx::query("SELECT * FROM webhook_subscriptions WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_subscription(&r)).transpose()
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM webhook_subscriptions ORDER BY created_at, id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_subscription).collect()
+    }
+
+    async fn delete_subscription(&self, id: &WebhookId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM webhook_subscriptions WHERE id = ?")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), DomainError> {
+        let event = serde_json::to_string(&delivery.event)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO webhook_deliveries
+                (id, subscription_id, event, response_status, created_at,
+                 status, attempts, last_error, last_attempt_at, next_attempt_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&delivery.id)
+        .bind(delivery.subscription_id.to_string())
+        .bind(event)
+        .bind(delivery.response_status.map(|s| s as i64))
+        .bind(delivery.created_at)
+        .bind(delivery.state.status.as_str())
+        .bind(delivery.state.attempts as i64)
+        .bind(&delivery.state.last_error)
+        .bind(delivery.state.last_attempt_at)
+        .bind(delivery.state.next_attempt_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_due_deliveries(&self, now: i64, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE status = 'retrying' AND (next_attempt_at IS NULL OR next_attempt_at <= ?)
+            ORDER BY created_at, id
+            LIMIT ?
+            "#,
+        )
+        .bind(now)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_webhook_delivery).collect()
+    }
+
+    async fn list_deliveries(&self, subscription_id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT * FROM webhook_deliveries WHERE subscription_id = ? ORDER BY created_at DESC, id DESC LIMIT ?",
+        )
+        .bind(subscription_id.to_string())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_webhook_delivery).collect()
+    }
+}
+
+fn map_row_to_subscription(row: &sqlx::sqlite::SqliteRow) -> Result<WebhookSubscription, DomainError> {
+    let id: String = row.try_get("id")
+        .map_err(|e| DomainError::Repository(format!("Missing id: {}", e)))?;
+    let event_types: String = row.try_get("event_types").unwrap_or_else(|_| "[]".to_string());
+    let event_types: Vec<String> = serde_json::from_str(&event_types)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+    let keywords: String = row.try_get("keywords").unwrap_or_else(|_| "[]".to_string());
+
+    Ok(WebhookSubscription {
+        id: WebhookId::from(id.as_str()),
+        url: row.try_get("url").unwrap_or_default(),
+        secret: row.try_get("secret").unwrap_or_default(),
+        event_types: event_types
+            .iter()
+            .map(|t| t.parse::<WebhookEventType>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| DomainError::Repository(e.to_string()))?,
+        keywords: serde_json::from_str(&keywords)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?,
+        active: row.try_get("active").unwrap_or(true),
+        created_at: row.try_get("created_at").unwrap_or_default(),
+    })
+}
+
+fn map_row_to_webhook_delivery(row: &sqlx::sqlite::SqliteRow) -> Result<WebhookDelivery, DomainError> {
+    let subscription_id: String = row.try_get("subscription_id")
+        .map_err(|e| DomainError::Repository(format!("Missing subscription_id: {}", e)))?;
+    let event: String = row.try_get("event")
+        .map_err(|e| DomainError::Repository(format!("Missing event: {}", e)))?;
+    let event: WebhookEvent = serde_json::from_str(&event)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+    Ok(WebhookDelivery {
+        id: row.try_get("id").map_err(|e| DomainError::Repository(format!("Missing id: {}", e)))?,
+        subscription_id: WebhookId::from(subscription_id.as_str()),
+        event,
+        response_status: row.try_get::<Option<i64>, _>("response_status").unwrap_or_default().map(|s| s as u16),
+        created_at: row.try_get("created_at").unwrap_or_default(),
+        state: map_row_to_delivery_state(row)?,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SqliteAlertRepo {
+    pool: Pool<Sqlite>,
+}
+
+impl SqliteAlertRepo {
+    pub fn new(pool: Pool<Sqlite>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AlertRepo for SqliteAlertRepo {
+    async fn save(&self, alert: &WatchAlert) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO watch_alerts
+                (user_id, rule_id, rule_name, article_id, title, url, source, matched_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(alert.user_id.to_string())
+        .bind(alert.rule_id.to_string())
+        .bind(&alert.rule_name)
+        .bind(alert.article_id.to_string())
+        .bind(&alert.title)
+        .bind(&alert.url)
+        .bind(&alert.source)
+        .bind(alert.matched_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<WatchAlert>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM watch_alerts WHERE user_id = ?
+            ORDER BY matched_at DESC, article_id DESC, rule_id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(user_id.to_string())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_alert).collect()
+    }
+}
+
+fn map_row_to_alert(row: &sqlx::sqlite::SqliteRow) -> Result<WatchAlert, DomainError> {
+    let user_id: String = row.try_get("user_id")
+        .map_err(|e| DomainError::Repository(format!("Missing user_id: {}", e)))?;
+    let rule_id: String = row.try_get("rule_id")
+        .map_err(|e| DomainError::Repository(format!("Missing rule_id: {}", e)))?;
+    let article_id: String = row.try_get("article_id")
+        .map_err(|e| DomainError::Repository(format!("Missing article_id: {}", e)))?;
+
+    Ok(WatchAlert {
+        user_id: UserId::new(user_id),
+        rule_id: WatchRuleId::from(rule_id.as_str()),
+        rule_name: row.try_get("rule_name").unwrap_or_default(),
+        article_id: ArticleId::from_persisted(article_id),
+        title: row.try_get("title").unwrap_or_default(),
+        url: row.try_get("url").unwrap_or_default(),
+        source: row.try_get("source").unwrap_or_default(),
+        matched_at: row.try_get("matched_at").unwrap_or_default(),
+    })
 }
@@ -1,11 +1,25 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
 use async_trait::async_trait;
-use techpulse_domain::article::{Article, ArticleId};
+use chrono::NaiveDate;
+use techpulse_domain::article::{Article, ArticleId, ArticleSnapshot, SaveOutcome};
 use techpulse_domain::error::DomainError;
-use techpulse_domain::repository::{ArticleRepo, TimelineRepo, TrendRepo, UserRepo};
+use techpulse_domain::cooccurrence::CooccurrenceGraph;
+use techpulse_domain::briefing::Briefing;
+use techpulse_domain::delivery::BriefingDelivery;
+use techpulse_domain::interaction::{InteractionEvent, InteractionKind};
+use techpulse_domain::query::{ArticlePage, ArticleQuery};
+use techpulse_domain::search::{SearchHit, SearchQuery};
+use techpulse_domain::repository::{
+    ArticleRepo, BriefingRepo, CooccurrenceRepo, DeliveryRepo, InteractionRepo, TimelineRepo, TopicRepo,
+    TrendRepo, UserRepo, WebhookRepo, AlertRepo, SnapshotRepo,
+};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::{Topic, TopicSlug};
 use techpulse_domain::trend::{TimelineEvent, TimelineEventId, TrendReport};
 use techpulse_domain::user::{UserId, UserProfile};
+use techpulse_domain::watchlist::{WatchAlert, WatchRuleId};
+use techpulse_domain::webhook::{WebhookDelivery, WebhookId, WebhookSubscription};
 
 
 // --- Article Repository ---
@@ -18,14 +32,34 @@ impl InMemoryArticleRepo {
     pub fn new() -> Self {
         Self::default()
     }
+
+    fn upsert(store: &mut HashMap<ArticleId, Article>, article: &Article) -> SaveOutcome {
+        match store.get_mut(&article.id) {
+            Some(stored) => {
+                *stored = stored.merged_with(article);
+                SaveOutcome::Updated
+            }
+            None => {
+                let mut article = article.clone();
+                article.times_seen = article.times_seen.max(1);
+                store.insert(article.id.clone(), article);
+                SaveOutcome::Inserted
+            }
+        }
+    }
 }
 
 #[async_trait]
 impl ArticleRepo for InMemoryArticleRepo {
-    async fn save(&self, article: &Article) -> Result<(), DomainError> {
+    async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError> {
         let mut store = self.store.write().map_err(|e| DomainError::Repository(e.to_string()))?;
-        store.insert(article.id.clone(), article.clone());
-        Ok(())
+        Ok(Self::upsert(&mut store, article))
+    }
+
+    async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError> {
+        // One write lock for the batch, so readers never see part of it
+        let mut store = self.store.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(articles.iter().map(|a| Self::upsert(&mut store, a)).collect())
     }
 
     async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError> {
@@ -36,10 +70,100 @@ impl ArticleRepo for InMemoryArticleRepo {
     async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError> {
         let store = self.store.read().map_err(|e| DomainError::Repository(e.to_string()))?;
         let mut articles: Vec<Article> = store.values().cloned().collect();
-        articles.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        articles.sort_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then_with(|| b.id.to_string().cmp(&a.id.to_string()))
+        });
         articles.truncate(limit);
         Ok(articles)
     }
+
+    async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError> {
+        let store = self.store.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut articles: Vec<Article> = store
+            .values()
+            .filter(|a| query.matches(a) && query.cursor.as_ref().is_none_or(|c| c.precedes(a)))
+            .cloned()
+            .collect();
+        articles.sort_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then_with(|| b.id.to_string().cmp(&a.id.to_string()))
+        });
+        articles.truncate(query.limit + 1);
+        Ok(ArticlePage::from_overfetch(articles, query.limit))
+    }
+
+    async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError> {
+        let store = self.store.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut hits: Vec<SearchHit> = store
+            .values()
+            .filter_map(|article| {
+                let relevance = query.relevance(article)?;
+                Some(SearchHit { article: article.clone(), relevance, snippet: query.snippet(article) })
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.relevance
+                .total_cmp(&a.relevance)
+                .then_with(|| b.article.timestamp.cmp(&a.article.timestamp))
+        });
+        hits.truncate(limit);
+        Ok(hits)
+    }
+
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError> {
+        let mut store = self.store.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let count = store.len();
+        store.retain(|id, article| article.last_activity() >= before || keep.contains(id));
+        Ok(count - store.len())
+    }
+}
+
+// --- Snapshot Repository ---
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySnapshotRepo {
+    store: Arc<RwLock<HashMap<ArticleId, Vec<ArticleSnapshot>>>>,
+}
+
+impl InMemorySnapshotRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl SnapshotRepo for InMemorySnapshotRepo {
+    async fn append(&self, snapshots: &[ArticleSnapshot]) -> Result<(), DomainError> {
+        let mut store = self.store.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        for snapshot in snapshots {
+            let series = store.entry(snapshot.article_id.clone()).or_default();
+            series.retain(|s| s.captured_at != snapshot.captured_at);
+            series.push(snapshot.clone());
+            series.sort_by_key(|s| s.captured_at);
+        }
+        Ok(())
+    }
+
+    async fn list_for_article(&self, article_id: &ArticleId) -> Result<Vec<ArticleSnapshot>, DomainError> {
+        let store = self.store.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(store.get(article_id).cloned().unwrap_or_default())
+    }
+
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError> {
+        let mut store = self.store.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut removed = 0;
+        store.retain(|id, series| {
+            if !keep.contains(id) {
+                let count = series.len();
+                series.retain(|s| s.captured_at >= before);
+                removed += count - series.len();
+            }
+            !series.is_empty()
+        });
+        Ok(removed)
+    }
 }
 
 // --- User Repository ---
@@ -66,6 +190,13 @@ impl UserRepo for InMemoryUserRepo {
         let store = self.store.read().map_err(|e| DomainError::Repository(e.to_string()))?;
         Ok(store.get(id).cloned())
     }
+
+    async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError> {
+        let store = self.store.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut users: Vec<UserProfile> = store.values().cloned().collect();
+        users.sort_by_key(|u| u.id.to_string());
+        Ok(users)
+    }
 }
 
 // --- Trend Repository ---
@@ -93,6 +224,21 @@ impl TrendRepo for InMemoryTrendRepo {
         // Explicit sort by timestamp to ensure correctness regardless of insertion order
         Ok(reports.iter().max_by_key(|r| r.timestamp).cloned())
     }
+
+    async fn prune(&self, before: i64) -> Result<usize, DomainError> {
+        let mut reports = self.reports.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let Some(latest) = reports.iter().enumerate().max_by_key(|(_, r)| r.timestamp).map(|(i, _)| i) else {
+            return Ok(0);
+        };
+        let count = reports.len();
+        let mut index = 0;
+        reports.retain(|r| {
+            let keep = index == latest || r.timestamp >= before;
+            index += 1;
+            keep
+        });
+        Ok(count - reports.len())
+    }
 }
 
 // --- Timeline Repository ---
@@ -116,11 +262,306 @@ impl TimelineRepo for InMemoryTimelineRepo {
         Ok(())
     }
 
+    async fn find_event(&self, id: &TimelineEventId) -> Result<Option<TimelineEvent>, DomainError> {
+        let events = self.events.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(events.get(id).cloned())
+    }
+
     async fn list_events(&self) -> Result<Vec<TimelineEvent>, DomainError> {
         let events = self.events.read().map_err(|e| DomainError::Repository(e.to_string()))?;
         let mut list: Vec<TimelineEvent> = events.values().cloned().collect();
-        // Sort by date descending (newest first)
-        list.sort_by(|a, b| b.date.cmp(&a.date));
+        // Newest first, same-day events by ID like the SQL repos
+        list.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| a.id.to_string().cmp(&b.id.to_string())));
+        Ok(list)
+    }
+
+    async fn delete_event(&self, id: &TimelineEventId) -> Result<(), DomainError> {
+        let mut events = self.events.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        events.remove(id);
+        Ok(())
+    }
+}
+
+// --- Topic Repository ---
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTopicRepo {
+    topics: Arc<RwLock<HashMap<TopicSlug, Topic>>>,
+}
+
+impl InMemoryTopicRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TopicRepo for InMemoryTopicRepo {
+    async fn save(&self, topic: &Topic) -> Result<(), DomainError> {
+        let mut topics = self.topics.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        topics.insert(topic.slug.clone(), topic.clone());
+        Ok(())
+    }
+
+    async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError> {
+        let topics = self.topics.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(topics.get(slug).cloned())
+    }
+
+    async fn list_all(&self) -> Result<Vec<Topic>, DomainError> {
+        let topics = self.topics.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut list: Vec<Topic> = topics.values().cloned().collect();
+        list.sort_by(|a, b| a.slug.cmp(&b.slug));
+        Ok(list)
+    }
+}
+
+// --- Co-occurrence Repository ---
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryCooccurrenceRepo {
+    // Key by window so rebuilding a period replaces it
+    graphs: Arc<RwLock<HashMap<TimeWindow, CooccurrenceGraph>>>,
+}
+
+impl InMemoryCooccurrenceRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CooccurrenceRepo for InMemoryCooccurrenceRepo {
+    async fn save_graph(&self, graph: &CooccurrenceGraph) -> Result<(), DomainError> {
+        let mut graphs = self.graphs.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        graphs.insert(graph.window, graph.clone());
+        Ok(())
+    }
+
+    async fn find_graph(&self, window: &TimeWindow) -> Result<Option<CooccurrenceGraph>, DomainError> {
+        let graphs = self.graphs.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(graphs.get(window).cloned())
+    }
+
+    async fn find_latest_graph(&self) -> Result<Option<CooccurrenceGraph>, DomainError> {
+        let graphs = self.graphs.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(graphs.values().max_by_key(|g| (g.window.end, g.window.start)).cloned())
+    }
+}
+
+// --- Interaction Repository ---
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryInteractionRepo {
+    events: Arc<RwLock<Vec<InteractionEvent>>>,
+}
+
+impl InMemoryInteractionRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl InteractionRepo for InMemoryInteractionRepo {
+    async fn append(&self, events: &[InteractionEvent]) -> Result<(), DomainError> {
+        let mut store = self.events.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        store.extend_from_slice(events);
+        Ok(())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, since: i64) -> Result<Vec<InteractionEvent>, DomainError> {
+        let store = self.events.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut events: Vec<InteractionEvent> = store
+            .iter()
+            .filter(|e| &e.user_id == user_id && e.timestamp >= since)
+            .cloned()
+            .collect();
+        // Stable sort keeps insertion order for equal timestamps
+        events.sort_by_key(|e| e.timestamp);
+        Ok(events)
+    }
+
+    async fn bookmarked_articles(&self) -> Result<HashSet<ArticleId>, DomainError> {
+        let store = self.events.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(store
+            .iter()
+            .filter(|e| e.kind == InteractionKind::WantToLearn)
+            .map(|e| e.article_id.clone())
+            .collect())
+    }
+}
+
+// --- Briefing Repository ---
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryBriefingRepo {
+    briefings: Arc<RwLock<HashMap<(UserId, NaiveDate), Briefing>>>,
+}
+
+impl InMemoryBriefingRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BriefingRepo for InMemoryBriefingRepo {
+    async fn save(&self, briefing: &Briefing) -> Result<(), DomainError> {
+        let mut briefings = self.briefings.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        briefings.insert((briefing.user_id.clone(), briefing.date), briefing.clone());
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<Briefing>, DomainError> {
+        let briefings = self.briefings.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(briefings.get(&(user_id.clone(), date)).cloned())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<Briefing>, DomainError> {
+        let briefings = self.briefings.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut list: Vec<Briefing> = briefings
+            .values()
+            .filter(|b| &b.user_id == user_id)
+            .cloned()
+            .collect();
+        list.sort_by_key(|b| std::cmp::Reverse(b.date));
+        list.truncate(limit);
+        Ok(list)
+    }
+}
+
+// --- Delivery Repository ---
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryDeliveryRepo {
+    deliveries: Arc<RwLock<HashMap<(UserId, NaiveDate), BriefingDelivery>>>,
+}
+
+impl InMemoryDeliveryRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl DeliveryRepo for InMemoryDeliveryRepo {
+    async fn save(&self, delivery: &BriefingDelivery) -> Result<(), DomainError> {
+        let mut deliveries = self.deliveries.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        deliveries.insert((delivery.user_id.clone(), delivery.date), delivery.clone());
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<BriefingDelivery>, DomainError> {
+        let deliveries = self.deliveries.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(deliveries.get(&(user_id.clone(), date)).cloned())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<BriefingDelivery>, DomainError> {
+        let deliveries = self.deliveries.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut list: Vec<BriefingDelivery> = deliveries
+            .values()
+            .filter(|d| &d.user_id == user_id)
+            .cloned()
+            .collect();
+        list.sort_by_key(|d| std::cmp::Reverse(d.date));
+        list.truncate(limit);
+        Ok(list)
+    }
+}
+
+// --- Webhook Repository ---
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryWebhookRepo {
+    subscriptions: Arc<RwLock<HashMap<WebhookId, WebhookSubscription>>>,
+    deliveries: Arc<RwLock<HashMap<String, WebhookDelivery>>>,
+}
+
+impl InMemoryWebhookRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl WebhookRepo for InMemoryWebhookRepo {
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), DomainError> {
+        let mut subscriptions = self.subscriptions.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        subscriptions.insert(subscription.id.clone(), subscription.clone());
+        Ok(())
+    }
+
+    async fn find_subscription(&self, id: &WebhookId) -> Result<Option<WebhookSubscription>, DomainError> {
+        let subscriptions = self.subscriptions.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(subscriptions.get(id).cloned())
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, DomainError> {
+        let subscriptions = self.subscriptions.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut list: Vec<WebhookSubscription> = subscriptions.values().cloned().collect();
+        list.sort_by_key(|s| (s.created_at, s.id.to_string()));
+        Ok(list)
+    }
+
+    async fn delete_subscription(&self, id: &WebhookId) -> Result<(), DomainError> {
+        let mut subscriptions = self.subscriptions.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        subscriptions.remove(id);
+        Ok(())
+    }
+
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), DomainError> {
+        let mut deliveries = self.deliveries.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        deliveries.insert(delivery.id.clone(), delivery.clone());
+        Ok(())
+    }
+
+    async fn list_due_deliveries(&self, now: i64, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let deliveries = self.deliveries.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut due: Vec<WebhookDelivery> = deliveries.values().filter(|d| d.state.is_due(now)).cloned().collect();
+        due.sort_by_key(|d| (d.created_at, d.id.clone()));
+        due.truncate(limit);
+        Ok(due)
+    }
+
+    async fn list_deliveries(&self, subscription_id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let deliveries = self.deliveries.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut list: Vec<WebhookDelivery> = deliveries
+            .values()
+            .filter(|d| &d.subscription_id == subscription_id)
+            .cloned()
+            .collect();
+        list.sort_by_key(|d| std::cmp::Reverse((d.created_at, d.id.clone())));
+        list.truncate(limit);
+        Ok(list)
+    }
+}
+
+// --- Alert Repository ---
+type AlertKey = (UserId, WatchRuleId, ArticleId);
+
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryAlertRepo {
+    alerts: Arc<RwLock<HashMap<AlertKey, WatchAlert>>>,
+}
+
+impl InMemoryAlertRepo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl AlertRepo for InMemoryAlertRepo {
+    async fn save(&self, alert: &WatchAlert) -> Result<bool, DomainError> {
+        let mut alerts = self.alerts.write().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let key = (alert.user_id.clone(), alert.rule_id.clone(), alert.article_id.clone());
+        if alerts.contains_key(&key) {
+            return Ok(false);
+        }
+        alerts.insert(key, alert.clone());
+        Ok(true)
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<WatchAlert>, DomainError> {
+        let alerts = self.alerts.read().map_err(|e| DomainError::Repository(e.to_string()))?;
+        let mut list: Vec<WatchAlert> = alerts.values().filter(|a| &a.user_id == user_id).cloned().collect();
+        list.sort_by_key(|a| std::cmp::Reverse((a.matched_at, a.article_id.to_string(), a.rule_id.to_string())));
+        list.truncate(limit);
         Ok(list)
     }
 }
@@ -152,6 +593,26 @@ mod tests {
         assert!(missing.is_none());
     }
 
+    #[tokio::test]
+    async fn test_article_query_pages_with_cursor() {
+        let repo = InMemoryArticleRepo::new();
+        for (id, ts) in [("1", 300), ("2", 200), ("3", 200), ("4", 100)] {
+            let article = Article::new(Source::HackerNews, id, format!("Story {}", id), "".into(), ts).unwrap();
+            repo.save(&article).await.unwrap();
+        }
+
+        let first = repo.query(&ArticleQuery::latest(2)).await.unwrap();
+        let ids: Vec<String> = first.articles.iter().map(|a| a.id.to_string()).collect();
+        assert_eq!(ids, vec!["hn-1", "hn-3"]);
+
+        let mut next = ArticleQuery::latest(2);
+        next.cursor = first.next_cursor;
+        let second = repo.query(&next).await.unwrap();
+        let ids: Vec<String> = second.articles.iter().map(|a| a.id.to_string()).collect();
+        assert_eq!(ids, vec!["hn-2", "hn-4"]);
+        assert!(second.next_cursor.is_none());
+    }
+
     #[tokio::test]
     async fn test_find_latest_sorting_and_limit() {
         let repo = InMemoryArticleRepo::new();
@@ -179,13 +640,41 @@ mod tests {
     async fn test_article_upsert() {
         let repo = InMemoryArticleRepo::new();
         let mut article = Article::new(Source::HackerNews, "1", "V1".into(), "".into(), 100).unwrap();
-        repo.save(&article).await.unwrap();
+        assert_eq!(repo.save(&article).await.unwrap(), SaveOutcome::Inserted);
         
         article.title = "V2".into();
-        repo.save(&article).await.unwrap();
+        article.score = 30.0;
+        assert_eq!(repo.save(&article).await.unwrap(), SaveOutcome::Updated);
         
+        // The title stays as first seen; engagement is refreshed
         let found = repo.find_by_id(&article.id).await.unwrap().unwrap();
-        assert_eq!(found.title, "V2");
+        assert_eq!(found.title, "V1");
+        assert_eq!(found.score, 30.0);
+        assert_eq!(found.times_seen, 2);
+    }
+
+    #[tokio::test]
+    async fn test_article_save_many_reports_outcomes_in_order() {
+        let repo = InMemoryArticleRepo::new();
+        let article = |id: &str| Article::new(Source::HackerNews, id, "T".into(), "".into(), 100).unwrap();
+        repo.save(&article("1")).await.unwrap();
+
+        let outcomes = repo.save_many(&[article("2"), article("1"), article("2")]).await.unwrap();
+        assert_eq!(outcomes, vec![SaveOutcome::Inserted, SaveOutcome::Updated, SaveOutcome::Updated]);
+        assert_eq!(repo.find_by_id(&article("2").id).await.unwrap().unwrap().times_seen, 2);
+    }
+
+    #[tokio::test]
+    async fn test_trend_prune_keeps_latest_report() {
+        let repo = InMemoryTrendRepo::new();
+        assert_eq!(repo.prune(1000).await.unwrap(), 0);
+        for timestamp in [300, 100, 2000, 200] {
+            let report = TrendReport { timestamp, trends: vec![], metadata: Default::default() };
+            repo.save_report(&report).await.unwrap();
+        }
+        assert_eq!(repo.prune(250).await.unwrap(), 2);
+        assert_eq!(repo.prune(5000).await.unwrap(), 1);
+        assert_eq!(repo.find_latest_report().await.unwrap().unwrap().timestamp, 2000);
     }
     
     #[tokio::test]
@@ -211,6 +700,8 @@ mod tests {
             description: "".into(),
             category: "".into(),
             importance_score: 1.0,
+            url: None,
+            article_id: None,
         };
         let e2 = TimelineEvent {
             id: TimelineEventId::from("e2"),
@@ -219,6 +710,8 @@ mod tests {
             description: "".into(),
             category: "".into(),
             importance_score: 1.0,
+            url: None,
+            article_id: None,
         };
         
         repo.save_event(&e1).await.unwrap();
@@ -237,6 +730,12 @@ mod tests {
         assert_eq!(events_after.len(), 2); // Count same
         let found_e1 = events_after.iter().find(|e| e.id == e1.id).unwrap();
         assert_eq!(found_e1.title, "E1 Updated");
+
+        // Find and delete
+        assert!(repo.find_event(&e2.id).await.unwrap().is_some());
+        repo.delete_event(&e2.id).await.unwrap();
+        assert!(repo.find_event(&e2.id).await.unwrap().is_none());
+        assert_eq!(repo.list_events().await.unwrap().len(), 1);
     }
     
     #[tokio::test]
@@ -253,4 +752,46 @@ mod tests {
         assert!(latest.is_some());
         assert_eq!(latest.unwrap().timestamp, 200);
     }
+
+    #[tokio::test]
+    async fn test_topic_repo_upsert_and_ordering() {
+        use techpulse_domain::topic::TopicCategory;
+
+        let repo = InMemoryTopicRepo::new();
+        let wasm = Topic::new(TopicSlug::new("webassembly").unwrap(), "WebAssembly", TopicCategory::Web);
+        let ai = Topic::new(TopicSlug::new("ai").unwrap(), "AI", TopicCategory::Ai);
+
+        repo.save(&wasm).await.unwrap();
+        repo.save(&ai).await.unwrap();
+
+        let all = repo.list_all().await.unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].slug.as_str(), "ai"); // Sorted by slug
+
+        let updated = wasm.clone().with_aliases(&["wasm"]);
+        repo.save(&updated).await.unwrap();
+
+        let found = repo.find_by_slug(&wasm.slug).await.unwrap().unwrap();
+        assert_eq!(found.aliases, vec!["wasm".to_string()]);
+        assert_eq!(repo.list_all().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cooccurrence_repo_replaces_period() {
+        let repo = InMemoryCooccurrenceRepo::new();
+        let older = CooccurrenceGraph::build(TimeWindow::new(0, 100).unwrap(), vec![], 1);
+        let newer = CooccurrenceGraph::build(TimeWindow::new(100, 200).unwrap(), vec![], 1);
+
+        repo.save_graph(&newer).await.unwrap();
+        repo.save_graph(&older).await.unwrap();
+
+        let latest = repo.find_latest_graph().await.unwrap().unwrap();
+        assert_eq!(latest.window, newer.window);
+
+        let rebuilt = CooccurrenceGraph { document_count: 7, ..older.clone() };
+        repo.save_graph(&rebuilt).await.unwrap();
+
+        let found = repo.find_graph(&older.window).await.unwrap().unwrap();
+        assert_eq!(found.document_count, 7);
+    }
 }
@@ -1,2 +1,5 @@
+pub mod backend;
 pub mod db;
 pub mod mem;
+#[cfg(feature = "postgres")]
+pub mod pg;
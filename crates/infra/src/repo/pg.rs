@@ -0,0 +1,1305 @@
+//! PostgreSQL implementations of the repository traits, enabled with the `postgres` feature.
+//! They mirror the SQLite repos in `db` query for query; the schema lives in `migrations/postgres`.
+use super::db::{escape_like, parse_source};
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::postgres::{PgPool, PgRow};
+use sqlx::{Postgres, QueryBuilder, Row};
+use techpulse_domain::article::{Article, ArticleId, ArticleSnapshot, SaveOutcome};
+use techpulse_domain::briefing::Briefing;
+use techpulse_domain::delivery::{BriefingDelivery, DeliveryState, DeliveryStatus};
+use techpulse_domain::cooccurrence::{CooccurrenceEdge, CooccurrenceGraph};
+use techpulse_domain::error::DomainError;
+use techpulse_domain::interaction::{InteractionEvent, InteractionKind};
+use techpulse_domain::query::{ArticlePage, ArticleQuery};
+use techpulse_domain::search::{highlight, SearchHit, SearchQuery, COLUMN_WEIGHTS, MATCH_END, MATCH_START, SNIPPET_TOKENS};
+use techpulse_domain::repository::{
+    ArticleRepo, BriefingRepo, CooccurrenceRepo, DeliveryRepo, InteractionRepo, TimelineRepo, TopicRepo,
+    TrendRepo, UserRepo, WebhookRepo, AlertRepo, SnapshotRepo,
+};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::{Topic, TopicCategory, TopicSlug};
+use techpulse_domain::trend::{TimelineEvent, TimelineEventId, TrendReport, Trend};
+use techpulse_domain::mute::MuteRule;
+use techpulse_domain::watchlist::{WatchAlert, WatchRule, WatchRuleId};
+use techpulse_domain::webhook::{WebhookDelivery, WebhookEvent, WebhookEventType, WebhookId, WebhookSubscription};
+use techpulse_domain::user::{KnowledgeMap, KnowledgeState, TopicKnowledge, UserId, UserProfile, UserSettings};
+use std::collections::{HashSet, HashMap};
+
+#[derive(Debug, Clone)]
+pub struct PgArticleRepo {
+    pool: PgPool,
+}
+
+impl PgArticleRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ArticleRepo for PgArticleRepo {
+    async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError> {
+        upsert_article(&self.pool, article).await
+    }
+
+    async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError> {
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut outcomes = Vec::with_capacity(articles.len());
+        for article in articles {
+            // Dropping the transaction on error rolls back the articles already written
+            outcomes.push(upsert_article(&mut *tx, article).await?);
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(outcomes)
+    }
+
+    async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError> {
+        let row = sqlx::query("SELECT * FROM articles WHERE id = $1")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        if let Some(row) = row {
+            Ok(Some(map_row_to_article(&row)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM articles ORDER BY timestamp DESC, id DESC LIMIT $1")
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut articles = Vec::new();
+        for row in rows {
+            articles.push(map_row_to_article(&row)?);
+        }
+        Ok(articles)
+    }
+
+    async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError> {
+        let mut sql = QueryBuilder::<Postgres>::new("SELECT * FROM articles WHERE 1 = 1");
+        if !query.sources.is_empty() {
+            sql.push(" AND (");
+            for (i, source) in query.sources.iter().enumerate() {
+                if i > 0 {
+                    sql.push(" OR ");
+                }
+                sql.push("lower(source) = ").push_bind(source.clone());
+                sql.push(" OR lower(source) LIKE ")
+                    .push_bind(format!("{}-%", escape_like(source)))
+                    .push(" ESCAPE '\\'");
+            }
+            sql.push(")");
+        }
+        for tag in &query.tags {
+            sql.push(" AND EXISTS (SELECT 1 FROM jsonb_array_elements_text(articles.tags::jsonb) AS tag WHERE lower(tag) = ")
+                .push_bind(tag.clone())
+                .push(")");
+        }
+        if let Some(since) = query.since {
+            sql.push(" AND timestamp >= ").push_bind(since);
+        }
+        if let Some(until) = query.until {
+            sql.push(" AND timestamp < ").push_bind(until);
+        }
+        if let Some(min_score) = query.min_score {
+            sql.push(" AND score >= ").push_bind(min_score);
+        }
+        if let Some(domain) = &query.domain {
+            sql.push(" AND (domain = ").push_bind(domain.clone());
+            sql.push(" OR domain LIKE ")
+                .push_bind(format!("%.{}", escape_like(domain)))
+                .push(" ESCAPE '\\')");
+        }
+        if let Some(text) = &query.text {
+            sql.push(" AND strpos(lower(title), ").push_bind(text.clone()).push(") > 0");
+        }
+        if let Some(cursor) = &query.cursor {
+            sql.push(" AND (timestamp < ").push_bind(cursor.timestamp);
+            sql.push(" OR (timestamp = ").push_bind(cursor.timestamp);
+            sql.push(" AND id < ").push_bind(cursor.id.to_string()).push("))");
+        }
+        sql.push(" ORDER BY timestamp DESC, id DESC LIMIT ")
+            .push_bind(query.limit as i64 + 1);
+
+        let rows = sql
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut articles = Vec::new();
+        for row in rows {
+            articles.push(map_row_to_article(&row)?);
+        }
+        Ok(ArticlePage::from_overfetch(articles, query.limit))
+    }
+
+    async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError> {
+        // search_vector weights title, tags, author and text as A to D; ts_rank takes them D first
+        let heaviest = COLUMN_WEIGHTS[0];
+        let weights: Vec<f32> = COLUMN_WEIGHTS.iter().rev().map(|w| (w / heaviest) as f32).collect();
+        let headline = format!(
+            r#"StartSel="{}", StopSel="{}", MaxWords={}, MinWords={}, MaxFragments=1"#,
+            MATCH_START,
+            MATCH_END,
+            SNIPPET_TOKENS,
+            SNIPPET_TOKENS / 4
+        );
+        let rows = sqlx::query(
+            r#"
+            SELECT articles.*,
+                ts_rank($1::float4[], search_vector, query)::float8 AS relevance,
+                ts_headline('simple', concat_ws(' ', title, translate(tags, '[]",', '    '), author, text), query, $2) AS snippet
+            FROM articles, to_tsquery('simple', $3) AS query
+            WHERE search_vector @@ query
+            ORDER BY relevance DESC, articles.timestamp DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(weights)
+        .bind(headline)
+        .bind(query.to_tsquery())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut hits = Vec::new();
+        for row in rows {
+            hits.push(SearchHit {
+                article: map_row_to_article(&row)?,
+                relevance: row.try_get("relevance").unwrap_or_default(),
+                snippet: highlight(&row.try_get::<String, _>("snippet").unwrap_or_default()),
+            });
+        }
+        Ok(hits)
+    }
+
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError> {
+        let result = sqlx::query("DELETE FROM articles WHERE GREATEST(timestamp, last_seen_at) < $1 AND id <> ALL($2)")
+            .bind(before)
+            .bind(id_list(keep))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+/// IDs bound as one `TEXT[]` parameter, however many there are.
+fn id_list(ids: &HashSet<ArticleId>) -> Vec<String> {
+    ids.iter().map(ArticleId::to_string).collect()
+}
+
+async fn upsert_article<'e, E>(executor: E, article: &Article) -> Result<SaveOutcome, DomainError>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let tags_json = serde_json::to_string(&article.tags)
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+    // The update mirrors Article::merged_with; search_vector is a generated column and follows along.
+    // xmax is zero only on a row version the statement inserted, not on one it updated.
+    let inserted: bool = sqlx::query_scalar(
+        r#"
+        INSERT INTO articles (id, title, url, domain, source, score, author, timestamp, tags, comment_count, is_hot_on_source, text, first_seen_at, last_seen_at, times_seen)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, GREATEST($15, 1))
+        ON CONFLICT (id) DO UPDATE SET
+            score = EXCLUDED.score,
+            tags = CASE WHEN coalesce(articles.tags, '[]') IN ('', '[]') THEN EXCLUDED.tags ELSE articles.tags END,
+            comment_count = EXCLUDED.comment_count,
+            is_hot_on_source = EXCLUDED.is_hot_on_source,
+            text = CASE WHEN articles.text = '' THEN EXCLUDED.text ELSE articles.text END,
+            last_seen_at = GREATEST(articles.last_seen_at, EXCLUDED.last_seen_at),
+            times_seen = GREATEST(articles.times_seen, 1) + 1
+        RETURNING (xmax = 0)
+        "#,
+    )
+    .bind(article.id.to_string())
+    .bind(&article.title)
+    .bind(&article.url)
+    .bind(article.domain())
+    .bind(article.source.to_string())
+    .bind(article.score)
+    .bind(&article.author)
+    .bind(article.timestamp)
+    .bind(tags_json)
+    .bind(article.comment_count as i64)
+    .bind(article.is_hot_on_source)
+    .bind(&article.text)
+    .bind(article.first_seen_at)
+    .bind(article.last_seen_at)
+    .bind(article.times_seen as i64)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+    Ok(if inserted { SaveOutcome::Inserted } else { SaveOutcome::Updated })
+}
+
+fn map_row_to_article(row: &PgRow) -> Result<Article, DomainError> {
+    let source: String = row.try_get("source")
+        .map_err(|e| DomainError::Repository(format!("Missing source: {}", e)))?;
+
+    let tags_str: String = row.try_get("tags").unwrap_or_else(|_| "[]".to_string());
+    let tags: HashSet<String> = serde_json::from_str(&tags_str)
+        .unwrap_or_default();
+
+    let id_str: String = row.try_get("id")
+        .map_err(|e| DomainError::Repository(format!("Missing id: {}", e)))?;
+
+    Ok(Article {
+        id: ArticleId::from_persisted(id_str),
+        title: row.try_get("title").map_err(|e| DomainError::Repository(format!("Missing title: {}", e)))?,
+        url: row.try_get("url").unwrap_or_default(),
+        source: parse_source(source),
+        score: row.try_get("score").unwrap_or_default(),
+        author: row.try_get("author").unwrap_or_default(),
+        timestamp: row.try_get("timestamp").map_err(|e| DomainError::Repository(format!("Missing timestamp: {}", e)))?,
+        tags,
+        comment_count: row.try_get::<i64, _>("comment_count").unwrap_or_default() as u32,
+        is_hot_on_source: row.try_get("is_hot_on_source").unwrap_or_default(),
+        text: row.try_get("text").unwrap_or_default(),
+        first_seen_at: row.try_get("first_seen_at").unwrap_or_default(),
+        last_seen_at: row.try_get("last_seen_at").unwrap_or_default(),
+        times_seen: row.try_get::<i64, _>("times_seen").unwrap_or_default() as u32,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgSnapshotRepo {
+    pool: PgPool,
+}
+
+impl PgSnapshotRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SnapshotRepo for PgSnapshotRepo {
+    async fn append(&self, snapshots: &[ArticleSnapshot]) -> Result<(), DomainError> {
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        for snapshot in snapshots {
+            sqlx::query(
+                r#"
+                INSERT INTO article_snapshots (article_id, captured_at, score, comment_count, is_hot_on_source)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (article_id, captured_at) DO UPDATE SET
+                    score = EXCLUDED.score,
+                    comment_count = EXCLUDED.comment_count,
+                    is_hot_on_source = EXCLUDED.is_hot_on_source
+                "#,
+            )
+            .bind(snapshot.article_id.to_string())
+            .bind(snapshot.captured_at)
+            .bind(snapshot.score)
+            .bind(snapshot.comment_count as i64)
+            .bind(snapshot.is_hot_on_source)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn list_for_article(&self, article_id: &ArticleId) -> Result<Vec<ArticleSnapshot>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM article_snapshots WHERE article_id = $1 ORDER BY captured_at ASC")
+            .bind(article_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_snapshot).collect()
+    }
+
+    async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError> {
+        let result = sqlx::query(
+            "DELETE FROM article_snapshots WHERE captured_at < $1 AND article_id <> ALL($2)",
+        )
+        .bind(before)
+        .bind(id_list(keep))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+fn map_row_to_snapshot(row: &PgRow) -> Result<ArticleSnapshot, DomainError> {
+    let article_id: String = row.try_get("article_id")
+        .map_err(|e| DomainError::Repository(format!("Missing article_id: {}", e)))?;
+
+    Ok(ArticleSnapshot {
+        article_id: ArticleId::from_persisted(article_id),
+        captured_at: row.try_get("captured_at")
+            .map_err(|e| DomainError::Repository(format!("Missing captured_at: {}", e)))?,
+        score: row.try_get("score").unwrap_or_default(),
+        comment_count: row.try_get::<i64, _>("comment_count").unwrap_or_default() as u32,
+        is_hot_on_source: row.try_get("is_hot_on_source").unwrap_or_default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgTrendRepo {
+    pool: PgPool,
+}
+
+impl PgTrendRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TrendRepo for PgTrendRepo {
+    async fn save_report(&self, report: &TrendReport) -> Result<(), DomainError> {
+        let data = serde_json::to_string(&report.trends)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let metadata = serde_json::to_string(&report.metadata)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        // Note: report.timestamp is just a value in the column, not PK anymore
+        sqlx::query(
+            r#"
+            INSERT INTO trends (timestamp, data, metadata)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(report.timestamp)
+        .bind(data)
+        .bind(metadata)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_latest_report(&self) -> Result<Option<TrendReport>, DomainError> {
+        // Use timestamp index to find latest; a rerun at the same timestamp supersedes the earlier report
+        let row = sqlx::query("SELECT * FROM trends ORDER BY timestamp DESC, id DESC LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        if let Some(row) = row {
+            let timestamp: i64 = row.try_get("timestamp").map_err(|e| DomainError::Repository(format!("Missing timestamp: {}", e)))?;
+            let data_str: String = row.try_get("data").map_err(|e| DomainError::Repository(format!("Missing data: {}", e)))?;
+            let trends: Vec<Trend> = serde_json::from_str(&data_str)
+                .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+            
+            let metadata_str: String = row.try_get("metadata").unwrap_or_else(|_| "{}".to_string());
+            let metadata: HashMap<String, String> = serde_json::from_str(&metadata_str)
+                .unwrap_or_default();
+
+            Ok(Some(TrendReport { timestamp, trends, metadata }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn prune(&self, before: i64) -> Result<usize, DomainError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM trends
+            WHERE timestamp < $1 AND id <> (SELECT id FROM trends ORDER BY timestamp DESC, id DESC LIMIT 1)
+            "#,
+        )
+        .bind(before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PgTopicRepo {
+    pool: PgPool,
+}
+
+impl PgTopicRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TopicRepo for PgTopicRepo {
+    async fn save(&self, topic: &Topic) -> Result<(), DomainError> {
+        let aliases_json = serde_json::to_string(&topic.aliases)
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO topics (slug, display_name, category, aliases)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (slug) DO UPDATE SET
+                display_name = EXCLUDED.display_name, category = EXCLUDED.category, aliases = EXCLUDED.aliases
+            "#,
+        )
+        .bind(topic.slug.as_str())
+        .bind(&topic.display_name)
+        .bind(topic.category.as_str())
+        .bind(aliases_json)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError> {
+        let row = sqlx::query("SELECT * FROM topics WHERE slug = $1")
+            .bind(slug.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_topic(&r)).transpose()
+    }
+
+    async fn list_all(&self) -> Result<Vec<Topic>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM topics ORDER BY slug ASC")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_topic).collect()
+    }
+}
+
+fn map_row_to_topic(row: &PgRow) -> Result<Topic, DomainError> {
+    let slug: String = row.try_get("slug")
+        .map_err(|e| DomainError::Repository(format!("Missing slug: {}", e)))?;
+    let category_str: String = row.try_get("category").unwrap_or_default();
+    let aliases_str: String = row.try_get("aliases").unwrap_or_else(|_| "[]".to_string());
+
+    Ok(Topic {
+        slug: TopicSlug::from_persisted(slug),
+        display_name: row.try_get("display_name").map_err(|e| DomainError::Repository(format!("Missing display_name: {}", e)))?,
+        aliases: serde_json::from_str(&aliases_str).unwrap_or_default(),
+        // Unknown categories (e.g. from a newer schema) fall back to Other
+        category: category_str.parse::<TopicCategory>().unwrap_or_default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgCooccurrenceRepo {
+    pool: PgPool,
+}
+
+impl PgCooccurrenceRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CooccurrenceRepo for PgCooccurrenceRepo {
+    async fn save_graph(&self, graph: &CooccurrenceGraph) -> Result<(), DomainError> {
+        let topic_counts = serde_json::to_string(&graph.topic_counts)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let edges = serde_json::to_string(&graph.edges)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        // One graph per period: rebuilding a window replaces the previous row
+        sqlx::query(
+            r#"
+            INSERT INTO cooccurrence_graphs (period_start, period_end, document_count, topic_counts, edges)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (period_start, period_end) DO UPDATE SET
+                document_count = EXCLUDED.document_count, topic_counts = EXCLUDED.topic_counts, edges = EXCLUDED.edges
+            "#,
+        )
+        .bind(graph.window.start)
+        .bind(graph.window.end)
+        .bind(graph.document_count as i64)
+        .bind(topic_counts)
+        .bind(edges)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_graph(&self, window: &TimeWindow) -> Result<Option<CooccurrenceGraph>, DomainError> {
+        let row = sqlx::query("SELECT * FROM cooccurrence_graphs WHERE period_start = $1 AND period_end = $2")
+            .bind(window.start)
+            .bind(window.end)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_graph(&r)).transpose()
+    }
+
+    async fn find_latest_graph(&self) -> Result<Option<CooccurrenceGraph>, DomainError> {
+        let row = sqlx::query("SELECT * FROM cooccurrence_graphs ORDER BY period_end DESC, period_start DESC LIMIT 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_graph(&r)).transpose()
+    }
+}
+
+fn map_row_to_graph(row: &PgRow) -> Result<CooccurrenceGraph, DomainError> {
+    let start: i64 = row.try_get("period_start").map_err(|e| DomainError::Repository(format!("Missing period_start: {}", e)))?;
+    let end: i64 = row.try_get("period_end").map_err(|e| DomainError::Repository(format!("Missing period_end: {}", e)))?;
+    let topic_counts_str: String = row.try_get("topic_counts").unwrap_or_else(|_| "{}".to_string());
+    let edges_str: String = row.try_get("edges").map_err(|e| DomainError::Repository(format!("Missing edges: {}", e)))?;
+    let edges: Vec<CooccurrenceEdge> = serde_json::from_str(&edges_str)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+    Ok(CooccurrenceGraph {
+        window: TimeWindow { start, end },
+        document_count: row.try_get::<i64, _>("document_count").unwrap_or_default() as u32,
+        topic_counts: serde_json::from_str(&topic_counts_str).unwrap_or_default(),
+        edges,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgTimelineRepo {
+    pool: PgPool,
+}
+
+impl PgTimelineRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TimelineRepo for PgTimelineRepo {
+    async fn save_event(&self, event: &TimelineEvent) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO timeline_events (id, title, date, description, category, importance_score, url, article_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (id) DO UPDATE SET
+                title = EXCLUDED.title, date = EXCLUDED.date, description = EXCLUDED.description,
+                category = EXCLUDED.category, importance_score = EXCLUDED.importance_score,
+                url = EXCLUDED.url, article_id = EXCLUDED.article_id
+            "#,
+        )
+        .bind(event.id.to_string())
+        .bind(&event.title)
+        .bind(event.date.to_string())
+        .bind(&event.description)
+        .bind(&event.category)
+        .bind(event.importance_score)
+        .bind(&event.url)
+        .bind(event.article_id.as_ref().map(|id| id.to_string()))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_event(&self, id: &TimelineEventId) -> Result<Option<TimelineEvent>, DomainError> {
+        let row = sqlx::query("SELECT * FROM timeline_events WHERE id = $1")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_timeline_event(&r)).transpose()
+    }
+
+    async fn list_events(&self) -> Result<Vec<TimelineEvent>, DomainError> {
+        // ISO dates sort lexicographically; newest first like the in-memory repo
+        let rows = sqlx::query("SELECT * FROM timeline_events ORDER BY date DESC, id ASC")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_timeline_event).collect()
+    }
+
+    async fn delete_event(&self, id: &TimelineEventId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM timeline_events WHERE id = $1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+fn map_row_to_timeline_event(row: &PgRow) -> Result<TimelineEvent, DomainError> {
+    let id: String = row.try_get("id")
+        .map_err(|e| DomainError::Repository(format!("Missing id: {}", e)))?;
+    let date_str: String = row.try_get("date")
+        .map_err(|e| DomainError::Repository(format!("Missing date: {}", e)))?;
+    let date = NaiveDate::parse_from_str(&date_str, "%Y-%m-%d")
+        .map_err(|e| DomainError::Repository(format!("Invalid date '{}': {}", date_str, e)))?;
+
+    Ok(TimelineEvent {
+        id: TimelineEventId::from(id.as_str()),
+        title: row.try_get("title").map_err(|e| DomainError::Repository(format!("Missing title: {}", e)))?,
+        date,
+        description: row.try_get("description").unwrap_or_default(),
+        category: row.try_get("category").unwrap_or_default(),
+        importance_score: row.try_get("importance_score").unwrap_or_default(),
+        url: row.try_get("url").unwrap_or_default(),
+        article_id: row
+            .try_get::<Option<String>, _>("article_id")
+            .unwrap_or_default()
+            .map(ArticleId::from_persisted),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgUserRepo {
+    pool: PgPool,
+}
+
+impl PgUserRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserRepo for PgUserRepo {
+    async fn save(&self, user: &UserProfile) -> Result<(), DomainError> {
+        let settings = serde_json::to_string(&user.settings)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let watchlist = serde_json::to_string(&user.watchlist)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let mutes = serde_json::to_string(&user.mutes)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        // Profile and knowledge map are written together so readers never see a partial map
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO users (id, settings, watchlist, mutes) VALUES ($1, $2, $3, $4)
+            ON CONFLICT (id) DO UPDATE SET
+                settings = EXCLUDED.settings, watchlist = EXCLUDED.watchlist, mutes = EXCLUDED.mutes
+            "#,
+        )
+        .bind(user.id.to_string())
+        .bind(settings)
+        .bind(watchlist)
+        .bind(mutes)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        sqlx::query("DELETE FROM user_topic_knowledge WHERE user_id = $1")
+            .bind(user.id.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        for knowledge in user.knowledge.topics.values() {
+            sqlx::query(
+                r#"
+                INSERT INTO user_topic_knowledge (user_id, topic, state, first_seen, last_interaction, interaction_count)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+            )
+            .bind(user.id.to_string())
+            .bind(&knowledge.topic)
+            .bind(knowledge.state.as_str())
+            .bind(knowledge.first_seen)
+            .bind(knowledge.last_interaction)
+            .bind(knowledge.interaction_count as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError> {
+        let row = sqlx::query("SELECT * FROM users WHERE id = $1")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let settings_str: String = row.try_get("settings").unwrap_or_else(|_| "{}".to_string());
+        let settings: UserSettings = serde_json::from_str(&settings_str)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+        let topic_rows = sqlx::query("SELECT * FROM user_topic_knowledge WHERE user_id = $1")
+            .bind(id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut knowledge = KnowledgeMap::default();
+        for topic_row in &topic_rows {
+            let entry = map_row_to_topic_knowledge(topic_row)?;
+            knowledge.topics.insert(entry.topic.clone(), entry);
+        }
+
+        let watchlist_str: String = row.try_get("watchlist").unwrap_or_else(|_| "[]".to_string());
+        let watchlist: Vec<WatchRule> = serde_json::from_str(&watchlist_str)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+        let mutes_str: String = row.try_get("mutes").unwrap_or_else(|_| "[]".to_string());
+        let mutes: Vec<MuteRule> = serde_json::from_str(&mutes_str)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+        Ok(Some(UserProfile {
+            id: id.clone(),
+            knowledge,
+            settings,
+            watchlist,
+            mutes,
+        }))
+    }
+
+    async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError> {
+        let ids: Vec<String> = sqlx::query_scalar("SELECT id FROM users ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        let mut users = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(user) = self.find_by_id(&UserId::new(id)).await? {
+                users.push(user);
+            }
+        }
+        Ok(users)
+    }
+}
+
+fn map_row_to_topic_knowledge(row: &PgRow) -> Result<TopicKnowledge, DomainError> {
+    let state_str: String = row.try_get("state").unwrap_or_default();
+
+    Ok(TopicKnowledge {
+        topic: row.try_get("topic").map_err(|e| DomainError::Repository(format!("Missing topic: {}", e)))?,
+        state: state_str.parse::<KnowledgeState>().unwrap_or_default(),
+        first_seen: row.try_get("first_seen").unwrap_or_default(),
+        last_interaction: row.try_get("last_interaction").unwrap_or_default(),
+        interaction_count: row.try_get::<i64, _>("interaction_count").unwrap_or_default() as u32,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgInteractionRepo {
+    pool: PgPool,
+}
+
+impl PgInteractionRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InteractionRepo for PgInteractionRepo {
+    async fn append(&self, events: &[InteractionEvent]) -> Result<(), DomainError> {
+        // A batch is stored all-or-nothing
+        let mut tx = self.pool.begin().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        for event in events {
+            sqlx::query(
+                r#"
+                INSERT INTO interactions (user_id, article_id, kind, dwell_secs, timestamp)
+                VALUES ($1, $2, $3, $4, $5)
+                "#,
+            )
+            .bind(event.user_id.to_string())
+            .bind(event.article_id.to_string())
+            .bind(event.kind.as_str())
+            .bind(event.kind.dwell_secs().map(|s| s as i64))
+            .bind(event.timestamp)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        }
+
+        tx.commit().await.map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, since: i64) -> Result<Vec<InteractionEvent>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT * FROM interactions WHERE user_id = $1 AND timestamp >= $2 ORDER BY timestamp ASC, id ASC",
+        )
+        .bind(user_id.to_string())
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_interaction).collect()
+    }
+
+    async fn bookmarked_articles(&self) -> Result<HashSet<ArticleId>, DomainError> {
+        let ids: Vec<String> = sqlx::query_scalar("SELECT DISTINCT article_id FROM interactions WHERE kind = $1")
+            .bind(InteractionKind::WantToLearn.as_str())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(ids.into_iter().map(ArticleId::from_persisted).collect())
+    }
+}
+
+fn map_row_to_interaction(row: &PgRow) -> Result<InteractionEvent, DomainError> {
+    let kind: String = row.try_get("kind")
+        .map_err(|e| DomainError::Repository(format!("Missing kind: {}", e)))?;
+    let dwell_secs: Option<i64> = row.try_get("dwell_secs").unwrap_or_default();
+    let kind = InteractionKind::from_parts(&kind, dwell_secs.map(|s| s as u32))
+        .map_err(|e| DomainError::Repository(format!("Invalid interaction: {}", e)))?;
+    let user_id: String = row.try_get("user_id")
+        .map_err(|e| DomainError::Repository(format!("Missing user_id: {}", e)))?;
+    let article_id: String = row.try_get("article_id")
+        .map_err(|e| DomainError::Repository(format!("Missing article_id: {}", e)))?;
+
+    Ok(InteractionEvent {
+        user_id: UserId::new(user_id),
+        article_id: ArticleId::from_persisted(article_id),
+        kind,
+        timestamp: row.try_get("timestamp").unwrap_or_default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgBriefingRepo {
+    pool: PgPool,
+}
+
+impl PgBriefingRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BriefingRepo for PgBriefingRepo {
+    async fn save(&self, briefing: &Briefing) -> Result<(), DomainError> {
+        // The whole briefing is a snapshot, so it is stored as one JSON document
+        let content = serde_json::to_string(briefing)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO briefings (user_id, date, generated_at, content)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id, date) DO UPDATE SET generated_at = EXCLUDED.generated_at, content = EXCLUDED.content
+            "#,
+        )
+        .bind(briefing.user_id.to_string())
+        .bind(briefing.date.to_string())
+        .bind(briefing.generated_at)
+        .bind(content)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<Briefing>, DomainError> {
+        let row = sqlx::query("SELECT content FROM briefings WHERE user_id = $1 AND date = $2")
+            .bind(user_id.to_string())
+            .bind(date.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_briefing(&r)).transpose()
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<Briefing>, DomainError> {
+        let rows = sqlx::query("SELECT content FROM briefings WHERE user_id = $1 ORDER BY date DESC LIMIT $2")
+            .bind(user_id.to_string())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_briefing).collect()
+    }
+}
+
+fn map_row_to_briefing(row: &PgRow) -> Result<Briefing, DomainError> {
+    let content: String = row.try_get("content")
+        .map_err(|e| DomainError::Repository(format!("Missing content: {}", e)))?;
+    serde_json::from_str(&content)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))
+}
+
+#[derive(Debug, Clone)]
+pub struct PgDeliveryRepo {
+    pool: PgPool,
+}
+
+impl PgDeliveryRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DeliveryRepo for PgDeliveryRepo {
+    async fn save(&self, delivery: &BriefingDelivery) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO briefing_deliveries
+                (user_id, date, email, status, attempts, last_error, last_attempt_at, next_attempt_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (user_id, date) DO UPDATE SET
+                email = EXCLUDED.email, status = EXCLUDED.status, attempts = EXCLUDED.attempts,
+                last_error = EXCLUDED.last_error, last_attempt_at = EXCLUDED.last_attempt_at,
+                next_attempt_at = EXCLUDED.next_attempt_at
+            "#,
+        )
+        .bind(delivery.user_id.to_string())
+        .bind(delivery.date.to_string())
+        .bind(&delivery.email)
+        .bind(delivery.state.status.as_str())
+        .bind(delivery.state.attempts as i64)
+        .bind(&delivery.state.last_error)
+        .bind(delivery.state.last_attempt_at)
+        .bind(delivery.state.next_attempt_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<BriefingDelivery>, DomainError> {
+        let row = sqlx::query("SELECT * FROM briefing_deliveries WHERE user_id = $1 AND date = $2")
+            .bind(user_id.to_string())
+            .bind(date.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_delivery(&r)).transpose()
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<BriefingDelivery>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM briefing_deliveries WHERE user_id = $1 ORDER BY date DESC LIMIT $2")
+            .bind(user_id.to_string())
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_delivery).collect()
+    }
+}
+
+fn map_row_to_delivery(row: &PgRow) -> Result<BriefingDelivery, DomainError> {
+    let user_id: String = row.try_get("user_id")
+        .map_err(|e| DomainError::Repository(format!("Missing user_id: {}", e)))?;
+    let date: String = row.try_get("date")
+        .map_err(|e| DomainError::Repository(format!("Missing date: {}", e)))?;
+
+    Ok(BriefingDelivery {
+        user_id: UserId::new(user_id),
+        date: date
+            .parse::<NaiveDate>()
+            .map_err(|e| DomainError::Repository(format!("Invalid date '{}': {}", date, e)))?,
+        email: row.try_get("email").unwrap_or_default(),
+        state: map_row_to_delivery_state(row)?,
+    })
+}
+
+fn map_row_to_delivery_state(row: &PgRow) -> Result<DeliveryState, DomainError> {
+    let status: String = row.try_get("status")
+        .map_err(|e| DomainError::Repository(format!("Missing status: {}", e)))?;
+
+    Ok(DeliveryState {
+        status: status
+            .parse::<DeliveryStatus>()
+            .map_err(|e| DomainError::Repository(e.to_string()))?,
+        attempts: row.try_get::<i64, _>("attempts").unwrap_or_default() as u32,
+        last_error: row.try_get("last_error").unwrap_or_default(),
+        last_attempt_at: row.try_get("last_attempt_at").unwrap_or_default(),
+        next_attempt_at: row.try_get("next_attempt_at").unwrap_or_default(),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgWebhookRepo {
+    pool: PgPool,
+}
+
+impl PgWebhookRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookRepo for PgWebhookRepo {
+    async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), DomainError> {
+        let event_types: Vec<&str> = subscription.event_types.iter().map(|t| t.as_str()).collect();
+        let event_types = serde_json::to_string(&event_types)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+        let keywords = serde_json::to_string(&subscription.keywords)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_subscriptions (id, url, secret, event_types, keywords, active, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO UPDATE SET
+                url = EXCLUDED.url, secret = EXCLUDED.secret, event_types = EXCLUDED.event_types,
+                keywords = EXCLUDED.keywords, active = EXCLUDED.active, created_at = EXCLUDED.created_at
+            "#,
+        )
+        .bind(subscription.id.to_string())
+        .bind(&subscription.url)
+        .bind(&subscription.secret)
+        .bind(event_types)
+        .bind(keywords)
+        .bind(subscription.active)
+        .bind(subscription.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_subscription(&self, id: &WebhookId) -> Result<Option<WebhookSubscription>, DomainError> {
+        let row = sqlx::query("SELECT * FROM webhook_subscriptions WHERE id = $1")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        row.map(|r| map_row_to_subscription(&r)).transpose()
+    }
+
+    async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, DomainError> {
+        let rows = sqlx::query("SELECT * FROM webhook_subscriptions ORDER BY created_at, id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_subscription).collect()
+    }
+
+    async fn delete_subscription(&self, id: &WebhookId) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM webhook_subscriptions WHERE id = $1")
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Repository(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), DomainError> {
+        let event = serde_json::to_string(&delivery.event)
+            .map_err(|e| DomainError::Repository(format!("Serialization error: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries
+                (id, subscription_id, event, response_status, created_at,
+                 status, attempts, last_error, last_attempt_at, next_attempt_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (id) DO UPDATE SET
+                subscription_id = EXCLUDED.subscription_id, event = EXCLUDED.event,
+                response_status = EXCLUDED.response_status, created_at = EXCLUDED.created_at,
+                status = EXCLUDED.status, attempts = EXCLUDED.attempts, last_error = EXCLUDED.last_error,
+                last_attempt_at = EXCLUDED.last_attempt_at, next_attempt_at = EXCLUDED.next_attempt_at
+            "#,
+        )
+        .bind(&delivery.id)
+        .bind(delivery.subscription_id.to_string())
+        .bind(event)
+        .bind(delivery.response_status.map(|s| s as i64))
+        .bind(delivery.created_at)
+        .bind(delivery.state.status.as_str())
+        .bind(delivery.state.attempts as i64)
+        .bind(&delivery.state.last_error)
+        .bind(delivery.state.last_attempt_at)
+        .bind(delivery.state.next_attempt_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn list_due_deliveries(&self, now: i64, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE status = 'retrying' AND (next_attempt_at IS NULL OR next_attempt_at <= $1)
+            ORDER BY created_at, id
+            LIMIT $2
+            "#,
+        )
+        .bind(now)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_webhook_delivery).collect()
+    }
+
+    async fn list_deliveries(&self, subscription_id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let rows = sqlx::query(
+            "SELECT * FROM webhook_deliveries WHERE subscription_id = $1 ORDER BY created_at DESC, id DESC LIMIT $2",
+        )
+        .bind(subscription_id.to_string())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_webhook_delivery).collect()
+    }
+}
+
+fn map_row_to_subscription(row: &PgRow) -> Result<WebhookSubscription, DomainError> {
+    let id: String = row.try_get("id")
+        .map_err(|e| DomainError::Repository(format!("Missing id: {}", e)))?;
+    let event_types: String = row.try_get("event_types").unwrap_or_else(|_| "[]".to_string());
+    let event_types: Vec<String> = serde_json::from_str(&event_types)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+    let keywords: String = row.try_get("keywords").unwrap_or_else(|_| "[]".to_string());
+
+    Ok(WebhookSubscription {
+        id: WebhookId::from(id.as_str()),
+        url: row.try_get("url").unwrap_or_default(),
+        secret: row.try_get("secret").unwrap_or_default(),
+        event_types: event_types
+            .iter()
+            .map(|t| t.parse::<WebhookEventType>())
+            .collect::<Result<_, _>>()
+            .map_err(|e| DomainError::Repository(e.to_string()))?,
+        keywords: serde_json::from_str(&keywords)
+            .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?,
+        active: row.try_get("active").unwrap_or(true),
+        created_at: row.try_get("created_at").unwrap_or_default(),
+    })
+}
+
+fn map_row_to_webhook_delivery(row: &PgRow) -> Result<WebhookDelivery, DomainError> {
+    let subscription_id: String = row.try_get("subscription_id")
+        .map_err(|e| DomainError::Repository(format!("Missing subscription_id: {}", e)))?;
+    let event: String = row.try_get("event")
+        .map_err(|e| DomainError::Repository(format!("Missing event: {}", e)))?;
+    let event: WebhookEvent = serde_json::from_str(&event)
+        .map_err(|e| DomainError::Repository(format!("Deserialization error: {}", e)))?;
+
+    Ok(WebhookDelivery {
+        id: row.try_get("id").map_err(|e| DomainError::Repository(format!("Missing id: {}", e)))?,
+        subscription_id: WebhookId::from(subscription_id.as_str()),
+        event,
+        response_status: row.try_get::<Option<i64>, _>("response_status").unwrap_or_default().map(|s| s as u16),
+        created_at: row.try_get("created_at").unwrap_or_default(),
+        state: map_row_to_delivery_state(row)?,
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct PgAlertRepo {
+    pool: PgPool,
+}
+
+impl PgAlertRepo {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AlertRepo for PgAlertRepo {
+    async fn save(&self, alert: &WatchAlert) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO watch_alerts
+                (user_id, rule_id, rule_name, article_id, title, url, source, matched_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(alert.user_id.to_string())
+        .bind(alert.rule_id.to_string())
+        .bind(&alert.rule_name)
+        .bind(alert.article_id.to_string())
+        .bind(&alert.title)
+        .bind(&alert.url)
+        .bind(&alert.source)
+        .bind(alert.matched_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<WatchAlert>, DomainError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM watch_alerts WHERE user_id = $1
+            ORDER BY matched_at DESC, article_id DESC, rule_id DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(user_id.to_string())
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Repository(e.to_string()))?;
+
+        rows.iter().map(map_row_to_alert).collect()
+    }
+}
+
+fn map_row_to_alert(row: &PgRow) -> Result<WatchAlert, DomainError> {
+    let user_id: String = row.try_get("user_id")
+        .map_err(|e| DomainError::Repository(format!("Missing user_id: {}", e)))?;
+    let rule_id: String = row.try_get("rule_id")
+        .map_err(|e| DomainError::Repository(format!("Missing rule_id: {}", e)))?;
+    let article_id: String = row.try_get("article_id")
+        .map_err(|e| DomainError::Repository(format!("Missing article_id: {}", e)))?;
+
+    Ok(WatchAlert {
+        user_id: UserId::new(user_id),
+        rule_id: WatchRuleId::from(rule_id.as_str()),
+        rule_name: row.try_get("rule_name").unwrap_or_default(),
+        article_id: ArticleId::from_persisted(article_id),
+        title: row.try_get("title").unwrap_or_default(),
+        url: row.try_get("url").unwrap_or_default(),
+        source: row.try_get("source").unwrap_or_default(),
+        matched_at: row.try_get("matched_at").unwrap_or_default(),
+    })
+}
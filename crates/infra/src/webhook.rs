@@ -0,0 +1,66 @@
+// HTTP delivery for outbound webhooks
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use reqwest::Client;
+use sha2::Sha256;
+use std::time::Duration;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::gateway::WebhookGateway;
+use techpulse_domain::webhook::{WebhookDelivery, WebhookSubscription};
+
+pub const EVENT_HEADER: &str = "X-TechPulse-Event";
+pub const DELIVERY_HEADER: &str = "X-TechPulse-Delivery";
+pub const TIMESTAMP_HEADER: &str = "X-TechPulse-Timestamp";
+pub const SIGNATURE_HEADER: &str = "X-TechPulse-Signature";
+
+/// Signature sent in `X-TechPulse-Signature`: `sha256=` followed by the hex HMAC-SHA256
+/// of `"{timestamp}.{body}"` keyed with the subscription secret.
+/// Receivers recompute it and should reject stale timestamps to prevent replays.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpWebhookGateway {
+    client: Client,
+}
+
+impl HttpWebhookGateway {
+    pub fn new(timeout: Duration) -> Result<Self, DomainError> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .user_agent("TechPulse-Webhooks/1.0")
+            .build()
+            .map_err(|e| DomainError::Gateway(format!("HTTP client error: {}", e)))?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait]
+impl WebhookGateway for HttpWebhookGateway {
+    async fn post(&self, subscription: &WebhookSubscription, delivery: &WebhookDelivery) -> Result<u16, DomainError> {
+        let body = serde_json::to_string(&delivery.event)
+            .map_err(|e| DomainError::Gateway(format!("Serialization error: {}", e)))?;
+        // Signed at send time so retries carry a fresh timestamp
+        let timestamp = chrono::Utc::now().timestamp();
+
+        let response = self
+            .client
+            .post(&subscription.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(EVENT_HEADER, delivery.event.event_type().as_str())
+            .header(DELIVERY_HEADER, &delivery.id)
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, sign(&subscription.secret, timestamp, &body))
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| DomainError::Gateway(format!("Webhook request failed: {}", e)))?;
+
+        Ok(response.status().as_u16())
+    }
+}
@@ -0,0 +1,94 @@
+//! Runs the shared repository conformance suite (`techpulse_domain::conformance`) against every backend. Postgres runs
+//! too when built with `--features postgres` and `TEST_POSTGRES_URL` is set, e.g.
+//! `TEST_POSTGRES_URL=postgres://postgres@localhost/techpulse_test cargo test -p techpulse-infra --features postgres`.
+use sqlx::sqlite::SqlitePoolOptions;
+use std::future::Future;
+use std::sync::Arc;
+use techpulse_domain::conformance;
+use techpulse_infra::repo::backend::Repositories;
+use techpulse_infra::repo::mem::{
+    InMemoryAlertRepo, InMemoryArticleRepo, InMemoryBriefingRepo, InMemoryCooccurrenceRepo, InMemoryDeliveryRepo,
+    InMemoryInteractionRepo, InMemorySnapshotRepo, InMemoryTimelineRepo, InMemoryTopicRepo, InMemoryTrendRepo,
+    InMemoryUserRepo, InMemoryWebhookRepo,
+};
+
+// Every repository of the backend, each from a fresh set of empty repositories
+async fn conform<F, Fut>(new_repos: F)
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Repositories>,
+{
+    let new_repos = &new_repos;
+    conformance::conform_article_repo(|| async move { new_repos().await.articles }).await;
+    conformance::conform_snapshot_repo(|| async move { new_repos().await.snapshots }).await;
+    conformance::conform_trend_repo(|| async move { new_repos().await.trends }).await;
+    conformance::conform_topic_repo(|| async move { new_repos().await.topics }).await;
+    conformance::conform_cooccurrence_repo(|| async move { new_repos().await.cooccurrence }).await;
+    conformance::conform_timeline_repo(|| async move { new_repos().await.timeline }).await;
+    conformance::conform_user_repo(|| async move { new_repos().await.users }).await;
+    conformance::conform_interaction_repo(|| async move { new_repos().await.interactions }).await;
+    conformance::conform_briefing_repo(|| async move { new_repos().await.briefings }).await;
+    conformance::conform_delivery_repo(|| async move { new_repos().await.deliveries }).await;
+    conformance::conform_webhook_repo(|| async move { new_repos().await.webhooks }).await;
+    conformance::conform_alert_repo(|| async move { new_repos().await.alerts }).await;
+}
+
+#[tokio::test]
+async fn test_mem_repos_conform() {
+    conform(|| async {
+        Repositories {
+            articles: Arc::new(InMemoryArticleRepo::new()),
+            snapshots: Arc::new(InMemorySnapshotRepo::new()),
+            trends: Arc::new(InMemoryTrendRepo::new()),
+            topics: Arc::new(InMemoryTopicRepo::new()),
+            cooccurrence: Arc::new(InMemoryCooccurrenceRepo::new()),
+            timeline: Arc::new(InMemoryTimelineRepo::new()),
+            users: Arc::new(InMemoryUserRepo::new()),
+            interactions: Arc::new(InMemoryInteractionRepo::new()),
+            briefings: Arc::new(InMemoryBriefingRepo::new()),
+            deliveries: Arc::new(InMemoryDeliveryRepo::new()),
+            webhooks: Arc::new(InMemoryWebhookRepo::new()),
+            alerts: Arc::new(InMemoryAlertRepo::new()),
+        }
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repos_conform() {
+    conform(|| async {
+        let pool = SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+        Repositories::sqlite(pool).await.unwrap()
+    })
+    .await;
+}
+
+#[cfg(feature = "postgres")]
+#[tokio::test]
+async fn test_postgres_repos_conform() {
+    use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+    use sqlx::Executor;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let Ok(url) = std::env::var("TEST_POSTGRES_URL") else {
+        return;
+    };
+    // A schema per check, so every check starts from empty tables
+    static NEXT_SCHEMA: AtomicUsize = AtomicUsize::new(0);
+    conform(|| async {
+        let schema = format!("conformance_{}_{}", std::process::id(), NEXT_SCHEMA.fetch_add(1, Ordering::Relaxed));
+        let options: PgConnectOptions = url.parse().unwrap();
+        let admin = PgPool::connect_with(options.clone()).await.unwrap();
+        admin
+            .execute(format!("DROP SCHEMA IF EXISTS {0} CASCADE; CREATE SCHEMA {0}", schema).as_str())
+            .await
+            .unwrap();
+        let pool = PgPoolOptions::new()
+            .max_connections(2)
+            .connect_with(options.options([("search_path", schema.as_str())]))
+            .await
+            .unwrap();
+        Repositories::postgres(pool).await.unwrap()
+    })
+    .await;
+}
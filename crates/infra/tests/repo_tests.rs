@@ -1,8 +1,29 @@
 use sqlx::sqlite::SqlitePoolOptions;
-use techpulse_domain::article::{Article, ArticleId, Source};
-use techpulse_domain::repository::{ArticleRepo, TrendRepo};
-use techpulse_domain::trend::{Trend, TrendReport};
-use techpulse_infra::repo::db::{SqliteArticleRepo, SqliteTrendRepo};
+use techpulse_domain::article::{Article, ArticleId, ArticleSnapshot, Source};
+use techpulse_domain::briefing::{Briefing, BriefingStory, BriefingTrend};
+use techpulse_domain::error::DomainError;
+use std::collections::BTreeSet;
+use techpulse_domain::cooccurrence::CooccurrenceGraph;
+use chrono::{Datelike, NaiveDate};
+use techpulse_domain::delivery::{BriefingDelivery, DeliveryStatus, RetryPolicy};
+use techpulse_domain::interaction::{InteractionEvent, InteractionKind};
+use techpulse_domain::search::SearchQuery;
+use techpulse_domain::repository::{
+    ArticleRepo, BriefingRepo, CooccurrenceRepo, DeliveryRepo, InteractionRepo, TimelineRepo, TopicRepo,
+    TrendRepo, UserRepo, WebhookRepo, AlertRepo, SnapshotRepo,
+};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::{Topic, TopicCategory, TopicSlug};
+use techpulse_domain::trend::{TimelineEvent, TimelineEventId, Trend, TrendReport};
+use techpulse_domain::user::{DeliveryPreferences, KnowledgeState, UserId, UserProfile};
+use techpulse_domain::mute::{MuteKind, MuteRule};
+use techpulse_domain::watchlist::{WatchAlert, WatchRule};
+use techpulse_domain::webhook::{WebhookData, WebhookDelivery, WebhookEvent, WebhookEventType, WebhookSubscription};
+use techpulse_infra::repo::db::{
+    SqliteArticleRepo, SqliteBriefingRepo, SqliteCooccurrenceRepo, SqliteDeliveryRepo, SqliteInteractionRepo,
+    SqliteTimelineRepo, SqliteTopicRepo, SqliteTrendRepo, SqliteUserRepo, SqliteWebhookRepo,
+    SqliteAlertRepo, SqliteSnapshotRepo,
+};
 
 #[tokio::test]
 async fn test_sqlite_article_roundtrip() {
@@ -118,3 +139,499 @@ async fn test_sqlite_trend_roundtrip() {
     let latest = repo.find_latest_report().await.unwrap().unwrap();
     assert_eq!(latest.timestamp, 2000);
 }
+
+#[tokio::test]
+async fn test_sqlite_topic_roundtrip() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let repo = SqliteTopicRepo::new(pool);
+
+    let topic = Topic::new(TopicSlug::new("webassembly").unwrap(), "WebAssembly", TopicCategory::Web)
+        .with_aliases(&["wasm"]);
+    repo.save(&topic).await.unwrap();
+    repo.save(&Topic::new(TopicSlug::new("ai").unwrap(), "AI", TopicCategory::Ai)).await.unwrap();
+
+    let found = repo.find_by_slug(&topic.slug).await.unwrap().unwrap();
+    assert_eq!(found, topic);
+
+    let missing = repo.find_by_slug(&TopicSlug::new("zig").unwrap()).await.unwrap();
+    assert!(missing.is_none());
+
+    // Upsert keeps a single row per slug
+    let renamed = Topic { display_name: "Wasm".into(), ..topic.clone() };
+    repo.save(&renamed).await.unwrap();
+
+    let all = repo.list_all().await.unwrap();
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].slug.as_str(), "ai");
+    assert_eq!(all[1].display_name, "Wasm");
+}
+
+#[tokio::test]
+async fn test_sqlite_cooccurrence_roundtrip() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let repo = SqliteCooccurrenceRepo::new(pool);
+
+    let docs: Vec<BTreeSet<String>> = vec![
+        ["rust", "webassembly"].iter().map(|s| s.to_string()).collect(),
+        ["rust", "webassembly"].iter().map(|s| s.to_string()).collect(),
+    ];
+    let window = TimeWindow::new(1000, 2000).unwrap();
+    let graph = CooccurrenceGraph::build(window, docs, 1);
+    repo.save_graph(&graph).await.unwrap();
+
+    let found = repo.find_graph(&window).await.unwrap().unwrap();
+    assert_eq!(found, graph);
+
+    // Rebuilding the same period replaces it, later periods win "latest"
+    let rebuilt = CooccurrenceGraph::build(window, vec![], 1);
+    repo.save_graph(&rebuilt).await.unwrap();
+    let later = CooccurrenceGraph::build(TimeWindow::new(2000, 3000).unwrap(), vec![], 1);
+    repo.save_graph(&later).await.unwrap();
+
+    assert_eq!(repo.find_graph(&window).await.unwrap().unwrap().document_count, 0);
+    let latest = repo.find_latest_graph().await.unwrap().unwrap();
+    assert_eq!(latest.window.start, 2000);
+}
+
+#[tokio::test]
+async fn test_sqlite_timeline_ordering_and_upsert() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let repo = SqliteTimelineRepo::new(pool);
+
+    let e1 = TimelineEvent {
+        id: TimelineEventId::from("e1"),
+        title: "E1".into(),
+        date: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+        description: "First".into(),
+        category: "ai".into(),
+        importance_score: 80.5,
+        url: Some("http://example.com/e1".into()),
+        article_id: Some(ArticleId::from_persisted("hn-1".into())),
+    };
+    let e2 = TimelineEvent {
+        id: TimelineEventId::from("e2"),
+        title: "E2".into(),
+        date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), // Newer
+        description: "".into(),
+        category: "".into(),
+        importance_score: 1.0,
+        url: None,
+        article_id: None,
+    };
+
+    repo.save_event(&e1).await.unwrap();
+    repo.save_event(&e2).await.unwrap();
+
+    let events = repo.list_events().await.unwrap();
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].id, e2.id); // Newer first
+    assert_eq!(events[1].date, e1.date);
+    assert_eq!(events[1].category, "ai");
+    assert_eq!(events[1].importance_score, 80.5);
+    assert_eq!(events[1].url.as_deref(), Some("http://example.com/e1"));
+    assert_eq!(events[1].article_id, e1.article_id);
+    assert!(events[0].url.is_none());
+
+    // Upsert check
+    let e1_updated = TimelineEvent { title: "E1 Updated".into(), ..e1.clone() };
+    repo.save_event(&e1_updated).await.unwrap();
+
+    let events_after = repo.list_events().await.unwrap();
+    assert_eq!(events_after.len(), 2);
+    let found_e1 = events_after.iter().find(|e| e.id == e1.id).unwrap();
+    assert_eq!(found_e1.title, "E1 Updated");
+
+    // Find and delete
+    assert_eq!(repo.find_event(&e2.id).await.unwrap().unwrap().title, "E2");
+    repo.delete_event(&e2.id).await.unwrap();
+    assert!(repo.find_event(&e2.id).await.unwrap().is_none());
+    assert_eq!(repo.list_events().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_sqlite_user_roundtrip_with_knowledge_map() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let repo = SqliteUserRepo::new(pool);
+
+    let missing = repo.find_by_id(&UserId::new("nobody")).await.unwrap();
+    assert!(missing.is_none());
+
+    let mut user = UserProfile::new(UserId::new("u1"));
+    user.settings.preferred_sources = vec!["hn".into(), "gh".into()];
+    user.settings.theme = "dark".into();
+    user.update_knowledge("rust", KnowledgeState::KnowIt, 1000);
+    user.update_knowledge("rust", KnowledgeState::KnowIt, 2000);
+    user.update_knowledge("webassembly", KnowledgeState::WantToLearn, 1500);
+
+    repo.save(&user).await.unwrap();
+
+    let found = repo.find_by_id(&user.id).await.unwrap().unwrap();
+    assert_eq!(found.id, user.id);
+    assert_eq!(found.settings.preferred_sources, vec!["hn".to_string(), "gh".to_string()]);
+    assert_eq!(found.settings.theme, "dark");
+    assert_eq!(found.knowledge.topics.len(), 2);
+
+    let rust = &found.knowledge.topics["rust"];
+    assert_eq!(rust.state, KnowledgeState::KnowIt);
+    assert_eq!(rust.first_seen, 1000);
+    assert_eq!(rust.last_interaction, 2000);
+    assert_eq!(rust.interaction_count, 2);
+    assert_eq!(found.knowledge.topics["webassembly"].state, KnowledgeState::WantToLearn);
+
+    // Saving again replaces the knowledge map instead of merging into it
+    user.knowledge.topics.remove("webassembly");
+    user.settings.theme = "light".into();
+    repo.save(&user).await.unwrap();
+
+    let updated = repo.find_by_id(&user.id).await.unwrap().unwrap();
+    assert_eq!(updated.knowledge.topics.len(), 1);
+    assert_eq!(updated.settings.theme, "light");
+}
+
+#[tokio::test]
+async fn test_sqlite_interactions_append_and_list() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let repo = SqliteInteractionRepo::new(pool);
+    let event = |user: &str, kind: InteractionKind, timestamp: i64| InteractionEvent {
+        user_id: UserId::new(user),
+        article_id: ArticleId::from_persisted("hn-1".into()),
+        kind,
+        timestamp,
+    };
+
+    repo.append(&[
+        event("u1", InteractionKind::Dwell { seconds: 42 }, 300),
+        event("u1", InteractionKind::Open, 100),
+        event("u2", InteractionKind::Dismiss, 150),
+        event("u1", InteractionKind::MarkKnown, 200),
+    ])
+    .await
+    .unwrap();
+
+    let all = repo.list_for_user(&UserId::new("u1"), 0).await.unwrap();
+    let kinds: Vec<InteractionKind> = all.iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![InteractionKind::Open, InteractionKind::MarkKnown, InteractionKind::Dwell { seconds: 42 }]
+    );
+    assert_eq!(all[0].article_id.to_string(), "hn-1");
+
+    let recent = repo.list_for_user(&UserId::new("u1"), 200).await.unwrap();
+    assert_eq!(recent.len(), 2);
+    assert!(repo.list_for_user(&UserId::new("nobody"), 0).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_sqlite_briefing_roundtrip_and_archive() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let repo = SqliteBriefingRepo::new(pool);
+    let briefing = |user: &str, day: u32| Briefing {
+        user_id: UserId::new(user),
+        date: NaiveDate::from_ymd_opt(2024, 3, day).unwrap(),
+        generated_at: day as i64,
+        stories: vec![BriefingStory {
+            article_id: ArticleId::from_persisted("hn-1".into()),
+            title: "Story".into(),
+            url: "http://a".into(),
+            source: "hn".into(),
+            score: 1.5,
+        }],
+        trends: vec![BriefingTrend { topic: "rust".into(), volume: 3, velocity: 0.5 }],
+        nudges: vec![],
+        muted: 0,
+    };
+
+    repo.save(&briefing("u1", 1)).await.unwrap();
+    repo.save(&briefing("u1", 3)).await.unwrap();
+    repo.save(&briefing("u1", 2)).await.unwrap();
+    repo.save(&briefing("u2", 3)).await.unwrap();
+
+    let found = repo
+        .find(&UserId::new("u1"), NaiveDate::from_ymd_opt(2024, 3, 2).unwrap())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found, briefing("u1", 2));
+    assert!(repo
+        .find(&UserId::new("u1"), NaiveDate::from_ymd_opt(2024, 3, 9).unwrap())
+        .await
+        .unwrap()
+        .is_none());
+
+    let archive = repo.list_for_user(&UserId::new("u1"), 2).await.unwrap();
+    let days: Vec<u32> = archive.iter().map(|b| b.date.day()).collect();
+    assert_eq!(days, vec![3, 2]);
+}
+
+#[tokio::test]
+async fn test_sqlite_delivery_log_and_subscribers() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let user_repo = SqliteUserRepo::new(pool.clone());
+    let mut subscriber = UserProfile::new(UserId::new("u2"));
+    subscriber.settings.time_zone = "Europe/Berlin".into();
+    subscriber.settings.delivery = DeliveryPreferences {
+        enabled: true,
+        email: Some("ada@example.com".into()),
+        send_at: "06:30".into(),
+        format: "text".into(),
+    };
+    user_repo.save(&subscriber).await.unwrap();
+    user_repo.save(&UserProfile::new(UserId::new("u1"))).await.unwrap();
+
+    let users = user_repo.list_all().await.unwrap();
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[1].settings.delivery, subscriber.settings.delivery);
+    assert_eq!(users[1].settings.time_zone, "Europe/Berlin");
+
+    let repo = SqliteDeliveryRepo::new(pool);
+    let day = |d: u32| NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+    let mut first = BriefingDelivery::new(UserId::new("u2"), day(1), "ada@example.com");
+    first.state.record_success(100);
+    let mut second = BriefingDelivery::new(UserId::new("u2"), day(2), "ada@example.com");
+    second.state.record_failure("SMTP error: timeout", 200, &RetryPolicy::default());
+    repo.save(&first).await.unwrap();
+    repo.save(&second).await.unwrap();
+
+    let found = repo.find(&UserId::new("u2"), day(2)).await.unwrap().unwrap();
+    assert_eq!(found, second);
+    assert_eq!(found.state.status, DeliveryStatus::Retrying);
+
+    // Later attempts replace the entry for the day
+    second.state.record_success(600);
+    repo.save(&second).await.unwrap();
+    let log = repo.list_for_user(&UserId::new("u2"), 10).await.unwrap();
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0].date, day(2));
+    assert_eq!(log[0].state.status, DeliveryStatus::Sent);
+    assert_eq!(log[0].state.attempts, 2);
+}
+
+#[tokio::test]
+async fn test_sqlite_webhook_subscriptions_and_retry_queue() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+    let repo = SqliteWebhookRepo::new(pool);
+
+    let subscription = WebhookSubscription::new(
+        "https://hooks.example.com/tp",
+        "0123456789abcdef",
+        vec![WebhookEventType::TrendSpike, WebhookEventType::ArticleMatched],
+        &["Rust".to_string()],
+        100,
+    )
+    .unwrap();
+    repo.save_subscription(&subscription).await.unwrap();
+    assert_eq!(repo.find_subscription(&subscription.id).await.unwrap(), Some(subscription.clone()));
+    assert_eq!(repo.list_subscriptions().await.unwrap().len(), 1);
+
+    let event = WebhookEvent::new(
+        WebhookData::TrendSpike {
+            keyword: "rust".into(),
+            volume: 8,
+            previous_volume: 2,
+        },
+        150,
+    );
+    let mut pending = WebhookDelivery::new(subscription.id.clone(), event.clone(), 200);
+    let mut retrying = WebhookDelivery::new(subscription.id.clone(), event.clone(), 210);
+    retrying.response_status = Some(503);
+    retrying.state.record_failure("HTTP 503", 210, &RetryPolicy::default());
+    repo.save_delivery(&pending).await.unwrap();
+    repo.save_delivery(&retrying).await.unwrap();
+
+    // Only the never-attempted delivery is due until the backoff elapses
+    let due = repo.list_due_deliveries(300, 10).await.unwrap();
+    assert_eq!(due, vec![pending.clone()]);
+    let later = retrying.state.next_attempt_at.unwrap();
+    assert_eq!(repo.list_due_deliveries(later, 10).await.unwrap().len(), 2);
+
+    pending.state.record_success(301);
+    pending.response_status = Some(200);
+    repo.save_delivery(&pending).await.unwrap();
+    let log = repo.list_deliveries(&subscription.id, 10).await.unwrap();
+    assert_eq!(log.len(), 2);
+    assert_eq!(log[0], retrying); // Newest first
+    assert_eq!(log[1].state.status, DeliveryStatus::Sent);
+    assert_eq!(log[1].event, event);
+
+    repo.delete_subscription(&subscription.id).await.unwrap();
+    assert!(repo.find_subscription(&subscription.id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_sqlite_watchlist_and_alerts() {
+    let pool = SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+
+    let user_repo = SqliteUserRepo::new(pool.clone());
+    let mut user = UserProfile::new(UserId::new("u1"));
+    let rule = WatchRule::new("sqlite", "domain:sqlite.org OR \"sqlite 4\"", 10).unwrap();
+    user.add_watch_rule(rule.clone()).unwrap();
+    user_repo.save(&user).await.unwrap();
+    let mute = MuteRule::new(MuteKind::Regex, r"bitcoin.*price", Some(3600), 10).unwrap();
+    user.add_mute(mute.clone(), 10).unwrap();
+    user_repo.save(&user).await.unwrap();
+    let found = user_repo.find_by_id(&UserId::new("u1")).await.unwrap().unwrap();
+    assert_eq!(found.watchlist, vec![rule.clone()]);
+    assert_eq!(found.mutes, vec![mute]);
+
+    let repo = SqliteAlertRepo::new(pool);
+    let article = Article::new(Source::HackerNews, "1", "SQLite 4".into(), "https://sqlite.org".into(), 0).unwrap();
+    let other = Article::new(Source::HackerNews, "2", "SQLite 4 again".into(), "https://sqlite.org/2".into(), 0).unwrap();
+    assert!(repo.save(&WatchAlert::new(user.id.clone(), &rule, &article, 100)).await.unwrap());
+    assert!(repo.save(&WatchAlert::new(user.id.clone(), &rule, &other, 200)).await.unwrap());
+    // The first match of a rule and article wins
+    assert!(!repo.save(&WatchAlert::new(user.id.clone(), &rule, &article, 300)).await.unwrap());
+
+    let alerts = repo.list_for_user(&user.id, 10).await.unwrap();
+    assert_eq!(alerts.len(), 2);
+    assert_eq!(alerts[0].article_id, other.id);
+    assert_eq!(alerts[1].matched_at, 100);
+    assert_eq!(alerts[1].source, "hn");
+    assert!(repo.list_for_user(&UserId::new("u2"), 10).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_sqlite_full_text_search_stays_in_sync() {
+    let pool = SqlitePoolOptions::new().connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+    let repo = SqliteArticleRepo::new(pool);
+
+    let mut rust = Article::new(Source::HackerNews, "1", "Async Rust in production".into(), "".into(), 100).unwrap();
+    rust.text = "We moved every service to tokio and never looked back.".into();
+    let mut sqlite = Article::new(Source::HackerNews, "2", "SQLite internals".into(), "".into(), 200).unwrap();
+    sqlite.tags.insert("databases".into());
+    sqlite.author = "drh".into();
+    for article in [&rust, &sqlite] {
+        repo.save(article).await.unwrap();
+    }
+    let search = |q: &str| {
+        let query: SearchQuery = q.parse().unwrap();
+        let repo = repo.clone();
+        async move { repo.search(&query, 10).await.unwrap() }
+    };
+    let ids = |hits: &[techpulse_domain::search::SearchHit]| hits.iter().map(|h| h.article.id.to_string()).collect::<Vec<_>>();
+
+    let hits = search("tokio").await;
+    assert_eq!(ids(&hits), vec!["hn-1"]);
+    assert_eq!(hits[0].article.text, rust.text);
+    assert!(hits[0].snippet.contains("<mark>tokio</mark>"), "{}", hits[0].snippet);
+    assert_eq!(ids(&search("\"async rust\"").await), vec!["hn-1"]);
+    assert!(search("\"rust async\"").await.is_empty());
+    assert_eq!(ids(&search("datab*").await), vec!["hn-2"]);
+    assert_eq!(ids(&search("drh").await), vec!["hn-2"]);
+    // Quoted words, so FTS5 syntax in the input is inert
+    assert!(search("NEAR(sqlite internals) OR").await.is_empty());
+
+    // Title matches outrank body matches
+    let mut body_only = Article::new(Source::HackerNews, "3", "Release notes".into(), "".into(), 300).unwrap();
+    body_only.text = "Internals of the new SQLite query planner".into();
+    repo.save(&body_only).await.unwrap();
+    let hits = search("sqlite").await;
+    assert_eq!(ids(&hits), vec!["hn-2", "hn-3"]);
+    assert!(hits[0].relevance > hits[1].relevance);
+
+    // Re-saving an article re-indexes what the merge filled in, but keeps its title
+    rust.title = "Go in production".into();
+    rust.tags.insert("concurrency".into());
+    repo.save(&rust).await.unwrap();
+    assert_eq!(ids(&search("concurrency").await), vec!["hn-1"]);
+    assert!(search("go").await.is_empty());
+    assert_eq!(ids(&search("tokio").await), vec!["hn-1"]);
+}
+
+#[tokio::test]
+async fn test_sqlite_snapshots_series() {
+    let pool = SqlitePoolOptions::new().connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+    let repo = SqliteSnapshotRepo::new(pool);
+
+    let mut article = Article::new(Source::HackerNews, "1", "Rust 2.0".into(), "".into(), 100).unwrap();
+    let other = Article::new(Source::HackerNews, "2", "Go 1.23".into(), "".into(), 100).unwrap();
+    article.comment_count = 3;
+    repo.append(&[ArticleSnapshot::of(&article, 200), ArticleSnapshot::of(&other, 200)]).await.unwrap();
+    article.comment_count = 9;
+    article.score = 35.5;
+    article.is_hot_on_source = true;
+    repo.append(&[ArticleSnapshot::of(&article, 300)]).await.unwrap();
+    // Same ingest time replaces rather than duplicates
+    article.comment_count = 10;
+    repo.append(&[ArticleSnapshot::of(&article, 300)]).await.unwrap();
+
+    let series = repo.list_for_article(&article.id).await.unwrap();
+    assert_eq!(series.len(), 2);
+    assert_eq!(series[0].captured_at, 200);
+    assert_eq!(series[0].comment_count, 3);
+    assert_eq!(series[1], ArticleSnapshot::of(&article, 300));
+    assert!(repo.list_for_article(&ArticleId::parse("hn-3").unwrap()).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_sqlite_save_many_is_atomic() {
+    let pool = SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../../migrations").run(&pool).await.unwrap();
+    let repo = SqliteArticleRepo::new(pool.clone());
+
+    let article = |id: &str, score: f64| {
+        let mut a = Article::new(Source::HackerNews, id, format!("Story {}", id), "".into(), 100).unwrap().seen_at(1000);
+        a.score = score;
+        a
+    };
+    repo.save_many(&[article("1", 10.0), article("2", 20.0)]).await.unwrap();
+
+    // Fail the batch halfway: neither the update before nor the insert after the bad row sticks
+    sqlx::query(
+        "CREATE TRIGGER reject_bad BEFORE INSERT ON articles WHEN NEW.id = 'hn-bad' BEGIN SELECT RAISE(ABORT, 'rejected'); END",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let result = repo.save_many(&[article("2", 99.0), article("bad", 0.0), article("3", 30.0)]).await;
+    assert!(matches!(result, Err(DomainError::Repository(_))));
+
+    assert_eq!(repo.find_by_id(&article("2", 0.0).id).await.unwrap().unwrap().score, 20.0);
+    assert!(repo.find_by_id(&article("3", 0.0).id).await.unwrap().is_none());
+    let search: SearchQuery = "story".parse().unwrap();
+    assert_eq!(repo.search(&search, 10).await.unwrap().len(), 2); // Index rolled back too
+}
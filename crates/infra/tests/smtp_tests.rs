@@ -0,0 +1,125 @@
+use std::sync::{Arc, Mutex};
+use techpulse_domain::delivery::EmailMessage;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::gateway::EmailGateway;
+use techpulse_infra::email::{SmtpConfig, SmtpEmailGateway, SmtpSecurity};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// Minimal SMTP server that records every message it accepts.
+/// With `reject` set, it refuses every sender so delivery fails.
+async fn start_sink(reject: bool) -> (u16, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let messages = Arc::new(Mutex::new(Vec::new()));
+
+    let received = messages.clone();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            let received = received.clone();
+            tokio::spawn(async move {
+                let (read, mut write) = stream.into_split();
+                let mut lines = BufReader::new(read).lines();
+                write.write_all(b"220 sink ESMTP\r\n").await.unwrap();
+
+                let mut data: Option<String> = None;
+                while let Ok(Some(line)) = lines.next_line().await {
+                    if let Some(body) = data.as_mut() {
+                        if line == "." {
+                            received.lock().unwrap().push(data.take().unwrap());
+                            write.write_all(b"250 queued\r\n").await.unwrap();
+                        } else {
+                            body.push_str(&line);
+                            body.push('\n');
+                        }
+                        continue;
+                    }
+
+                    let command = line.to_uppercase();
+                    let reply: &[u8] = if command.starts_with("EHLO") || command.starts_with("HELO") {
+                        b"250 sink\r\n"
+                    } else if command.starts_with("MAIL") && reject {
+                        b"550 mailbox unavailable\r\n"
+                    } else if command.starts_with("DATA") {
+                        data = Some(String::new());
+                        b"354 end with .\r\n"
+                    } else if command.starts_with("QUIT") {
+                        write.write_all(b"221 bye\r\n").await.unwrap();
+                        break;
+                    } else {
+                        b"250 ok\r\n"
+                    };
+                    write.write_all(reply).await.unwrap();
+                }
+            });
+        }
+    });
+
+    (port, messages)
+}
+
+fn gateway(port: u16) -> SmtpEmailGateway {
+    let mut config = SmtpConfig::new("127.0.0.1", "Tech Pulse <briefing@techpulse.test>");
+    config.port = port;
+    config.security = SmtpSecurity::Plain;
+    SmtpEmailGateway::new(config).unwrap()
+}
+
+fn message(html: Option<&str>) -> EmailMessage {
+    EmailMessage {
+        to: "ada@example.com".into(),
+        subject: "Your Tech Pulse briefing".into(),
+        text_body: "Top stories: Rust ships a new release".into(),
+        html_body: html.map(|h| h.to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_smtp_sends_multipart_briefing() {
+    let (port, messages) = start_sink(false).await;
+
+    gateway(port)
+        .send(&message(Some("<h1>Top stories</h1>")))
+        .await
+        .unwrap();
+
+    let messages = messages.lock().unwrap();
+    assert_eq!(messages.len(), 1);
+    let raw = &messages[0];
+    assert!(raw.contains("To: ada@example.com"));
+    assert!(raw.contains("Subject: Your Tech Pulse briefing"));
+    assert!(raw.contains("multipart/alternative"));
+    assert!(raw.contains("Top stories: Rust ships a new release"));
+    assert!(raw.contains("<h1>Top stories</h1>"));
+}
+
+#[tokio::test]
+async fn test_smtp_sends_plain_text_only() {
+    let (port, messages) = start_sink(false).await;
+
+    gateway(port).send(&message(None)).await.unwrap();
+
+    let messages = messages.lock().unwrap();
+    assert!(messages[0].contains("Content-Type: text/plain"));
+    assert!(!messages[0].contains("multipart"));
+}
+
+#[tokio::test]
+async fn test_smtp_rejection_is_a_gateway_error() {
+    let (port, messages) = start_sink(true).await;
+
+    let result = gateway(port).send(&message(None)).await;
+
+    assert!(matches!(result, Err(DomainError::Gateway(_))), "got {:?}", result);
+    assert!(messages.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_smtp_invalid_recipient() {
+    let mut bad = message(None);
+    bad.to = "not an address".into();
+
+    // Rejected before connecting, so no sink is needed
+    let result = gateway(1).send(&bad).await;
+    assert!(matches!(result, Err(DomainError::Validation(_))));
+}
@@ -0,0 +1,90 @@
+use techpulse_domain::error::DomainError;
+use techpulse_domain::gateway::WebhookGateway;
+use techpulse_domain::webhook::{WebhookData, WebhookDelivery, WebhookEvent, WebhookEventType, WebhookSubscription};
+use techpulse_infra::webhook::{sign, HttpWebhookGateway};
+use std::time::Duration;
+use wiremock::matchers::{header, header_exists, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SECRET: &str = "0123456789abcdef";
+
+fn delivery(url: &str) -> (WebhookSubscription, WebhookDelivery) {
+    let subscription = WebhookSubscription::new(url, SECRET, vec![WebhookEventType::TopicEmerging], &[], 0).unwrap();
+    let event = WebhookEvent::new(
+        WebhookData::TopicEmerging {
+            keyword: "zig".into(),
+            volume: 5,
+        },
+        10,
+    );
+    let delivery = WebhookDelivery::new(subscription.id.clone(), event, 20);
+    (subscription, delivery)
+}
+
+#[test]
+fn test_sign_is_stable_hmac_sha256() {
+    // Reference value from `printf '1700000000.{}' | openssl dgst -sha256 -hmac key`
+    assert_eq!(
+        sign("key", 1_700_000_000, "{}"),
+        "sha256=9d713ed406bb7076d4123f0dc2c39d2df5c654ed4b0cd56b52c8b4c940bd63ae"
+    );
+}
+
+#[tokio::test]
+async fn test_posts_signed_event_payload() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hooks"))
+        .and(header("content-type", "application/json"))
+        .and(header("x-techpulse-event", "topic.emerging"))
+        .and(header_exists("x-techpulse-signature"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let (subscription, delivery) = delivery(&format!("{}/hooks", server.uri()));
+    let gateway = HttpWebhookGateway::new(Duration::from_secs(5)).unwrap();
+    let status = gateway.post(&subscription, &delivery).await.unwrap();
+    assert_eq!(status, 202);
+
+    let requests = server.received_requests().await.unwrap();
+    let request = &requests[0];
+    let body = String::from_utf8(request.body.clone()).unwrap();
+    let header_value = |name: &str| request.headers.get(name).unwrap().to_str().unwrap().to_string();
+
+    // Receivers can verify the body with the shared secret and the timestamp header
+    let timestamp: i64 = header_value("x-techpulse-timestamp").parse().unwrap();
+    assert_eq!(header_value("x-techpulse-signature"), sign(SECRET, timestamp, &body));
+    assert_eq!(header_value("x-techpulse-delivery"), delivery.id);
+
+    let payload: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(payload["type"], "topic.emerging");
+    assert_eq!(payload["data"]["keyword"], "zig");
+    assert_eq!(payload["id"], delivery.event.id.as_str());
+}
+
+#[tokio::test]
+async fn test_error_statuses_are_returned_not_raised() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&server)
+        .await;
+
+    let (subscription, delivery) = delivery(&server.uri());
+    let gateway = HttpWebhookGateway::new(Duration::from_secs(5)).unwrap();
+    assert_eq!(gateway.post(&subscription, &delivery).await.unwrap(), 500);
+}
+
+#[tokio::test]
+async fn test_unreachable_endpoint_is_a_gateway_error() {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+
+    let (subscription, delivery) = delivery(&format!("http://127.0.0.1:{}/", port));
+    let gateway = HttpWebhookGateway::new(Duration::from_secs(5)).unwrap();
+    let result = gateway.post(&subscription, &delivery).await;
+    assert!(matches!(result, Err(DomainError::Gateway(_))));
+}
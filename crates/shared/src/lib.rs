@@ -1,5 +1,5 @@
 // Shared utilities
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 
 #[derive(Debug, thiserror::Error)]
 pub enum AppError {
@@ -0,0 +1,55 @@
+use std::collections::{BTreeMap, HashMap};
+use techpulse_domain::article::Article;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::ArticleRepo;
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::TopicTaxonomy;
+
+use crate::cooccurrence::{article_topics, articles_in_window};
+
+/// How busy a topic is in a window, compared with the window of equal length before it.
+#[derive(Debug, Clone)]
+pub struct TopicActivity {
+    pub volume: u32,
+    pub velocity: f64,         // (current - previous) / max(previous, 1)
+    pub articles: Vec<Article>, // Published in the current window, highest scoring first
+}
+
+/// Activity of every topic mentioned in `window`, keyed by canonical slug.
+pub async fn measure_topic_activity(
+    article_repo: &dyn ArticleRepo,
+    taxonomy: &TopicTaxonomy,
+    window: TimeWindow,
+    now: i64,
+) -> Result<BTreeMap<String, TopicActivity>, DomainError> {
+    let previous = TimeWindow::new(window.start - window.duration_secs(), window.start)?;
+
+    let mut current: BTreeMap<String, Vec<Article>> = BTreeMap::new();
+    let mut previous_counts: HashMap<String, u32> = HashMap::new();
+    let both = TimeWindow::new(previous.start, window.end)?;
+    for article in articles_in_window(article_repo, both).await? {
+        let in_current = window.contains(article.timestamp);
+        for topic in article_topics(taxonomy, &article.tags, &article.title) {
+            if in_current {
+                current.entry(topic).or_default().push(article.clone());
+            } else {
+                *previous_counts.entry(topic).or_insert(0) += 1;
+            }
+        }
+    }
+
+    Ok(current
+        .into_iter()
+        .map(|(topic, mut articles)| {
+            let volume = articles.len() as u32;
+            let before = previous_counts.get(&topic).copied().unwrap_or(0);
+            articles.sort_by(|a, b| b.calculate_score(now).total_cmp(&a.calculate_score(now)));
+            let activity = TopicActivity {
+                volume,
+                velocity: (volume as f64 - before as f64) / before.max(1) as f64,
+                articles,
+            };
+            (topic, activity)
+        })
+        .collect())
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use techpulse_domain::article::{Article, ArticleId, ArticleSnapshot};
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{ArticleRepo, SnapshotRepo};
+
+#[derive(Debug, Clone)]
+pub struct ArticleDetail {
+    pub article: Article,
+    pub score: f64, // Decayed ranking score at request time
+    pub history: Vec<ArticleSnapshot>, // Oldest first
+}
+
+pub struct GetArticleDetail {
+    article_repo: Arc<dyn ArticleRepo>,
+    snapshot_repo: Arc<dyn SnapshotRepo>,
+}
+
+impl GetArticleDetail {
+    pub fn new(article_repo: Arc<dyn ArticleRepo>, snapshot_repo: Arc<dyn SnapshotRepo>) -> Self {
+        Self {
+            article_repo,
+            snapshot_repo,
+        }
+    }
+
+    pub async fn execute(&self, id: &ArticleId, now: i64) -> Result<ArticleDetail, DomainError> {
+        let article = self
+            .article_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Article {} not found", id)))?;
+        let history = self.snapshot_repo.list_for_article(id).await?;
+
+        Ok(ArticleDetail {
+            score: article.calculate_score(now),
+            article,
+            history,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::article::{SaveOutcome, Source};
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub SnapshotRepo {}
+        #[async_trait]
+        impl SnapshotRepo for SnapshotRepo {
+            async fn append(&self, snapshots: &[ArticleSnapshot]) -> Result<(), DomainError>;
+            async fn list_for_article(&self, article_id: &ArticleId) -> Result<Vec<ArticleSnapshot>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detail_includes_score_and_history() {
+        let mut article = Article::new(Source::HackerNews, "1", "Rust 2.0".into(), "".into(), 1000).unwrap();
+        article.score = 50.0;
+        let history: Vec<ArticleSnapshot> = [(1100, 10.0), (1200, 50.0)]
+            .iter()
+            .map(|(at, score)| ArticleSnapshot { score: *score, ..ArticleSnapshot::of(&article, *at) })
+            .collect();
+
+        let mut article_repo = MockArticleRepo::new();
+        let stored = article.clone();
+        article_repo.expect_find_by_id().returning(move |id| Ok((id == &stored.id).then(|| stored.clone())));
+        let mut snapshot_repo = MockSnapshotRepo::new();
+        let series = history.clone();
+        snapshot_repo.expect_list_for_article().returning(move |_| Ok(series.clone()));
+        let usecase = GetArticleDetail::new(Arc::new(article_repo), Arc::new(snapshot_repo));
+
+        let detail = usecase.execute(&article.id, 1000 + 3600).await.unwrap();
+        assert_eq!(detail.history, history);
+        assert_eq!(detail.score, article.calculate_score(1000 + 3600));
+
+        let missing = ArticleId::parse("hn-2").unwrap();
+        assert!(matches!(usecase.execute(&missing, 0).await, Err(DomainError::NotFound(_))));
+    }
+}
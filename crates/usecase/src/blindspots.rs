@@ -0,0 +1,242 @@
+use std::sync::Arc;
+use techpulse_domain::article::{Article, ArticleId};
+use techpulse_domain::blindspot::{BlindSpot, BlindSpotPolicy};
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{ArticleRepo, TopicRepo, UserRepo};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::user::UserId;
+
+use crate::activity::measure_topic_activity;
+use crate::topics::load_taxonomy;
+
+#[derive(Debug, Clone)]
+pub struct BlindSpotView {
+    pub blind_spot: BlindSpot,
+    pub samples: Vec<Article>,
+}
+
+pub struct GetBlindSpots {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    user_repo: Arc<dyn UserRepo>,
+    policy: BlindSpotPolicy,
+}
+
+impl GetBlindSpots {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        user_repo: Arc<dyn UserRepo>,
+        policy: BlindSpotPolicy,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            user_repo,
+            policy,
+        }
+    }
+
+    /// Active topics in `window` the user does not know, most severe first.
+    /// Velocity compares against the window of equal length right before it.
+    /// Users without a profile are treated as knowing nothing yet.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        window: TimeWindow,
+        now: i64,
+        limit: usize,
+        samples_per_topic: usize,
+    ) -> Result<Vec<BlindSpotView>, DomainError> {
+        let profile = self.user_repo.find_by_id(user_id).await?;
+        let mutes = profile.as_ref().map(|p| p.mute_filter(now));
+        let knowledge = profile.map(|u| u.knowledge).unwrap_or_default();
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let activity = measure_topic_activity(self.article_repo.as_ref(), &taxonomy, window, now).await?;
+
+        let mut views: Vec<BlindSpotView> = Vec::new();
+        for (topic, activity) in activity {
+            let Some(severity) = self
+                .policy
+                .severity(&knowledge, &topic, activity.volume, activity.velocity)
+            else {
+                continue;
+            };
+
+            let related_articles: Vec<ArticleId> = activity.articles.iter().map(|a| a.id.clone()).collect();
+            // Muted articles still count towards the topic but are never shown
+            let samples = activity
+                .articles
+                .into_iter()
+                .filter(|a| mutes.as_ref().is_none_or(|m| m.muted_by(a).is_none()))
+                .take(samples_per_topic)
+                .collect();
+
+            views.push(BlindSpotView {
+                blind_spot: BlindSpot {
+                    state: knowledge.topics.get(&topic).map(|k| k.state.clone()),
+                    topic,
+                    volume: activity.volume,
+                    velocity: activity.velocity,
+                    severity,
+                    related_articles,
+                },
+                samples,
+            });
+        }
+
+        views.sort_by(|a, b| {
+            b.blind_spot
+                .severity
+                .total_cmp(&a.blind_spot.severity)
+                .then_with(|| a.blind_spot.topic.cmp(&b.blind_spot.topic))
+        });
+        views.truncate(limit);
+        Ok(views)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{SaveOutcome, Source};
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+    use techpulse_domain::user::{KnowledgeState, UserProfile};
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    fn article(id: &str, title: &str, score: f64, timestamp: i64) -> Article {
+        let mut a = Article::new(Source::HackerNews, id, title.into(), "".into(), timestamp).unwrap();
+        a.score = score;
+        a
+    }
+
+    #[tokio::test]
+    async fn test_ranks_unknown_active_topics() {
+        let now = 10_000;
+        let articles = vec![
+            // Rust: busy but known
+            article("1", "Rust 2.0", 10.0, now - 100),
+            article("2", "Rust async", 10.0, now - 200),
+            article("3", "Rust embedded", 10.0, now - 300),
+            // Kubernetes: busy and growing, never touched
+            article("4", "Kubernetes 2.0", 50.0, now - 100),
+            article("5", "Kubernetes at scale", 90.0, now - 200),
+            article("6", "Kubernetes operators", 10.0, now - 300),
+            // Go: busy, only heard of, flat against the previous window
+            article("7", "Go generics", 10.0, now - 100),
+            article("8", "Go 2", 10.0, now - 200),
+            article("9", "Go modules", 10.0, now - 300),
+            article("10", "Go tooling", 10.0, now - 1100),
+            article("11", "Go errors", 10.0, now - 1200),
+            article("12", "Go runtime", 10.0, now - 1300),
+            // Python: too quiet
+            article("13", "Python 4", 10.0, now - 100),
+        ];
+
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        profile.update_knowledge("rust", KnowledgeState::KnowIt, 0);
+        profile.update_knowledge("go", KnowledgeState::HeardOf, 0);
+
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_query().returning(move |q| {
+            let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+            Ok(ArticlePage { articles, next_cursor: None })
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(profile.clone())));
+
+        let usecase = GetBlindSpots::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            Arc::new(mock_user_repo),
+            BlindSpotPolicy::default(),
+        );
+        let window = TimeWindow::ending_at(now, 1000).unwrap();
+        let spots = usecase.execute(&UserId::from("u1"), window, now, 10, 2).await.unwrap();
+
+        let topics: Vec<&str> = spots.iter().map(|s| s.blind_spot.topic.as_str()).collect();
+        assert_eq!(topics, vec!["cloud", "go"]);
+
+        let cloud = &spots[0];
+        assert_eq!(cloud.blind_spot.state, None);
+        assert_eq!(cloud.blind_spot.volume, 3);
+        assert_eq!(cloud.blind_spot.velocity, 3.0);
+        assert_eq!(cloud.samples.len(), 2);
+        assert_eq!(cloud.samples[0].id.to_string(), "hn-5"); // Highest scoring sample first
+
+        assert_eq!(spots[1].blind_spot.state, Some(KnowledgeState::HeardOf));
+        assert_eq!(spots[1].blind_spot.velocity, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_user_sees_all_active_topics() {
+        let now = 10_000;
+        let articles: Vec<Article> = (0..3)
+            .map(|i| article(&i.to_string(), "Rust news", 10.0, now - 10))
+            .collect();
+
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_query().returning(move |q| {
+            let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+            Ok(ArticlePage { articles, next_cursor: None })
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(|_| Ok(None));
+
+        let usecase = GetBlindSpots::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            Arc::new(mock_user_repo),
+            BlindSpotPolicy::default(),
+        );
+        let window = TimeWindow::ending_at(now, 1000).unwrap();
+        let spots = usecase.execute(&UserId::from("new"), window, now, 10, 3).await.unwrap();
+
+        assert_eq!(spots.len(), 1);
+        assert_eq!(spots[0].blind_spot.topic, "rust");
+    }
+}
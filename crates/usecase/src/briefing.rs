@@ -0,0 +1,319 @@
+use chrono::NaiveDate;
+use std::sync::Arc;
+use techpulse_domain::article::Article;
+use techpulse_domain::briefing::{Briefing, BriefingLimits, BriefingNudge, BriefingStory, BriefingTrend};
+use techpulse_domain::error::DomainError;
+use techpulse_domain::query::ArticleQuery;
+use techpulse_domain::repository::{ArticleRepo, BriefingRepo, TopicRepo, UserRepo};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::user::UserId;
+
+use crate::activity::measure_topic_activity;
+use crate::blindspots::GetBlindSpots;
+use crate::feed::PersonalizeFeed;
+use crate::topics::load_taxonomy;
+
+const BRIEFING_WINDOW_SECS: i64 = 24 * 3600;
+
+pub struct GenerateBriefing {
+    feed: Arc<PersonalizeFeed>,
+    blind_spots: Arc<GetBlindSpots>,
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    user_repo: Arc<dyn UserRepo>,
+    briefing_repo: Arc<dyn BriefingRepo>,
+    limits: BriefingLimits,
+}
+
+impl GenerateBriefing {
+    pub fn new(
+        feed: Arc<PersonalizeFeed>,
+        blind_spots: Arc<GetBlindSpots>,
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        user_repo: Arc<dyn UserRepo>,
+        briefing_repo: Arc<dyn BriefingRepo>,
+        limits: BriefingLimits,
+    ) -> Self {
+        Self {
+            feed,
+            blind_spots,
+            article_repo,
+            topic_repo,
+            user_repo,
+            briefing_repo,
+            limits,
+        }
+    }
+
+    /// Returns the user's briefing for `date` (today if None), generating and storing it on first request.
+    /// "Today" is the user's local date. Past days are only served from the archive; they are never
+    /// generated after the fact.
+    pub async fn execute(&self, user_id: &UserId, date: Option<NaiveDate>, now: i64) -> Result<Briefing, DomainError> {
+        let settings = self.user_repo.find_by_id(user_id).await?.map(|u| u.settings).unwrap_or_default();
+        let today = settings.local_date(now)?;
+        let date = date.unwrap_or(today);
+        if date > today {
+            return Err(DomainError::Validation(format!("No briefing for future date {}", date)));
+        }
+        if let Some(existing) = self.briefing_repo.find(user_id, date).await? {
+            return Ok(existing);
+        }
+        if date < today {
+            return Err(DomainError::NotFound(format!("Briefing of '{}' for {}", user_id, date)));
+        }
+
+        let window = TimeWindow::ending_at(now, BRIEFING_WINDOW_SECS)?;
+
+        let feed = self.feed.execute(user_id, ArticleQuery::latest(self.limits.stories), now).await?;
+        let stories = feed.articles.iter().map(|r| story(&r.article, r.score)).collect();
+
+        let nudges = self
+            .blind_spots
+            .execute(user_id, window, now, self.limits.nudges, 1)
+            .await?
+            .into_iter()
+            .map(|view| BriefingNudge {
+                story: view
+                    .samples
+                    .first()
+                    .map(|a| story(a, a.calculate_score(now))),
+                topic: view.blind_spot.topic,
+                severity: view.blind_spot.severity,
+            })
+            .collect();
+
+        let briefing = Briefing {
+            user_id: user_id.clone(),
+            date,
+            generated_at: now,
+            stories,
+            trends: self.top_trends(window, now).await?,
+            nudges,
+            muted: feed.muted.hidden,
+        };
+        self.briefing_repo.save(&briefing).await?;
+
+        Ok(briefing)
+    }
+
+    // Busiest topics of the window, growth counting as extra volume
+    async fn top_trends(&self, window: TimeWindow, now: i64) -> Result<Vec<BriefingTrend>, DomainError> {
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let activity = measure_topic_activity(self.article_repo.as_ref(), &taxonomy, window, now).await?;
+
+        let mut trends: Vec<BriefingTrend> = activity
+            .into_iter()
+            .map(|(topic, a)| BriefingTrend {
+                topic,
+                volume: a.volume,
+                velocity: a.velocity,
+            })
+            .collect();
+        let momentum = |t: &BriefingTrend| t.volume as f64 * (1.0 + t.velocity.max(0.0));
+        trends.sort_by(|a, b| momentum(b).total_cmp(&momentum(a)).then_with(|| a.topic.cmp(&b.topic)));
+        trends.truncate(self.limits.trends);
+        Ok(trends)
+    }
+}
+
+fn story(article: &Article, score: f64) -> BriefingStory {
+    BriefingStory {
+        article_id: article.id.clone(),
+        title: article.title.clone(),
+        url: article.url.clone(),
+        source: article.source.to_string(),
+        score,
+    }
+}
+
+pub struct ListBriefings {
+    briefing_repo: Arc<dyn BriefingRepo>,
+}
+
+impl ListBriefings {
+    pub fn new(briefing_repo: Arc<dyn BriefingRepo>) -> Self {
+        Self { briefing_repo }
+    }
+
+    /// Archive of past briefings, newest first.
+    pub async fn execute(&self, user_id: &UserId, limit: usize) -> Result<Vec<Briefing>, DomainError> {
+        self.briefing_repo.list_for_user(user_id, limit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::ArticlePage;
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{ArticleId, SaveOutcome, Source};
+    use techpulse_domain::blindspot::BlindSpotPolicy;
+    use techpulse_domain::personalization::PersonalizationWeights;
+    use techpulse_domain::repository::UserRepo;
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+    use techpulse_domain::user::{KnowledgeState, UserProfile};
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub BriefingRepo {}
+        #[async_trait]
+        impl BriefingRepo for BriefingRepo {
+            async fn save(&self, briefing: &Briefing) -> Result<(), DomainError>;
+            async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<Briefing>, DomainError>;
+            async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<Briefing>, DomainError>;
+        }
+    }
+
+    // 2024-03-01T12:00:00Z
+    const NOW: i64 = 1709294400;
+
+    fn generator(briefing_repo: MockBriefingRepo) -> GenerateBriefing {
+        let articles: Vec<Article> = (0..8)
+            .map(|i| {
+                let title = if i < 5 { format!("Rust story {}", i) } else { format!("Kubernetes story {}", i) };
+                let mut a = Article::new(Source::HackerNews, &i.to_string(), title, "".into(), NOW - 600).unwrap();
+                a.score = 10.0 + i as f64;
+                a
+            })
+            .collect();
+        let article_repo: Arc<dyn ArticleRepo> = {
+            let mut mock = MockArticleRepo::new();
+            let page = ArticlePage { articles, next_cursor: None };
+            mock.expect_query().returning(move |_| Ok(page.clone()));
+            Arc::new(mock)
+        };
+        let topic_repo: Arc<dyn TopicRepo> = {
+            let mut mock = MockTopicRepo::new();
+            mock.expect_list_all().returning(|| Ok(default_topics()));
+            Arc::new(mock)
+        };
+        let user_repo: Arc<dyn UserRepo> = {
+            let mut profile = UserProfile::new(UserId::from("u1"));
+            profile.update_knowledge("rust", KnowledgeState::KnowIt, NOW);
+            let mut mock = MockUserRepo::new();
+            mock.expect_find_by_id().returning(move |_| Ok(Some(profile.clone())));
+            Arc::new(mock)
+        };
+
+        let feed = Arc::new(PersonalizeFeed::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            user_repo.clone(),
+            PersonalizationWeights::default(),
+        ));
+        let blind_spots = Arc::new(GetBlindSpots::new(
+            article_repo.clone(),
+            topic_repo.clone(),
+            user_repo.clone(),
+            BlindSpotPolicy::default(),
+        ));
+        GenerateBriefing::new(
+            feed,
+            blind_spots,
+            article_repo,
+            topic_repo,
+            user_repo,
+            Arc::new(briefing_repo),
+            BriefingLimits::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_generates_and_stores_todays_briefing() {
+        let mut mock_briefing_repo = MockBriefingRepo::new();
+        mock_briefing_repo.expect_find().returning(|_, _| Ok(None));
+        mock_briefing_repo.expect_save().times(1).returning(|_| Ok(()));
+
+        let briefing = generator(mock_briefing_repo)
+            .execute(&UserId::from("u1"), None, NOW)
+            .await
+            .unwrap();
+
+        assert_eq!(briefing.date, NaiveDate::from_ymd_opt(2024, 3, 1).unwrap());
+        assert_eq!(briefing.stories.len(), 5);
+        assert_eq!(briefing.trends.len(), 2);
+        assert_eq!(briefing.trends[0].topic, "rust");
+        // Rust is known, so only Kubernetes is nudged
+        assert_eq!(briefing.nudges.len(), 1);
+        assert_eq!(briefing.nudges[0].topic, "cloud");
+        assert!(briefing.nudges[0].story.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_returns_stored_briefing_unchanged() {
+        let stored = Briefing {
+            user_id: UserId::from("u1"),
+            date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            generated_at: NOW - 3600,
+            stories: vec![],
+            trends: vec![],
+            nudges: vec![],
+            muted: 0,
+        };
+        let mut mock_briefing_repo = MockBriefingRepo::new();
+        let returned = stored.clone();
+        mock_briefing_repo.expect_find().returning(move |_, _| Ok(Some(returned.clone())));
+        mock_briefing_repo.expect_save().never();
+
+        let briefing = generator(mock_briefing_repo)
+            .execute(&UserId::from("u1"), None, NOW)
+            .await
+            .unwrap();
+        assert_eq!(briefing, stored);
+    }
+
+    #[tokio::test]
+    async fn test_past_and_future_dates() {
+        let mut mock_briefing_repo = MockBriefingRepo::new();
+        mock_briefing_repo.expect_find().returning(|_, _| Ok(None));
+        mock_briefing_repo.expect_save().never();
+        let usecase = generator(mock_briefing_repo);
+
+        let past = usecase
+            .execute(&UserId::from("u1"), NaiveDate::from_ymd_opt(2024, 2, 1), NOW)
+            .await;
+        assert!(matches!(past, Err(DomainError::NotFound(_))));
+
+        let future = usecase
+            .execute(&UserId::from("u1"), NaiveDate::from_ymd_opt(2024, 3, 2), NOW)
+            .await;
+        assert!(matches!(future, Err(DomainError::Validation(_))));
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use techpulse_domain::article::Article;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::event::{ClusteringPolicy, EventCluster, EventClusterId, StoryFeatures};
+use techpulse_domain::repository::{ArticleRepo, TopicRepo};
+use techpulse_domain::time::TimeWindow;
+
+use crate::cooccurrence::{article_topics, articles_in_window};
+use crate::topics::load_taxonomy;
+
+pub struct ClusterStories {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    policy: ClusteringPolicy,
+}
+
+impl ClusterStories {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        policy: ClusteringPolicy,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            policy,
+        }
+    }
+
+    /// Groups articles published inside `window` into events, highest scoring first.
+    /// Articles unrelated to anything else become single-article events.
+    pub async fn execute(&self, window: TimeWindow, now: i64) -> Result<Vec<EventCluster>, DomainError> {
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let articles = articles_in_window(self.article_repo.as_ref(), window).await?;
+
+        let stories: Vec<StoryFeatures> = articles
+            .iter()
+            .map(|a| StoryFeatures::new(a, article_topics(&taxonomy, &a.tags, &a.title)))
+            .collect();
+
+        // Union-find over pairwise relations (single-link clustering). Walking articles in time
+        // order, each one is only compared with those less than `max_time_gap_secs` after it.
+        let mut by_time: Vec<usize> = (0..articles.len()).collect();
+        by_time.sort_by_key(|&i| stories[i].timestamp);
+        let mut parent: Vec<usize> = (0..articles.len()).collect();
+        for (n, &i) in by_time.iter().enumerate() {
+            for &j in &by_time[n + 1..] {
+                if stories[j].timestamp - stories[i].timestamp > self.policy.max_time_gap_secs {
+                    break;
+                }
+                if self.policy.are_related(&stories[i], &stories[j]) {
+                    let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                    if ri != rj {
+                        parent[rj] = ri;
+                    }
+                }
+            }
+        }
+
+        let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+        for i in 0..articles.len() {
+            let root = find(&mut parent, i);
+            groups.entry(root).or_default().push(i);
+        }
+
+        let mut clusters: Vec<EventCluster> = groups
+            .into_values()
+            .map(|members| self.build_cluster(&articles, &stories, members, now))
+            .collect();
+        clusters.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.to_string().cmp(&b.id.to_string())));
+
+        Ok(clusters)
+    }
+
+    fn build_cluster(
+        &self,
+        articles: &[Article],
+        stories: &[StoryFeatures],
+        mut members: Vec<usize>,
+        now: i64,
+    ) -> EventCluster {
+        let scores: HashMap<usize, f64> = members
+            .iter()
+            .map(|&i| (i, articles[i].calculate_score(now)))
+            .collect();
+        members.sort_by(|a, b| scores[b].total_cmp(&scores[a]));
+
+        let representative = &articles[members[0]];
+        let seed = members
+            .iter()
+            .map(|&i| &articles[i])
+            .min_by(|a, b| a.timestamp.cmp(&b.timestamp).then_with(|| a.id.to_string().cmp(&b.id.to_string())))
+            .unwrap_or(representative);
+
+        let timestamps: Vec<i64> = members.iter().map(|&i| articles[i].timestamp).collect();
+        let mut sources: Vec<String> = Vec::new();
+        for &i in &members {
+            let source = articles[i].source.to_string();
+            if !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+
+        EventCluster {
+            id: EventClusterId::for_seed(&seed.id),
+            headline: representative.title.clone(),
+            representative: representative.id.clone(),
+            url: representative.url.clone(),
+            articles: members.iter().map(|&i| articles[i].id.clone()).collect(),
+            sources,
+            topics: members.iter().flat_map(|&i| stories[i].topics.iter().cloned()).collect(),
+            first_seen: timestamps.iter().copied().min().unwrap_or(now),
+            last_seen: timestamps.iter().copied().max().unwrap_or(now),
+            score: members.iter().map(|i| scores[i]).sum(),
+            lifecycle: self.policy.lifecycle(&timestamps, now),
+        }
+    }
+}
+
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    // Path compression
+    let mut node = i;
+    while parent[node] != root {
+        let next = parent[node];
+        parent[node] = root;
+        node = next;
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{ArticleId, SaveOutcome, Source};
+    use techpulse_domain::event::EventLifecycle;
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    fn article(source: Source, id: &str, title: &str, score: f64, timestamp: i64) -> Article {
+        let mut a = Article::new(source, id, title.into(), "".into(), timestamp).unwrap();
+        a.score = score;
+        a
+    }
+
+    #[tokio::test]
+    async fn test_clusters_related_articles_across_sources() {
+        let now = 100_000;
+        let articles = [
+            article(Source::HackerNews, "1", "OpenAI releases GPT-5", 80.0, now - 3600),
+            article(Source::Reddit("programming".into()), "2", "GPT-5 released by OpenAI", 40.0, now - 1800),
+            article(Source::GitHub, "3", "OpenAI GPT-5 release notes", 10.0, now - 600),
+            article(Source::HackerNews, "4", "A tour of the SQLite VM", 50.0, now - 900),
+        ];
+
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_query().returning(move |q| {
+            let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+            Ok(ArticlePage { articles, next_cursor: None })
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+
+        let usecase = ClusterStories::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            ClusteringPolicy::default(),
+        );
+        let clusters = usecase
+            .execute(TimeWindow::ending_at(now, 24 * 3600).unwrap(), now)
+            .await
+            .unwrap();
+
+        assert_eq!(clusters.len(), 2);
+
+        let gpt = &clusters[0];
+        assert_eq!(gpt.articles.len(), 3);
+        assert_eq!(gpt.headline, "OpenAI releases GPT-5"); // Highest scoring member
+        assert_eq!(gpt.id.to_string(), "evt-hn-1"); // Named after the earliest member
+        assert_eq!(gpt.sources, vec!["hn".to_string(), "rd-programming".to_string(), "gh".to_string()]);
+        assert!(gpt.topics.contains("llm"));
+        assert_eq!(gpt.lifecycle, EventLifecycle::Emerging);
+
+        assert_eq!(clusters[1].articles.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_only_articles_within_time_gap_are_linked() {
+        let now = 1_000_000;
+        let hour = 3600;
+        // Out of time order on purpose; the first and last are linked only through the middle one
+        let articles = [
+            article(Source::HackerNews, "3", "OpenAI GPT-5 release notes", 10.0, now - 10 * hour),
+            article(Source::HackerNews, "1", "OpenAI releases GPT-5", 10.0, now - 90 * hour),
+            article(Source::HackerNews, "2", "GPT-5 released by OpenAI", 10.0, now - 50 * hour),
+            article(Source::HackerNews, "4", "OpenAI releases GPT-5 again", 10.0, now - 140 * hour),
+        ];
+
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_query().returning(move |q| {
+            let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+            Ok(ArticlePage { articles, next_cursor: None })
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(vec![]));
+
+        let usecase = ClusterStories::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            ClusteringPolicy::default(),
+        );
+        let clusters = usecase
+            .execute(TimeWindow::ending_at(now, 7 * 24 * hour).unwrap(), now)
+            .await
+            .unwrap();
+
+        let mut sizes: Vec<(String, usize)> = clusters.iter().map(|c| (c.id.to_string(), c.articles.len())).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![("evt-hn-1".to_string(), 3), ("evt-hn-4".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_articles_outside_window_are_ignored() {
+        let now = 100_000;
+        let articles = [article(Source::HackerNews, "1", "Old news", 10.0, now - 48 * 3600)];
+
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_query().returning(move |q| {
+            let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+            Ok(ArticlePage { articles, next_cursor: None })
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(vec![]));
+
+        let usecase = ClusterStories::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            ClusteringPolicy::default(),
+        );
+        let clusters = usecase
+            .execute(TimeWindow::ending_at(now, 24 * 3600).unwrap(), now)
+            .await
+            .unwrap();
+
+        assert!(clusters.is_empty());
+    }
+}
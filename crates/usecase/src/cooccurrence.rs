@@ -0,0 +1,275 @@
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use techpulse_domain::article::Article;
+use techpulse_domain::cooccurrence::{CooccurrenceGraph, TopicNeighbor};
+use techpulse_domain::error::DomainError;
+use techpulse_domain::query::{ArticleQuery, MAX_QUERY_LIMIT};
+use techpulse_domain::repository::{ArticleRepo, CooccurrenceRepo, TopicRepo};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::TopicTaxonomy;
+
+use crate::topics::load_taxonomy;
+
+pub struct BuildCooccurrenceGraph {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    graph_repo: Arc<dyn CooccurrenceRepo>,
+}
+
+impl BuildCooccurrenceGraph {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        graph_repo: Arc<dyn CooccurrenceRepo>,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            graph_repo,
+        }
+    }
+
+    pub async fn execute(&self, window: TimeWindow, min_count: u32) -> Result<CooccurrenceGraph, DomainError> {
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let articles = articles_in_window(self.article_repo.as_ref(), window).await?;
+
+        let documents = articles.iter().map(|a| article_topics(&taxonomy, &a.tags, &a.title));
+
+        let graph = CooccurrenceGraph::build(window, documents, min_count);
+        self.graph_repo.save_graph(&graph).await?;
+
+        Ok(graph)
+    }
+}
+
+/// Every article published inside `window`, newest first, read page by page.
+pub async fn articles_in_window(article_repo: &dyn ArticleRepo, window: TimeWindow) -> Result<Vec<Article>, DomainError> {
+    let mut query = ArticleQuery {
+        since: Some(window.start),
+        until: Some(window.end),
+        ..ArticleQuery::latest(MAX_QUERY_LIMIT)
+    };
+    let mut articles = Vec::new();
+    loop {
+        let page = article_repo.query(&query).await?;
+        articles.extend(page.articles);
+        match page.next_cursor {
+            Some(cursor) => query.cursor = Some(cursor),
+            None => return Ok(articles),
+        }
+    }
+}
+
+/// Canonical topics of an article: its tags, plus known topics mentioned in the title.
+pub fn article_topics<'a>(
+    taxonomy: &TopicTaxonomy,
+    tags: impl IntoIterator<Item = &'a String>,
+    title: &str,
+) -> BTreeSet<String> {
+    let mut topics: BTreeSet<String> = tags.into_iter().map(|t| taxonomy.canonicalize(t)).collect();
+    topics.extend(taxonomy.detect(title));
+    topics
+}
+
+pub struct GetTopicNeighborhood {
+    topic_repo: Arc<dyn TopicRepo>,
+    graph_repo: Arc<dyn CooccurrenceRepo>,
+}
+
+impl GetTopicNeighborhood {
+    pub fn new(topic_repo: Arc<dyn TopicRepo>, graph_repo: Arc<dyn CooccurrenceRepo>) -> Self {
+        Self {
+            topic_repo,
+            graph_repo,
+        }
+    }
+
+    /// Neighbors of `topic` (slug or alias) in the graph built for `period`, or in the most
+    /// recent graph when no period is given.
+    pub async fn execute(
+        &self,
+        topic: &str,
+        period: Option<TimeWindow>,
+        limit: usize,
+    ) -> Result<Vec<TopicNeighbor>, DomainError> {
+        let graph = match period {
+            Some(window) => Some(self.graph_repo.find_graph(&window).await?.ok_or_else(|| {
+                DomainError::NotFound(format!("Topic graph for {}..{}", window.start, window.end))
+            })?),
+            None => self.graph_repo.find_latest_graph().await?,
+        };
+        let Some(graph) = graph else {
+            return Ok(Vec::new());
+        };
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        Ok(graph.neighbors(&taxonomy.canonicalize(topic), limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::{ArticleCursor, ArticlePage};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{ArticleId, SaveOutcome, Source};
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub CooccurrenceRepo {}
+        #[async_trait]
+        impl CooccurrenceRepo for CooccurrenceRepo {
+            async fn save_graph(&self, graph: &CooccurrenceGraph) -> Result<(), DomainError>;
+            async fn find_graph(&self, window: &TimeWindow) -> Result<Option<CooccurrenceGraph>, DomainError>;
+            async fn find_latest_graph(&self) -> Result<Option<CooccurrenceGraph>, DomainError>;
+        }
+    }
+
+    fn tagged(id: &str, title: &str, tags: &[&str], timestamp: i64) -> Article {
+        let mut article = Article::new(Source::HackerNews, id, title.into(), "".into(), timestamp).unwrap();
+        article.tags = tags.iter().map(|t| t.to_string()).collect();
+        article
+    }
+
+    #[tokio::test]
+    async fn test_build_uses_canonical_topics_within_window() {
+        let mut mock_article_repo = MockArticleRepo::new();
+        let mut mock_topic_repo = MockTopicRepo::new();
+        let mut mock_graph_repo = MockCooccurrenceRepo::new();
+
+        let articles = [
+            tagged("1", "Shipping Rust to the browser", &["wasm"], 150),
+            tagged("2", "Rust and WebAssembly", &[], 160),
+            tagged("3", "Rust and WASM, again", &[], 50), // Outside window
+        ];
+        mock_article_repo
+            .expect_query()
+            .withf(|q| q.since == Some(100) && q.until == Some(200))
+            .returning(move |q| {
+                let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+                Ok(ArticlePage { articles, next_cursor: None })
+            });
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        mock_graph_repo
+            .expect_save_graph()
+            .times(1)
+            .withf(|g| g.window.start == 100)
+            .returning(|_| Ok(()));
+
+        let usecase = BuildCooccurrenceGraph::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            Arc::new(mock_graph_repo),
+        );
+        let graph = usecase.execute(TimeWindow::new(100, 200).unwrap(), 1).await.unwrap();
+
+        assert_eq!(graph.document_count, 2);
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.edges[0].source, "rust");
+        assert_eq!(graph.edges[0].target, "webassembly");
+        assert_eq!(graph.edges[0].count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_articles_in_window_follows_cursor() {
+        let mut mock_article_repo = MockArticleRepo::new();
+        let first = tagged("2", "Newer", &[], 160);
+        let second = tagged("1", "Older", &[], 150);
+        let cursor = ArticleCursor::after(&first);
+
+        let page = first.clone();
+        mock_article_repo
+            .expect_query()
+            .times(1)
+            .withf(|q| q.cursor.is_none())
+            .returning(move |_| Ok(ArticlePage { articles: vec![page.clone()], next_cursor: Some(ArticleCursor::after(&page)) }));
+        mock_article_repo
+            .expect_query()
+            .times(1)
+            .withf(move |q| q.cursor.as_ref() == Some(&cursor))
+            .returning(move |_| Ok(ArticlePage { articles: vec![second.clone()], next_cursor: None }));
+
+        let articles = articles_in_window(&mock_article_repo, TimeWindow::new(100, 200).unwrap()).await.unwrap();
+        assert_eq!(articles.iter().map(|a| a.id.to_string()).collect::<Vec<_>>(), vec!["hn-2", "hn-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_neighborhood_resolves_alias() {
+        let mut mock_topic_repo = MockTopicRepo::new();
+        let mut mock_graph_repo = MockCooccurrenceRepo::new();
+
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        mock_graph_repo.expect_find_latest_graph().returning(|| {
+            let docs: Vec<BTreeSet<String>> = vec![
+                ["rust", "webassembly"].iter().map(|s| s.to_string()).collect(),
+            ];
+            Ok(Some(CooccurrenceGraph::build(TimeWindow::new(0, 10).unwrap(), docs, 1)))
+        });
+
+        let usecase = GetTopicNeighborhood::new(Arc::new(mock_topic_repo), Arc::new(mock_graph_repo));
+        let neighbors = usecase.execute("WASM", None, 5).await.unwrap();
+
+        assert_eq!(neighbors.len(), 1);
+        assert_eq!(neighbors[0].topic, "rust");
+    }
+
+    #[tokio::test]
+    async fn test_neighborhood_of_period() {
+        let mut mock_topic_repo = MockTopicRepo::new();
+        let mut mock_graph_repo = MockCooccurrenceRepo::new();
+        let period = TimeWindow::new(0, 10).unwrap();
+
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        mock_graph_repo
+            .expect_find_graph()
+            .withf(move |w| *w == period)
+            .returning(|w| {
+                let docs: Vec<BTreeSet<String>> = vec![["go", "kubernetes"].iter().map(|s| s.to_string()).collect()];
+                Ok(Some(CooccurrenceGraph::build(*w, docs, 1)))
+            });
+        mock_graph_repo.expect_find_graph().returning(|_| Ok(None));
+
+        let usecase = GetTopicNeighborhood::new(Arc::new(mock_topic_repo), Arc::new(mock_graph_repo));
+        let neighbors = usecase.execute("go", Some(period), 5).await.unwrap();
+        assert_eq!(neighbors[0].topic, "kubernetes");
+
+        let missing = usecase.execute("go", Some(TimeWindow::new(10, 20).unwrap()), 5).await;
+        assert!(matches!(missing, Err(DomainError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_neighborhood_without_graph() {
+        let mock_topic_repo = MockTopicRepo::new();
+        let mut mock_graph_repo = MockCooccurrenceRepo::new();
+        mock_graph_repo.expect_find_latest_graph().returning(|| Ok(None));
+
+        let usecase = GetTopicNeighborhood::new(Arc::new(mock_topic_repo), Arc::new(mock_graph_repo));
+        assert!(usecase.execute("rust", None, 5).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+use std::sync::Arc;
+use techpulse_domain::article::Article;
+use techpulse_domain::decay::DecayPolicy;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{ArticleRepo, TopicRepo, UserRepo};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::user::{TopicKnowledge, UserId};
+
+use crate::activity::measure_topic_activity;
+use crate::topics::load_taxonomy;
+
+pub struct ApplyKnowledgeDecay {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    user_repo: Arc<dyn UserRepo>,
+    policy: DecayPolicy,
+}
+
+impl ApplyKnowledgeDecay {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        user_repo: Arc<dyn UserRepo>,
+        policy: DecayPolicy,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            user_repo,
+            policy,
+        }
+    }
+
+    /// Downgrades stale KnowIt topics, using velocity measured over `window`.
+    /// Returns the downgraded entries; the profile is only saved if something changed.
+    pub async fn execute(&self, user_id: &UserId, window: TimeWindow, now: i64) -> Result<Vec<TopicKnowledge>, DomainError> {
+        let Some(mut profile) = self.user_repo.find_by_id(user_id).await? else {
+            return Ok(Vec::new());
+        };
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let activity = measure_topic_activity(self.article_repo.as_ref(), &taxonomy, window, now).await?;
+
+        let mut downgraded = Vec::new();
+        for entry in profile.knowledge.topics.values_mut() {
+            let velocity = activity.get(&entry.topic).map_or(0.0, |a| a.velocity);
+            // Decay is not an interaction, so last_interaction stays untouched
+            if let Some(state) = self.policy.decayed_state(entry, velocity, now) {
+                entry.state = state;
+                downgraded.push(entry.clone());
+            }
+        }
+
+        if !downgraded.is_empty() {
+            self.user_repo.save(&profile).await?;
+        }
+        downgraded.sort_by(|a, b| a.topic.cmp(&b.topic));
+        Ok(downgraded)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RevisitSuggestion {
+    pub topic: String,
+    pub confidence: f64,
+    pub last_interaction: i64,
+    pub velocity: f64,
+    pub articles: Vec<Article>, // Published since the user last engaged, highest scoring first
+}
+
+pub struct GetRevisitSuggestions {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    user_repo: Arc<dyn UserRepo>,
+    policy: DecayPolicy,
+}
+
+impl GetRevisitSuggestions {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        user_repo: Arc<dyn UserRepo>,
+        policy: DecayPolicy,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            user_repo,
+            policy,
+        }
+    }
+
+    /// Known topics the user may be out of date on, least confident first.
+    /// Topics without fresh articles in `window` are left out: there is nothing to catch up on.
+    pub async fn execute(
+        &self,
+        user_id: &UserId,
+        window: TimeWindow,
+        now: i64,
+        limit: usize,
+        articles_per_topic: usize,
+    ) -> Result<Vec<RevisitSuggestion>, DomainError> {
+        let Some(profile) = self.user_repo.find_by_id(user_id).await? else {
+            return Ok(Vec::new());
+        };
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let mut activity = measure_topic_activity(self.article_repo.as_ref(), &taxonomy, window, now).await?;
+
+        let mut suggestions: Vec<RevisitSuggestion> = Vec::new();
+        for entry in profile.knowledge.topics.values() {
+            let Some(topic_activity) = activity.remove(&entry.topic) else {
+                continue;
+            };
+            if !self.policy.needs_review(entry, topic_activity.velocity, now) {
+                continue;
+            }
+            let articles: Vec<Article> = topic_activity
+                .articles
+                .into_iter()
+                .filter(|a| a.timestamp > entry.last_interaction)
+                .take(articles_per_topic)
+                .collect();
+            if articles.is_empty() {
+                continue;
+            }
+
+            suggestions.push(RevisitSuggestion {
+                topic: entry.topic.clone(),
+                confidence: self.policy.confidence(entry, topic_activity.velocity, now),
+                last_interaction: entry.last_interaction,
+                velocity: topic_activity.velocity,
+                articles,
+            });
+        }
+
+        suggestions.sort_by(|a, b| a.confidence.total_cmp(&b.confidence).then_with(|| a.topic.cmp(&b.topic)));
+        suggestions.truncate(limit);
+        Ok(suggestions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{ArticleId, SaveOutcome, Source};
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+    use techpulse_domain::user::{KnowledgeState, UserProfile};
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    const DAY: i64 = 24 * 3600;
+
+    fn article(id: &str, title: &str, timestamp: i64) -> Article {
+        Article::new(Source::HackerNews, id, title.into(), "".into(), timestamp).unwrap()
+    }
+
+    fn repos(profile: UserProfile, articles: Vec<Article>) -> (MockArticleRepo, MockTopicRepo, MockUserRepo) {
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_query().returning(move |q| {
+            let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+            Ok(ArticlePage { articles, next_cursor: None })
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(profile.clone())));
+        (mock_article_repo, mock_topic_repo, mock_user_repo)
+    }
+
+    #[tokio::test]
+    async fn test_decay_downgrades_stale_known_topics() {
+        let now = 1000 * DAY;
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        profile.update_knowledge("rust", KnowledgeState::KnowIt, now - 600 * DAY);
+        profile.update_knowledge("go", KnowledgeState::KnowIt, now - 10 * DAY);
+        profile.update_knowledge("python", KnowledgeState::HeardOf, 0);
+
+        let (article_repo, topic_repo, mut user_repo) = repos(profile, vec![]);
+        user_repo
+            .expect_save()
+            .times(1)
+            .withf(|u| {
+                u.knowledge.topics["rust"].state == KnowledgeState::HeardOf
+                    && u.knowledge.topics["go"].state == KnowledgeState::KnowIt
+            })
+            .returning(|_| Ok(()));
+
+        let usecase = ApplyKnowledgeDecay::new(
+            Arc::new(article_repo),
+            Arc::new(topic_repo),
+            Arc::new(user_repo),
+            DecayPolicy::default(),
+        );
+        let window = TimeWindow::ending_at(now, 7 * DAY).unwrap();
+        let downgraded = usecase.execute(&UserId::from("u1"), window, now).await.unwrap();
+
+        assert_eq!(downgraded.len(), 1);
+        assert_eq!(downgraded[0].topic, "rust");
+        assert_eq!(downgraded[0].last_interaction, now - 600 * DAY);
+    }
+
+    #[tokio::test]
+    async fn test_revisit_suggests_fresh_articles_on_fast_moving_known_topics() {
+        let now = 1000 * DAY;
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        profile.update_knowledge("rust", KnowledgeState::KnowIt, now - 120 * DAY);
+        profile.update_knowledge("go", KnowledgeState::KnowIt, now - 120 * DAY);
+        profile.update_knowledge("llm", KnowledgeState::KnowIt, now - 2 * DAY);
+
+        // Rust is busy this week, Go is quiet, LLMs were engaged with recently
+        let articles = vec![
+            article("1", "Rust 2.0", now - DAY),
+            article("2", "Rust in the kernel", now - 2 * DAY),
+            article("3", "Rust async traits", now - 3 * DAY),
+            article("4", "LLM news", now - DAY),
+        ];
+        let (article_repo, topic_repo, user_repo) = repos(profile, articles);
+
+        let usecase = GetRevisitSuggestions::new(
+            Arc::new(article_repo),
+            Arc::new(topic_repo),
+            Arc::new(user_repo),
+            DecayPolicy::default(),
+        );
+        let window = TimeWindow::ending_at(now, 7 * DAY).unwrap();
+        let suggestions = usecase.execute(&UserId::from("u1"), window, now, 10, 2).await.unwrap();
+
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].topic, "rust");
+        assert_eq!(suggestions[0].articles.len(), 2);
+        assert!(suggestions[0].confidence < 0.6);
+    }
+}
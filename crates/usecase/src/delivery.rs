@@ -0,0 +1,377 @@
+use chrono::NaiveDate;
+use std::sync::Arc;
+use techpulse_domain::delivery::{BriefingComposer, BriefingDelivery, RetryPolicy};
+use techpulse_domain::error::DomainError;
+use techpulse_domain::gateway::EmailGateway;
+use techpulse_domain::repository::{DeliveryRepo, UserRepo};
+use techpulse_domain::user::{UserId, UserProfile};
+
+use crate::briefing::GenerateBriefing;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeliveryReport {
+    pub sent: usize,
+    pub failed: usize, // Attempts that failed in this run, including ones that will be retried
+}
+
+pub struct DeliverBriefings {
+    user_repo: Arc<dyn UserRepo>,
+    briefings: Arc<GenerateBriefing>,
+    composer: Arc<dyn BriefingComposer>,
+    email_gateway: Arc<dyn EmailGateway>,
+    delivery_repo: Arc<dyn DeliveryRepo>,
+    policy: RetryPolicy,
+}
+
+impl DeliverBriefings {
+    pub fn new(
+        user_repo: Arc<dyn UserRepo>,
+        briefings: Arc<GenerateBriefing>,
+        composer: Arc<dyn BriefingComposer>,
+        email_gateway: Arc<dyn EmailGateway>,
+        delivery_repo: Arc<dyn DeliveryRepo>,
+        policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            user_repo,
+            briefings,
+            composer,
+            email_gateway,
+            delivery_repo,
+            policy,
+        }
+    }
+
+    /// Emails today's briefing to every subscribed user whose local send time has passed.
+    /// Each user gets at most one email per local day; failed sends are retried with backoff.
+    pub async fn execute(&self, now: i64) -> Result<DeliveryReport, DomainError> {
+        let mut report = DeliveryReport::default();
+
+        for user in self.user_repo.list_all().await? {
+            let (Some(date), Some(email)) = (user.settings.delivery_due(now), user.settings.delivery.email.clone())
+            else {
+                continue;
+            };
+
+            let mut delivery = match self.delivery_repo.find(&user.id, date).await? {
+                Some(existing) if !existing.state.is_due(now) => continue,
+                Some(existing) => existing,
+                None => BriefingDelivery::new(user.id.clone(), date, email.as_str()),
+            };
+            delivery.email = email;
+
+            match self.attempt(&user, date, &delivery.email, now).await {
+                Ok(()) => {
+                    delivery.state.record_success(now);
+                    report.sent += 1;
+                }
+                Err(e) => {
+                    delivery.state.record_failure(e.to_string(), now, &self.policy);
+                    report.failed += 1;
+                }
+            }
+            self.delivery_repo.save(&delivery).await?;
+        }
+
+        Ok(report)
+    }
+
+    // Sends the briefing of the user's local `date`, the day the delivery is logged under
+    async fn attempt(&self, user: &UserProfile, date: NaiveDate, email: &str, now: i64) -> Result<(), DomainError> {
+        let briefing = self.briefings.execute(&user.id, Some(date), now).await?;
+        let message = self
+            .composer
+            .compose(&briefing, email, user.settings.delivery.wants_html());
+        self.email_gateway.send(&message).await
+    }
+}
+
+pub struct ListDeliveries {
+    delivery_repo: Arc<dyn DeliveryRepo>,
+}
+
+impl ListDeliveries {
+    pub fn new(delivery_repo: Arc<dyn DeliveryRepo>) -> Self {
+        Self { delivery_repo }
+    }
+
+    /// Delivery log of `user_id`, newest first.
+    pub async fn execute(&self, user_id: &UserId, limit: usize) -> Result<Vec<BriefingDelivery>, DomainError> {
+        self.delivery_repo.list_for_user(user_id, limit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use mockall::predicate::eq;
+    use techpulse_domain::article::{Article, ArticleId, SaveOutcome};
+    use techpulse_domain::blindspot::BlindSpotPolicy;
+    use techpulse_domain::briefing::{Briefing, BriefingLimits};
+    use techpulse_domain::delivery::{DeliveryStatus, EmailMessage};
+    use techpulse_domain::personalization::PersonalizationWeights;
+    use techpulse_domain::repository::{ArticleRepo, BriefingRepo, TopicRepo};
+    use techpulse_domain::topic::{Topic, TopicSlug};
+    use techpulse_domain::user::DeliveryPreferences;
+
+    use crate::blindspots::GetBlindSpots;
+    use crate::feed::PersonalizeFeed;
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub BriefingRepo {}
+        #[async_trait]
+        impl BriefingRepo for BriefingRepo {
+            async fn save(&self, briefing: &Briefing) -> Result<(), DomainError>;
+            async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<Briefing>, DomainError>;
+            async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<Briefing>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub DeliveryRepo {}
+        #[async_trait]
+        impl DeliveryRepo for DeliveryRepo {
+            async fn save(&self, delivery: &BriefingDelivery) -> Result<(), DomainError>;
+            async fn find(&self, user_id: &UserId, date: NaiveDate) -> Result<Option<BriefingDelivery>, DomainError>;
+            async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<BriefingDelivery>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub EmailGateway {}
+        #[async_trait]
+        impl EmailGateway for EmailGateway {
+            async fn send(&self, message: &EmailMessage) -> Result<(), DomainError>;
+        }
+    }
+
+    struct SubjectComposer;
+
+    impl BriefingComposer for SubjectComposer {
+        fn compose(&self, briefing: &Briefing, to: &str, html: bool) -> EmailMessage {
+            EmailMessage {
+                to: to.to_string(),
+                subject: format!("Briefing {}", briefing.date),
+                text_body: String::new(),
+                html_body: html.then(String::new),
+            }
+        }
+    }
+
+    // 2024-03-01T12:00:00Z
+    const NOW: i64 = 1709294400;
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+    }
+
+    fn subscriber(id: &str, send_at: &str, enabled: bool) -> UserProfile {
+        let mut user = UserProfile::new(UserId::from(id));
+        user.settings.delivery = DeliveryPreferences {
+            enabled,
+            email: Some(format!("{}@example.com", id)),
+            send_at: send_at.into(),
+            format: String::new(),
+        };
+        user
+    }
+
+    fn usecase(
+        users: Vec<UserProfile>,
+        email_gateway: MockEmailGateway,
+        delivery_repo: MockDeliveryRepo,
+    ) -> DeliverBriefings {
+        // Briefings are already stored, so generation never touches the other repos
+        let mut briefing_repo = MockBriefingRepo::new();
+        briefing_repo.expect_find().returning(|user_id, date| {
+            Ok(Some(Briefing {
+                user_id: user_id.clone(),
+                date,
+                generated_at: NOW,
+                stories: vec![],
+                trends: vec![],
+                nudges: vec![],
+                muted: 0,
+            }))
+        });
+        let article_repo: Arc<dyn ArticleRepo> = Arc::new(MockArticleRepo::new());
+        let topic_repo: Arc<dyn TopicRepo> = Arc::new(MockTopicRepo::new());
+        let user_repo: Arc<dyn UserRepo> = {
+            let mut mock = MockUserRepo::new();
+            let by_id = users.clone();
+            mock.expect_find_by_id().returning(move |id| Ok(by_id.iter().find(|u| &u.id == id).cloned()));
+            mock.expect_list_all().returning(move || Ok(users.clone()));
+            Arc::new(mock)
+        };
+        let briefings = Arc::new(GenerateBriefing::new(
+            Arc::new(PersonalizeFeed::new(
+                article_repo.clone(),
+                topic_repo.clone(),
+                user_repo.clone(),
+                PersonalizationWeights::default(),
+            )),
+            Arc::new(GetBlindSpots::new(
+                article_repo.clone(),
+                topic_repo.clone(),
+                user_repo.clone(),
+                BlindSpotPolicy::default(),
+            )),
+            article_repo,
+            topic_repo,
+            user_repo.clone(),
+            Arc::new(briefing_repo),
+            BriefingLimits::default(),
+        ));
+
+        DeliverBriefings::new(
+            user_repo,
+            briefings,
+            Arc::new(SubjectComposer),
+            Arc::new(email_gateway),
+            Arc::new(delivery_repo),
+            RetryPolicy::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sends_to_due_subscribers_only() {
+        let users = vec![
+            subscriber("due", "07:00", true),
+            subscriber("later", "18:00", true),
+            subscriber("off", "07:00", false),
+        ];
+
+        let mut email_gateway = MockEmailGateway::new();
+        email_gateway
+            .expect_send()
+            .times(1)
+            .withf(|m| m.to == "due@example.com" && m.subject == "Briefing 2024-03-01" && m.html_body.is_some())
+            .returning(|_| Ok(()));
+        let mut delivery_repo = MockDeliveryRepo::new();
+        delivery_repo
+            .expect_find()
+            .with(eq(UserId::from("due")), eq(today()))
+            .returning(|_, _| Ok(None));
+        delivery_repo
+            .expect_save()
+            .times(1)
+            .withf(|d| d.state.status == DeliveryStatus::Sent && d.state.attempts == 1)
+            .returning(|_| Ok(()));
+
+        let report = usecase(users, email_gateway, delivery_repo).execute(NOW).await.unwrap();
+        assert_eq!(report, DeliveryReport { sent: 1, failed: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_sends_briefing_of_local_date() {
+        // 08:00 on March 2nd in Tokyo (UTC+9), still March 1st in UTC
+        let now = NOW + 11 * 3600;
+        let local_today = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+        let mut user = subscriber("tokyo", "07:00", true);
+        user.settings.time_zone = "Asia/Tokyo".into();
+
+        let mut email_gateway = MockEmailGateway::new();
+        email_gateway
+            .expect_send()
+            .times(1)
+            .withf(|m| m.subject == "Briefing 2024-03-02")
+            .returning(|_| Ok(()));
+        let mut delivery_repo = MockDeliveryRepo::new();
+        delivery_repo
+            .expect_find()
+            .with(eq(UserId::from("tokyo")), eq(local_today))
+            .returning(|_, _| Ok(None));
+        delivery_repo
+            .expect_save()
+            .times(1)
+            .withf(move |d| d.date == local_today && d.state.status == DeliveryStatus::Sent)
+            .returning(|_| Ok(()));
+
+        let report = usecase(vec![user], email_gateway, delivery_repo).execute(now).await.unwrap();
+        assert_eq!(report, DeliveryReport { sent: 1, failed: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_failed_send_is_logged_for_retry() {
+        let mut email_gateway = MockEmailGateway::new();
+        email_gateway
+            .expect_send()
+            .returning(|_| Err(DomainError::Gateway("SMTP error: connection refused".into())));
+        let mut delivery_repo = MockDeliveryRepo::new();
+        delivery_repo.expect_find().returning(|_, _| Ok(None));
+        delivery_repo
+            .expect_save()
+            .times(1)
+            .withf(|d| {
+                d.state.status == DeliveryStatus::Retrying
+                    && d.state.next_attempt_at == Some(NOW + RetryPolicy::default().base_delay_secs)
+                    && d.state.last_error.as_deref().is_some_and(|e| e.contains("connection refused"))
+            })
+            .returning(|_| Ok(()));
+
+        let report = usecase(vec![subscriber("u1", "07:00", true)], email_gateway, delivery_repo)
+            .execute(NOW)
+            .await
+            .unwrap();
+        assert_eq!(report, DeliveryReport { sent: 0, failed: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_sent_or_backing_off_deliveries_are_left_alone() {
+        let mut sent = BriefingDelivery::new(UserId::from("sent"), today(), "sent@example.com");
+        sent.state.record_success(NOW - 3600);
+        let mut waiting = BriefingDelivery::new(UserId::from("waiting"), today(), "waiting@example.com");
+        waiting.state.record_failure("timeout", NOW - 60, &RetryPolicy::default());
+
+        let mut email_gateway = MockEmailGateway::new();
+        email_gateway.expect_send().never();
+        let mut delivery_repo = MockDeliveryRepo::new();
+        delivery_repo.expect_find().returning(move |user_id, _| {
+            Ok(Some(if user_id == &UserId::from("sent") { sent.clone() } else { waiting.clone() }))
+        });
+        delivery_repo.expect_save().never();
+
+        let users = vec![subscriber("sent", "07:00", true), subscriber("waiting", "07:00", true)];
+        let report = usecase(users, email_gateway, delivery_repo).execute(NOW).await.unwrap();
+        assert_eq!(report, DeliveryReport::default());
+    }
+}
@@ -1,7 +1,20 @@
 use std::sync::Arc;
 use techpulse_domain::article::Article;
 use techpulse_domain::error::DomainError;
-use techpulse_domain::repository::ArticleRepo;
+use techpulse_domain::mute::MuteReport;
+use techpulse_domain::personalization::PersonalizationWeights;
+use techpulse_domain::query::{ArticlePage, ArticleQuery};
+use techpulse_domain::repository::{ArticleRepo, TopicRepo, UserRepo};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::user::UserId;
+
+use crate::activity::measure_topic_activity;
+use crate::cooccurrence::article_topics;
+use crate::topics::load_taxonomy;
+
+// Candidates considered per requested article, so boosted older stories can still surface
+const CANDIDATE_FACTOR: usize = 5;
+const VELOCITY_WINDOW_SECS: i64 = 24 * 3600;
 
 pub struct GetChronologicalFeed {
     repo: Arc<dyn ArticleRepo>,
@@ -12,25 +25,141 @@ impl GetChronologicalFeed {
         Self { repo }
     }
 
-    pub async fn execute(&self, limit: usize) -> Result<Vec<Article>, DomainError> {
-        self.repo.find_latest(limit).await
+    pub async fn execute(&self, query: ArticleQuery) -> Result<ArticlePage, DomainError> {
+        self.repo.query(&query.normalized()?).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RankedArticle {
+    pub article: Article,
+    pub base_score: f64,
+    pub score: f64, // Base score times the user's personalization multiplier
+    pub watch_matches: Vec<String>, // Names of the user's watch rules matching the article
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PersonalizedFeed {
+    pub articles: Vec<RankedArticle>,
+    pub muted: MuteReport, // Items the user's mute rules kept out of `articles`
+}
+
+pub struct PersonalizeFeed {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    user_repo: Arc<dyn UserRepo>,
+    weights: PersonalizationWeights,
+}
+
+impl PersonalizeFeed {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        user_repo: Arc<dyn UserRepo>,
+        weights: PersonalizationWeights,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            user_repo,
+            weights,
+        }
+    }
+
+    /// Recent articles passing `query`, ranked for `user_id` highest adjusted score first, without
+    /// muted ones. Unknown users get the unpersonalized ranking. Ranked feeds have no cursor.
+    pub async fn execute(&self, user_id: &UserId, query: ArticleQuery, now: i64) -> Result<PersonalizedFeed, DomainError> {
+        if query.cursor.is_some() {
+            return Err(DomainError::Validation("Ranked feeds cannot be paged with a cursor".to_string()));
+        }
+        let limit = query.limit;
+        let query = query.normalized()?;
+        let candidates = self
+            .article_repo
+            .query(&query.with_limit(limit * CANDIDATE_FACTOR))
+            .await?
+            .articles;
+        let profile = self.user_repo.find_by_id(user_id).await?;
+        let mutes = profile.as_ref().map(|p| p.mute_filter(now));
+
+        let mut ranked: Vec<RankedArticle> = match profile {
+            None => candidates
+                .into_iter()
+                .map(|article| {
+                    let base_score = article.calculate_score(now);
+                    RankedArticle { article, base_score, score: base_score, watch_matches: Vec::new() }
+                })
+                .collect(),
+            Some(profile) => {
+                let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+                let window = TimeWindow::ending_at(now, VELOCITY_WINDOW_SECS)?;
+                let activity = measure_topic_activity(self.article_repo.as_ref(), &taxonomy, window, now).await?;
+                let velocity_of = |topic: &str| activity.get(topic).map_or(0.0, |a| a.velocity);
+
+                candidates
+                    .into_iter()
+                    .map(|article| {
+                        let topics = article_topics(&taxonomy, &article.tags, &article.title);
+                        let multiplier = self.weights.multiplier(
+                            &profile,
+                            &topics,
+                            &article.source.to_string(),
+                            velocity_of,
+                        );
+                        let base_score = article.calculate_score(now);
+                        let watch_matches = profile.watch_matches(&article).map(|r| r.name.clone()).collect();
+                        RankedArticle { article, base_score, score: base_score * multiplier, watch_matches }
+                    })
+                    .collect()
+            }
+        };
+
+        ranked.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| b.article.timestamp.cmp(&a.article.timestamp))
+        });
+
+        // Only items that would have made the cut count as hidden
+        let mut feed = PersonalizedFeed::default();
+        for item in ranked {
+            if feed.articles.len() == limit {
+                break;
+            }
+            match mutes.as_ref().and_then(|m| m.muted_by(&item.article)) {
+                Some(rule) => feed.muted.record(rule),
+                None => feed.articles.push(item),
+            }
+        }
+        Ok(feed)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
     use async_trait::async_trait;
     use mockall::mock;
-    use techpulse_domain::article::{Article, ArticleId, Source};
-    
+    use techpulse_domain::article::{Article, ArticleId, SaveOutcome, Source};
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+    use techpulse_domain::user::{KnowledgeState, UserProfile};
+    use techpulse_domain::mute::{MuteKind, MuteRule};
+    use techpulse_domain::query::ArticleCursor;
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::watchlist::WatchRule;
+
     mock! {
         pub ArticleRepo {}
         #[async_trait]
         impl ArticleRepo for ArticleRepo {
-            async fn save(&self, article: &Article) -> Result<(), DomainError>;
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
             async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
             async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
         }
     }
 
@@ -42,15 +171,156 @@ mod tests {
         let expected = vec![article];
         let return_val = expected.clone();
         
-        mock_repo.expect_find_latest()
-            .with(mockall::predicate::eq(10))
+        mock_repo.expect_query()
+            .withf(|q| q.limit == 10 && q.sources == vec!["hn".to_string()])
             .times(1)
-            .returning(move |_| Ok(return_val.clone()));
+            .returning(move |_| Ok(ArticlePage { articles: return_val.clone(), next_cursor: None }));
             
         let usecase = GetChronologicalFeed::new(Arc::new(mock_repo));
-        let result = usecase.execute(10).await.unwrap();
+        let mut query = ArticleQuery::latest(10);
+        query.sources = vec![" HN ".into()];
+        let result = usecase.execute(query).await.unwrap();
         
-        assert_eq!(result.len(), 1);
-        assert_eq!(result[0].title, "T1");
+        assert_eq!(result.articles.len(), 1);
+        assert_eq!(result.articles[0].title, "T1");
+        assert!(usecase.execute(ArticleQuery::latest(0)).await.is_err());
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    fn personalized(profile: Option<UserProfile>, articles: Vec<Article>) -> PersonalizeFeed {
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_query().returning(move |q| {
+            let matching = articles.iter().filter(|a| q.matches(a)).take(q.limit + 1).cloned().collect();
+            Ok(ArticlePage::from_overfetch(matching, q.limit))
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(move |_| Ok(profile.clone()));
+
+        PersonalizeFeed::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            Arc::new(mock_user_repo),
+            PersonalizationWeights::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_personalized_feed_reorders_by_knowledge() {
+        let now = 100_000;
+        let articles: Vec<Article> = [("1", "Rust 2.0"), ("2", "LLM agents"), ("3", "Kubernetes tips")]
+            .iter()
+            .map(|(id, title)| {
+                let mut a = Article::new(Source::HackerNews, id, title.to_string(), "".into(), now - 60).unwrap();
+                a.score = 50.0;
+                a
+            })
+            .collect();
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        profile.update_knowledge("rust", KnowledgeState::KnowIt, 0);
+        profile.update_knowledge("llm", KnowledgeState::WantToLearn, 0);
+        profile
+            .add_watch_rule(WatchRule::new("k8s", "kubernetes OR k8s", 0).unwrap())
+            .unwrap();
+
+        let ranked = personalized(Some(profile), articles.clone())
+            .execute(&UserId::from("u1"), ArticleQuery::latest(10), now)
+            .await
+            .unwrap()
+            .articles;
+        let ids: Vec<String> = ranked.iter().map(|r| r.article.id.to_string()).collect();
+        assert_eq!(ids, vec!["hn-2", "hn-3", "hn-1"]);
+        assert!(ranked[2].score < ranked[2].base_score);
+        assert_eq!(ranked[1].watch_matches, vec!["k8s".to_string()]); // Flagged, not re-ranked
+        assert!(ranked[0].watch_matches.is_empty());
+
+        // Without a profile every article keeps its base score
+        let ranked = personalized(None, articles)
+            .execute(&UserId::from("ghost"), ArticleQuery::latest(2), now)
+            .await
+            .unwrap()
+            .articles;
+        assert_eq!(ranked.len(), 2);
+        assert!(ranked.iter().all(|r| r.score == r.base_score));
+    }
+
+    #[tokio::test]
+    async fn test_muted_articles_are_hidden_and_counted() {
+        let now = 100_000;
+        let articles: Vec<Article> = [
+            ("1", "Bitcoin price hits record", "https://coins.example/1", 90.0),
+            ("2", "Rust 2.0", "https://blog.rust-lang.org", 80.0),
+            ("3", "Ethereum price update", "https://coins.example/2", 70.0),
+            ("4", "Spam deals", "https://spam.example", 60.0),
+            ("5", "SQLite internals", "https://sqlite.org", 50.0),
+            ("6", "Go 1.23", "https://go.dev", 40.0),
+        ]
+        .iter()
+        .map(|(id, title, url, score)| {
+            let mut a = Article::new(Source::HackerNews, id, title.to_string(), url.to_string(), now - 60).unwrap();
+            a.score = *score;
+            a
+        })
+        .collect();
+
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        let price = MuteRule::new(MuteKind::Regex, r"\bprice\b", None, 0).unwrap();
+        let spam = MuteRule::new(MuteKind::Domain, "spam.example", Some(3600), now - 60).unwrap();
+        let expired = MuteRule::new(MuteKind::Keyword, "sqlite", Some(10), 0).unwrap();
+        for rule in [price.clone(), spam.clone(), expired] {
+            profile.add_mute(rule, 0).unwrap();
+        }
+
+        let feed = personalized(Some(profile), articles)
+            .execute(&UserId::from("u1"), ArticleQuery::latest(3), now)
+            .await
+            .unwrap();
+        let ids: Vec<String> = feed.articles.iter().map(|r| r.article.id.to_string()).collect();
+        assert_eq!(ids, vec!["hn-2", "hn-5", "hn-6"]);
+        assert_eq!(feed.muted.hidden, 3);
+        assert_eq!(feed.muted.by_rule, vec![(price.id, 2), (spam.id, 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_personalized_feed_applies_filters() {
+        let now = 100_000;
+        let articles: Vec<Article> = [("1", "Rust 2.0", "rust"), ("2", "Go 1.23", "golang"), ("3", "Rust async", "rust")]
+            .iter()
+            .map(|(id, title, sub)| Article::new(Source::Reddit(sub.to_string()), id, title.to_string(), "".into(), now - 60).unwrap())
+            .collect();
+        let feed = personalized(None, articles);
+
+        let mut query = ArticleQuery::latest(10);
+        query.sources = vec!["rd-rust".into()];
+        let ranked = feed.execute(&UserId::from("u1"), query, now).await.unwrap().articles;
+        assert_eq!(ranked.len(), 2);
+        assert!(ranked.iter().all(|r| r.article.title.starts_with("Rust")));
+
+        let mut paged = ArticleQuery::latest(10);
+        paged.cursor = Some(ArticleCursor::after(&ranked[0].article));
+        assert!(matches!(
+            feed.execute(&UserId::from("u1"), paged, now).await,
+            Err(DomainError::Validation(_))
+        ));
     }
 }
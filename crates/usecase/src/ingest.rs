@@ -1,37 +1,126 @@
 use std::sync::Arc;
+use techpulse_domain::article::{ArticleSnapshot, SaveOutcome};
 use techpulse_domain::error::DomainError;
 use techpulse_domain::gateway::ArticleGateway;
-use techpulse_domain::repository::ArticleRepo;
+use techpulse_domain::repository::{ArticleRepo, SnapshotRepo};
+use techpulse_domain::webhook::{WebhookData, WebhookEvent};
+
+use crate::watchlist::EvaluateWatchlists;
+use crate::webhooks::PublishWebhookEvents;
+
+/// Fetched articles split by whether they were stored for the first time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct IngestReport {
+    pub new: usize,
+    pub updated: usize, // Already stored; engagement refreshed
+}
+
+impl IngestReport {
+    pub fn total(&self) -> usize {
+        self.new + self.updated
+    }
+}
 
 pub struct IngestArticles {
     gateway: Arc<dyn ArticleGateway>,
     repo: Arc<dyn ArticleRepo>,
+    webhooks: Option<Arc<PublishWebhookEvents>>,
+    watchlists: Option<Arc<EvaluateWatchlists>>,
+    snapshots: Option<Arc<dyn SnapshotRepo>>,
 }
 
 impl IngestArticles {
     pub fn new(gateway: Arc<dyn ArticleGateway>, repo: Arc<dyn ArticleRepo>) -> Self {
-        Self { gateway, repo }
+        Self {
+            gateway,
+            repo,
+            webhooks: None,
+            watchlists: None,
+            snapshots: None,
+        }
     }
 
-    pub async fn execute(&self, limit: usize) -> Result<usize, DomainError> {
-        let articles = self.gateway.fetch_top_articles(limit).await?;
-        let count = articles.len();
-        
-        for article in articles {
-            self.repo.save(&article).await?;
+    /// Publishes `article.matched` for articles seen for the first time.
+    pub fn with_publisher(mut self, webhooks: Arc<PublishWebhookEvents>) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+
+    /// Checks every fetched article against the users' watchlists.
+    pub fn with_watchlists(mut self, watchlists: Arc<EvaluateWatchlists>) -> Self {
+        self.watchlists = Some(watchlists);
+        self
+    }
+
+    /// Records each fetched article's score and comment count, building its history across ingests.
+    pub fn with_snapshots(mut self, snapshots: Arc<dyn SnapshotRepo>) -> Self {
+        self.snapshots = Some(snapshots);
+        self
+    }
+
+    pub async fn execute(&self, limit: usize, now: i64) -> Result<IngestReport, DomainError> {
+        let articles: Vec<_> = self
+            .gateway
+            .fetch_top_articles(limit)
+            .await?
+            .into_iter()
+            .map(|a| a.seen_at(now))
+            .collect();
+
+        let outcomes = self.repo.save_many(&articles).await?;
+
+        let mut report = IngestReport::default();
+        let mut events = Vec::new();
+        for (article, outcome) in articles.iter().zip(outcomes) {
+            if outcome == SaveOutcome::Updated {
+                report.updated += 1;
+                continue;
+            }
+            report.new += 1;
+            if self.webhooks.is_some() {
+                events.push(WebhookEvent::new(
+                    WebhookData::ArticleMatched {
+                        article_id: article.id.to_string(),
+                        title: article.title.clone(),
+                        url: article.url.clone(),
+                        source: article.source.to_string(),
+                        tags: article.tags.iter().cloned().collect(),
+                    },
+                    article.timestamp,
+                ));
+            }
+        }
+
+        if let Some(snapshots) = &self.snapshots {
+            let taken: Vec<ArticleSnapshot> = articles.iter().map(|a| ArticleSnapshot::of(a, now)).collect();
+            snapshots.append(&taken).await?;
+        }
+        if let Some(webhooks) = &self.webhooks {
+            webhooks.execute(&events, now).await?;
+        }
+        if let Some(watchlists) = &self.watchlists {
+            watchlists.execute(&articles, now).await?;
         }
-        
-        Ok(count)
+
+        Ok(report)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
     use techpulse_domain::article::{Article, ArticleId, Source};
     use mockall::predicate::*;
     use mockall::mock;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
     use async_trait::async_trait;
+    use techpulse_domain::repository::WebhookRepo;
+    use techpulse_domain::webhook::{WebhookDelivery, WebhookEventType, WebhookId, WebhookSubscription};
+    use techpulse_domain::repository::{AlertRepo, UserRepo};
+    use techpulse_domain::user::{UserId, UserProfile};
+    use techpulse_domain::watchlist::{WatchAlert, WatchRule};
 
     mock! {
         Gateway {}
@@ -45,9 +134,13 @@ mod tests {
         Repo {}
         #[async_trait]
         impl ArticleRepo for Repo {
-            async fn save(&self, article: &Article) -> Result<(), DomainError>;
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
             async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
             async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
         }
     }
 
@@ -68,14 +161,149 @@ mod tests {
             .return_once(|_| Ok(articles));
 
         mock_repo
-            .expect_save()
-            .times(2)
-            .returning(|_| Ok(()));
+            .expect_save_many()
+            .times(1)
+            .withf(|batch| batch.len() == 2 && batch.iter().all(|a| a.first_seen_at == 300 && a.last_seen_at == 300 && a.times_seen == 1))
+            .returning(|_| Ok(vec![SaveOutcome::Updated, SaveOutcome::Inserted]));
 
         let use_case = IngestArticles::new(Arc::new(mock_gateway), Arc::new(mock_repo));
-        let result = use_case.execute(2).await;
+        let result = use_case.execute(2, 300).await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 2);
+        assert_eq!(result.unwrap(), IngestReport { new: 1, updated: 1 });
+    }
+
+    mock! {
+        WebhookRepo {}
+        #[async_trait]
+        impl WebhookRepo for WebhookRepo {
+            async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), DomainError>;
+            async fn find_subscription(&self, id: &WebhookId) -> Result<Option<WebhookSubscription>, DomainError>;
+            async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, DomainError>;
+            async fn delete_subscription(&self, id: &WebhookId) -> Result<(), DomainError>;
+            async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), DomainError>;
+            async fn list_due_deliveries(&self, now: i64, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+            async fn list_deliveries(&self, subscription_id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_new_articles_matching_keywords_are_published() {
+        let mut mock_gateway = MockGateway::new();
+        let mut mock_repo = MockRepo::new();
+        let mut mock_webhook_repo = MockWebhookRepo::new();
+
+        let known = Article::new(Source::HackerNews, "1", "Rust 1.80 released".to_string(), "".to_string(), 100).unwrap();
+        let fresh = Article::new(Source::HackerNews, "2", "Rust 2.0 released".to_string(), "".to_string(), 200).unwrap();
+        let other = Article::new(Source::HackerNews, "3", "Go 1.23 released".to_string(), "".to_string(), 200).unwrap();
+        let stored = known.id.clone();
+        mock_gateway
+            .expect_fetch_top_articles()
+            .return_once(move |_| Ok(vec![known, fresh, other]));
+        mock_repo.expect_save_many().times(1).returning(move |batch| {
+            Ok(batch
+                .iter()
+                .map(|a| if a.id == stored { SaveOutcome::Updated } else { SaveOutcome::Inserted })
+                .collect())
+        });
+
+        let subscription = WebhookSubscription::new(
+            "https://hooks.example.com",
+            "0123456789abcdef",
+            vec![WebhookEventType::ArticleMatched],
+            &["rust".to_string()],
+            0,
+        )
+        .unwrap();
+        mock_webhook_repo.expect_list_subscriptions().returning(move || Ok(vec![subscription.clone()]));
+        mock_webhook_repo
+            .expect_save_delivery()
+            .times(1) // Only the new Rust article
+            .withf(|d| d.event.mentions("rust") && d.event.occurred_at == 200)
+            .returning(|_| Ok(()));
+
+        let use_case = IngestArticles::new(Arc::new(mock_gateway), Arc::new(mock_repo))
+            .with_publisher(Arc::new(PublishWebhookEvents::new(Arc::new(mock_webhook_repo))));
+        assert_eq!(use_case.execute(3, 300).await.unwrap(), IngestReport { new: 2, updated: 1 });
+    }
+
+    mock! {
+        UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    mock! {
+        AlertRepo {}
+        #[async_trait]
+        impl AlertRepo for AlertRepo {
+            async fn save(&self, alert: &WatchAlert) -> Result<bool, DomainError>;
+            async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<WatchAlert>, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ingested_articles_are_checked_against_watchlists() {
+        let mut mock_gateway = MockGateway::new();
+        let mut mock_repo = MockRepo::new();
+        let mut mock_user_repo = MockUserRepo::new();
+        let mut mock_alert_repo = MockAlertRepo::new();
+
+        let articles = vec![
+            Article::new(Source::HackerNews, "1", "SQLite 3.45".to_string(), "https://sqlite.org/news".to_string(), 100).unwrap(),
+            Article::new(Source::HackerNews, "2", "Go 1.23".to_string(), "https://go.dev/blog".to_string(), 100).unwrap(),
+        ];
+        mock_gateway.expect_fetch_top_articles().return_once(|_| Ok(articles));
+        mock_repo.expect_save_many().times(1).returning(|batch| Ok(vec![SaveOutcome::Inserted; batch.len()]));
+
+        let mut watcher = UserProfile::new(UserId::from("u1"));
+        watcher.add_watch_rule(WatchRule::new("", "domain:sqlite.org", 0).unwrap()).unwrap();
+        mock_user_repo.expect_list_all().returning(move || Ok(vec![watcher.clone()]));
+        mock_alert_repo
+            .expect_save()
+            .times(1)
+            .withf(|a| a.article_id.to_string() == "hn-1" && a.matched_at == 300)
+            .returning(|_| Ok(true));
+
+        let use_case = IngestArticles::new(Arc::new(mock_gateway), Arc::new(mock_repo)).with_watchlists(Arc::new(
+            EvaluateWatchlists::new(Arc::new(mock_user_repo), Arc::new(mock_alert_repo)),
+        ));
+        assert_eq!(use_case.execute(2, 300).await.unwrap().total(), 2);
+    }
+
+    mock! {
+        SnapshotRepo {}
+        #[async_trait]
+        impl SnapshotRepo for SnapshotRepo {
+            async fn append(&self, snapshots: &[ArticleSnapshot]) -> Result<(), DomainError>;
+            async fn list_for_article(&self, article_id: &ArticleId) -> Result<Vec<ArticleSnapshot>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ingest_snapshots_article_metrics() {
+        let mut mock_gateway = MockGateway::new();
+        let mut mock_repo = MockRepo::new();
+        let mut mock_snapshot_repo = MockSnapshotRepo::new();
+
+        let mut article = Article::new(Source::HackerNews, "1", "Rust 2.0".to_string(), "".to_string(), 100).unwrap();
+        article.score = 42.0;
+        article.comment_count = 17;
+        mock_gateway.expect_fetch_top_articles().return_once(move |_| Ok(vec![article]));
+        mock_repo.expect_save_many().times(1).returning(|batch| Ok(vec![SaveOutcome::Inserted; batch.len()]));
+        mock_snapshot_repo
+            .expect_append()
+            .times(1)
+            .withf(|s| s.len() == 1 && s[0].captured_at == 300 && s[0].score == 42.0 && s[0].comment_count == 17)
+            .returning(|_| Ok(()));
+
+        let use_case = IngestArticles::new(Arc::new(mock_gateway), Arc::new(mock_repo))
+            .with_snapshots(Arc::new(mock_snapshot_repo));
+        assert_eq!(use_case.execute(1, 300).await.unwrap().total(), 1);
     }
 }
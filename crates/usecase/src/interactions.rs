@@ -0,0 +1,265 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use techpulse_domain::article::ArticleId;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::interaction::{InteractionEvent, InteractionPolicy};
+use techpulse_domain::repository::{ArticleRepo, InteractionRepo, TopicRepo, UserRepo};
+use techpulse_domain::user::{UserId, UserProfile};
+
+use crate::cooccurrence::article_topics;
+use crate::topics::load_taxonomy;
+
+pub const MAX_BATCH_SIZE: usize = 500;
+
+pub struct UpdateProfileFromInteractions {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    user_repo: Arc<dyn UserRepo>,
+    policy: InteractionPolicy,
+}
+
+impl UpdateProfileFromInteractions {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        user_repo: Arc<dyn UserRepo>,
+        policy: InteractionPolicy,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            user_repo,
+            policy,
+        }
+    }
+
+    /// Folds `events` into the knowledge map of each user involved, in timestamp order.
+    /// Events on unknown articles carry no topics and are skipped.
+    pub async fn execute(&self, events: &[InteractionEvent]) -> Result<Vec<UserProfile>, DomainError> {
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+
+        let mut by_user: BTreeMap<String, Vec<&InteractionEvent>> = BTreeMap::new();
+        for event in events {
+            by_user.entry(event.user_id.to_string()).or_default().push(event);
+        }
+
+        let mut topics_by_article: HashMap<ArticleId, BTreeSet<String>> = HashMap::new();
+        let mut updated = Vec::new();
+        for (user_id, mut user_events) in by_user {
+            user_events.sort_by_key(|e| e.timestamp);
+
+            let user_id = UserId::new(user_id);
+            let mut profile = self
+                .user_repo
+                .find_by_id(&user_id)
+                .await?
+                .unwrap_or_else(|| UserProfile::new(user_id.clone()));
+
+            for event in user_events {
+                if !topics_by_article.contains_key(&event.article_id) {
+                    let topics = match self.article_repo.find_by_id(&event.article_id).await? {
+                        Some(article) => article_topics(&taxonomy, &article.tags, &article.title),
+                        None => BTreeSet::new(),
+                    };
+                    topics_by_article.insert(event.article_id.clone(), topics);
+                }
+                self.policy
+                    .apply(&mut profile, &topics_by_article[&event.article_id], event);
+            }
+
+            self.user_repo.save(&profile).await?;
+            updated.push(profile);
+        }
+
+        Ok(updated)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedInteractions {
+    pub recorded: usize,
+    pub profiles_updated: usize,
+}
+
+pub struct RecordInteractions {
+    interaction_repo: Arc<dyn InteractionRepo>,
+    profile_updater: Arc<UpdateProfileFromInteractions>,
+}
+
+impl RecordInteractions {
+    pub fn new(
+        interaction_repo: Arc<dyn InteractionRepo>,
+        profile_updater: Arc<UpdateProfileFromInteractions>,
+    ) -> Self {
+        Self {
+            interaction_repo,
+            profile_updater,
+        }
+    }
+
+    /// Appends a batch to the interaction log, then folds it into the affected profiles.
+    pub async fn execute(&self, events: &[InteractionEvent]) -> Result<RecordedInteractions, DomainError> {
+        if events.len() > MAX_BATCH_SIZE {
+            return Err(DomainError::Validation(format!(
+                "Batch of {} interactions exceeds the limit of {}",
+                events.len(),
+                MAX_BATCH_SIZE
+            )));
+        }
+        if events.is_empty() {
+            return Ok(RecordedInteractions {
+                recorded: 0,
+                profiles_updated: 0,
+            });
+        }
+
+        self.interaction_repo.append(events).await?;
+        let profiles = self.profile_updater.execute(events).await?;
+
+        Ok(RecordedInteractions {
+            recorded: events.len(),
+            profiles_updated: profiles.len(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{Article, SaveOutcome, Source};
+    use techpulse_domain::interaction::InteractionKind;
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+    use techpulse_domain::user::KnowledgeState;
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub InteractionRepo {}
+        #[async_trait]
+        impl InteractionRepo for InteractionRepo {
+            async fn append(&self, events: &[InteractionEvent]) -> Result<(), DomainError>;
+            async fn list_for_user(&self, user_id: &UserId, since: i64) -> Result<Vec<InteractionEvent>, DomainError>;
+            async fn bookmarked_articles(&self) -> Result<HashSet<ArticleId>, DomainError>;
+        }
+    }
+
+    fn event(user: &str, article: &str, kind: InteractionKind, timestamp: i64) -> InteractionEvent {
+        InteractionEvent {
+            user_id: UserId::from(user),
+            article_id: ArticleId::from_persisted(article.into()),
+            kind,
+            timestamp,
+        }
+    }
+
+    fn updater(user_repo: MockUserRepo) -> UpdateProfileFromInteractions {
+        let mut mock_article_repo = MockArticleRepo::new();
+        mock_article_repo.expect_find_by_id().returning(|id| {
+            Ok((id.to_string() == "hn-1").then(|| {
+                Article::new(Source::HackerNews, "1", "Rust 2.0 is out".into(), "".into(), 0).unwrap()
+            }))
+        });
+        let mut mock_topic_repo = MockTopicRepo::new();
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+
+        UpdateProfileFromInteractions::new(
+            Arc::new(mock_article_repo),
+            Arc::new(mock_topic_repo),
+            Arc::new(user_repo),
+            InteractionPolicy::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_long_reads_fold_into_knowledge_map() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(|_| Ok(None));
+        mock_user_repo.expect_save().times(1).returning(|_| Ok(()));
+
+        let events: Vec<InteractionEvent> = (0..3)
+            .map(|i| event("u1", "hn-1", InteractionKind::Dwell { seconds: 120 }, 100 + i))
+            .chain(std::iter::once(event("u1", "hn-404", InteractionKind::MarkKnown, 50)))
+            .collect();
+
+        let profiles = updater(mock_user_repo).execute(&events).await.unwrap();
+
+        assert_eq!(profiles.len(), 1);
+        let knowledge = &profiles[0].knowledge.topics;
+        assert_eq!(knowledge.len(), 1); // The unknown article contributed nothing
+        assert_eq!(knowledge["rust"].state, KnowledgeState::HeardOf);
+        assert_eq!(knowledge["rust"].interaction_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_record_appends_then_updates_each_user() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(|_| Ok(None));
+        mock_user_repo.expect_save().times(2).returning(|_| Ok(()));
+        let mut mock_interaction_repo = MockInteractionRepo::new();
+        mock_interaction_repo
+            .expect_append()
+            .times(1)
+            .withf(|events| events.len() == 2)
+            .returning(|_| Ok(()));
+
+        let usecase = RecordInteractions::new(Arc::new(mock_interaction_repo), Arc::new(updater(mock_user_repo)));
+        let result = usecase
+            .execute(&[
+                event("u1", "hn-1", InteractionKind::Open, 1),
+                event("u2", "hn-1", InteractionKind::WantToLearn, 2),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(result, RecordedInteractions { recorded: 2, profiles_updated: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_record_rejects_oversized_batch() {
+        let usecase = RecordInteractions::new(
+            Arc::new(MockInteractionRepo::new()),
+            Arc::new(updater(MockUserRepo::new())),
+        );
+        let events = vec![event("u1", "hn-1", InteractionKind::Open, 1); MAX_BATCH_SIZE + 1];
+
+        let result = usecase.execute(&events).await;
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+}
@@ -1,3 +1,20 @@
 pub mod feed;
 pub mod ingest;
 pub mod trends;
+pub mod topics;
+pub mod cooccurrence;
+pub mod clustering;
+pub mod timeline;
+pub mod users;
+pub mod activity;
+pub mod blindspots;
+pub mod interactions;
+pub mod decay;
+pub mod briefing;
+pub mod delivery;
+pub mod webhooks;
+pub mod watchlist;
+pub mod mutes;
+pub mod search;
+pub mod articles;
+pub mod retention;
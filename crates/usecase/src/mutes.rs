@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::mute::{MuteKind, MuteRule, MuteRuleId};
+use techpulse_domain::repository::UserRepo;
+use techpulse_domain::user::UserId;
+
+use crate::users::load_or_create;
+
+pub struct AddMuteRule {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl AddMuteRule {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    /// Mutes `pattern` for `duration_secs`, or until removed if None.
+    pub async fn execute(
+        &self,
+        id: &UserId,
+        kind: MuteKind,
+        pattern: &str,
+        duration_secs: Option<i64>,
+        now: i64,
+    ) -> Result<MuteRule, DomainError> {
+        let rule = MuteRule::new(kind, pattern, duration_secs, now)?;
+        let mut profile = load_or_create(self.user_repo.as_ref(), id).await?;
+        profile.add_mute(rule.clone(), now)?;
+        self.user_repo.save(&profile).await?;
+        Ok(rule)
+    }
+}
+
+pub struct ListMuteRules {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl ListMuteRules {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    /// Rules still in effect at `now`, oldest first.
+    pub async fn execute(&self, id: &UserId, now: i64) -> Result<Vec<MuteRule>, DomainError> {
+        let profile = self
+            .user_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User '{}'", id)))?;
+        Ok(profile.mutes.into_iter().filter(|r| r.is_active(now)).collect())
+    }
+}
+
+pub struct RemoveMuteRule {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl RemoveMuteRule {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, id: &UserId, rule_id: &MuteRuleId) -> Result<(), DomainError> {
+        let mut profile = self
+            .user_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User '{}'", id)))?;
+        if !profile.remove_mute(rule_id) {
+            return Err(DomainError::NotFound(format!("Mute rule {}", rule_id)));
+        }
+        self.user_repo.save(&profile).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::user::UserProfile;
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_mute_creates_profile_and_sets_expiry() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(|_| Ok(None));
+        mock_user_repo
+            .expect_save()
+            .times(1)
+            .withf(|u| u.mutes.len() == 1 && u.mutes[0].expires_at == Some(1000 + 7 * 24 * 3600))
+            .returning(|_| Ok(()));
+
+        let usecase = AddMuteRule::new(Arc::new(mock_user_repo));
+        let id = UserId::from("u1");
+        let rule = usecase
+            .execute(&id, MuteKind::Keyword, "crypto", Some(7 * 24 * 3600), 1000)
+            .await
+            .unwrap();
+        assert_eq!(rule.pattern, "crypto");
+
+        let invalid = usecase.execute(&id, MuteKind::Regex, "(", None, 1000).await;
+        assert!(matches!(invalid, Err(DomainError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_hides_expired_rules() {
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        profile.add_mute(MuteRule::new(MuteKind::Author, "bot", Some(10), 0).unwrap(), 0).unwrap();
+        profile.add_mute(MuteRule::new(MuteKind::Domain, "spam.example", None, 0).unwrap(), 0).unwrap();
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(move |_| Ok(Some(profile.clone())));
+
+        let rules = ListMuteRules::new(Arc::new(mock_user_repo))
+            .execute(&UserId::from("u1"), 100)
+            .await
+            .unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].kind, MuteKind::Domain);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use techpulse_domain::article::ArticleId;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{ArticleRepo, InteractionRepo, SnapshotRepo, TimelineRepo, TrendRepo};
+use techpulse_domain::retention::{PruneReport, RetentionPolicy};
+
+pub struct PruneData {
+    article_repo: Arc<dyn ArticleRepo>,
+    snapshot_repo: Arc<dyn SnapshotRepo>,
+    trend_repo: Arc<dyn TrendRepo>,
+    timeline_repo: Arc<dyn TimelineRepo>,
+    interaction_repo: Arc<dyn InteractionRepo>,
+    policy: RetentionPolicy,
+}
+
+impl PruneData {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        snapshot_repo: Arc<dyn SnapshotRepo>,
+        trend_repo: Arc<dyn TrendRepo>,
+        timeline_repo: Arc<dyn TimelineRepo>,
+        interaction_repo: Arc<dyn InteractionRepo>,
+        policy: RetentionPolicy,
+    ) -> Self {
+        Self {
+            article_repo,
+            snapshot_repo,
+            trend_repo,
+            timeline_repo,
+            interaction_repo,
+            policy,
+        }
+    }
+
+    /// Deletes data past the retention policy, keeping articles the timeline links to or users bookmarked.
+    pub async fn execute(&self, now: i64) -> Result<PruneReport, DomainError> {
+        let mut keep: HashSet<ArticleId> = self.interaction_repo.bookmarked_articles().await?;
+        keep.extend(
+            self.timeline_repo
+                .list_events()
+                .await?
+                .into_iter()
+                .filter_map(|e| e.article_id),
+        );
+
+        let article_cutoff = self.policy.article_cutoff(now);
+        Ok(PruneReport {
+            articles: self.article_repo.prune(article_cutoff, &keep).await?,
+            snapshots: self.snapshot_repo.prune(article_cutoff, &keep).await?,
+            trend_reports: self.trend_repo.prune(self.policy.trend_report_cutoff(now)).await?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::NaiveDate;
+    use mockall::mock;
+    use techpulse_domain::article::{Article, ArticleSnapshot, SaveOutcome};
+    use techpulse_domain::interaction::InteractionEvent;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::trend::{TimelineEvent, TimelineEventId, TrendReport};
+    use techpulse_domain::user::UserId;
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub SnapshotRepo {}
+        #[async_trait]
+        impl SnapshotRepo for SnapshotRepo {
+            async fn append(&self, snapshots: &[ArticleSnapshot]) -> Result<(), DomainError>;
+            async fn list_for_article(&self, article_id: &ArticleId) -> Result<Vec<ArticleSnapshot>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TrendRepo {}
+        #[async_trait]
+        impl TrendRepo for TrendRepo {
+            async fn save_report(&self, report: &TrendReport) -> Result<(), DomainError>;
+            async fn find_latest_report(&self) -> Result<Option<TrendReport>, DomainError>;
+            async fn prune(&self, before: i64) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TimelineRepo {}
+        #[async_trait]
+        impl TimelineRepo for TimelineRepo {
+            async fn save_event(&self, event: &TimelineEvent) -> Result<(), DomainError>;
+            async fn find_event(&self, id: &TimelineEventId) -> Result<Option<TimelineEvent>, DomainError>;
+            async fn list_events(&self) -> Result<Vec<TimelineEvent>, DomainError>;
+            async fn delete_event(&self, id: &TimelineEventId) -> Result<(), DomainError>;
+        }
+    }
+
+    mock! {
+        pub InteractionRepo {}
+        #[async_trait]
+        impl InteractionRepo for InteractionRepo {
+            async fn append(&self, events: &[InteractionEvent]) -> Result<(), DomainError>;
+            async fn list_for_user(&self, user_id: &UserId, since: i64) -> Result<Vec<InteractionEvent>, DomainError>;
+            async fn bookmarked_articles(&self) -> Result<HashSet<ArticleId>, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prune_keeps_linked_and_bookmarked_articles() {
+        const DAY: i64 = 24 * 3600;
+        let now = 1_000 * DAY;
+        let id = |s: &str| ArticleId::parse(s).unwrap();
+        let expected_keep: HashSet<ArticleId> = [id("hn-1"), id("hn-2")].into_iter().collect();
+
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_list_events().returning(move || {
+            Ok(vec![
+                TimelineEvent {
+                    id: TimelineEventId::from("rust-1-0"),
+                    title: "Rust 1.0".into(),
+                    date: NaiveDate::from_ymd_opt(2015, 5, 15).unwrap(),
+                    description: String::new(),
+                    category: "language".into(),
+                    importance_score: 1.0,
+                    url: None,
+                    article_id: Some(id("hn-1")),
+                },
+                TimelineEvent {
+                    id: TimelineEventId::from("manual"),
+                    title: "Manual entry".into(),
+                    date: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                    description: String::new(),
+                    category: "misc".into(),
+                    importance_score: 0.5,
+                    url: None,
+                    article_id: None,
+                },
+            ])
+        });
+        let mut interaction_repo = MockInteractionRepo::new();
+        interaction_repo
+            .expect_bookmarked_articles()
+            .returning(move || Ok([id("hn-2")].into_iter().collect()));
+
+        let mut article_repo = MockArticleRepo::new();
+        let keep = expected_keep.clone();
+        article_repo
+            .expect_prune()
+            .times(1)
+            .withf(move |before, k| *before == 820 * DAY && *k == keep)
+            .returning(|_, _| Ok(7));
+        let mut snapshot_repo = MockSnapshotRepo::new();
+        let keep = expected_keep;
+        snapshot_repo
+            .expect_prune()
+            .times(1)
+            .withf(move |before, k| *before == 820 * DAY && *k == keep)
+            .returning(|_, _| Ok(40));
+        let mut trend_repo = MockTrendRepo::new();
+        trend_repo
+            .expect_prune()
+            .times(1)
+            .withf(|before| *before == 270 * DAY)
+            .returning(|_| Ok(3));
+
+        let usecase = PruneData::new(
+            Arc::new(article_repo),
+            Arc::new(snapshot_repo),
+            Arc::new(trend_repo),
+            Arc::new(timeline_repo),
+            Arc::new(interaction_repo),
+            RetentionPolicy::default(),
+        );
+        let report = usecase.execute(now).await.unwrap();
+        assert_eq!(report, PruneReport { articles: 7, snapshots: 40, trend_reports: 3 });
+        assert_eq!(report.total(), 50);
+    }
+}
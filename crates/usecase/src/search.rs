@@ -0,0 +1,110 @@
+use std::sync::Arc;
+use techpulse_domain::article::Article;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::ArticleRepo;
+use techpulse_domain::search::{SearchQuery, SearchRanking};
+
+// Relevance candidates considered per requested result, so fresher but slightly less relevant
+// stories can still surface
+const CANDIDATE_FACTOR: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    pub article: Article,
+    pub snippet: String, // Matches wrapped in <mark> tags
+    pub relevance: f64,
+    pub score: f64, // Relevance blended with recency
+}
+
+pub struct SearchArticles {
+    article_repo: Arc<dyn ArticleRepo>,
+    ranking: SearchRanking,
+}
+
+impl SearchArticles {
+    pub fn new(article_repo: Arc<dyn ArticleRepo>, ranking: SearchRanking) -> Self {
+        Self { article_repo, ranking }
+    }
+
+    /// Articles matching `query`, best blended score first.
+    pub async fn execute(&self, query: &str, limit: usize, now: i64) -> Result<Vec<SearchResult>, DomainError> {
+        let query: SearchQuery = query.parse()?;
+        let hits = self.article_repo.search(&query, limit * CANDIDATE_FACTOR).await?;
+
+        let mut results: Vec<SearchResult> = hits
+            .into_iter()
+            .map(|hit| SearchResult {
+                score: self.ranking.score(hit.relevance, hit.article.timestamp, now),
+                article: hit.article,
+                snippet: hit.snippet,
+                relevance: hit.relevance,
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| b.article.timestamp.cmp(&a.article.timestamp))
+        });
+        results.truncate(limit);
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::article::{ArticleId, SaveOutcome, Source};
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::SearchHit;
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_recency_reorders_close_matches() {
+        let now = 30 * 24 * 3600;
+        let hit = |id: &str, relevance: f64, age_days: i64| SearchHit {
+            article: Article::new(Source::HackerNews, id, format!("Story {}", id), "".into(), now - age_days * 24 * 3600).unwrap(),
+            relevance,
+            snippet: format!("<mark>Story</mark> {}", id),
+        };
+        let hits = vec![hit("old", 12.0, 20), hit("fresh", 10.0, 0), hit("stale", 2.0, 0)];
+
+        let mut repo = MockArticleRepo::new();
+        repo.expect_search()
+            .withf(|q, limit| q.to_string() == "story" && *limit == 10)
+            .times(1)
+            .returning(move |_, _| Ok(hits.clone()));
+
+        let results = SearchArticles::new(Arc::new(repo), SearchRanking::default())
+            .execute("Story", 2, now)
+            .await
+            .unwrap();
+        let ids: Vec<String> = results.iter().map(|r| r.article.id.to_string()).collect();
+        assert_eq!(ids, vec!["hn-fresh", "hn-old"]);
+        assert_eq!(results[0].score, 10.0);
+        assert_eq!(results[1].relevance, 12.0);
+        assert!(results[1].score < results[1].relevance);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_query_is_rejected() {
+        let usecase = SearchArticles::new(Arc::new(MockArticleRepo::new()), SearchRanking::default());
+        assert!(matches!(usecase.execute("\"unterminated", 10, 0).await, Err(DomainError::Validation(_))));
+        assert!(matches!(usecase.execute("  ", 10, 0).await, Err(DomainError::Validation(_))));
+    }
+}
@@ -0,0 +1,623 @@
+use chrono::{DateTime, Datelike, NaiveDate};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+use techpulse_domain::article::ArticleId;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::event::EventCluster;
+use techpulse_domain::repository::{ArticleRepo, TimelineRepo, TopicRepo};
+use techpulse_domain::time::TimeWindow;
+use techpulse_domain::topic::{TopicCategory, TopicTaxonomy};
+use techpulse_domain::trend::{MilestonePolicy, TimelineEvent, TimelineEventId};
+
+use crate::clustering::ClusterStories;
+use crate::cooccurrence::article_topics;
+use crate::topics::load_taxonomy;
+
+pub struct GenerateTimeline {
+    clusterer: Arc<ClusterStories>,
+    topic_repo: Arc<dyn TopicRepo>,
+    timeline_repo: Arc<dyn TimelineRepo>,
+    policy: MilestonePolicy,
+}
+
+impl GenerateTimeline {
+    pub fn new(
+        clusterer: Arc<ClusterStories>,
+        topic_repo: Arc<dyn TopicRepo>,
+        timeline_repo: Arc<dyn TimelineRepo>,
+        policy: MilestonePolicy,
+    ) -> Self {
+        Self {
+            clusterer,
+            topic_repo,
+            timeline_repo,
+            policy,
+        }
+    }
+
+    /// Promotes significant events (single articles included) in `window` and returns the new milestones.
+    pub async fn execute(&self, window: TimeWindow, now: i64) -> Result<Vec<TimelineEvent>, DomainError> {
+        let clusters = self.clusterer.execute(window, now).await?;
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let mut existing = self.timeline_repo.list_events().await?;
+
+        let scores: Vec<f64> = clusters.iter().map(|c| c.score).collect();
+        let mut created = Vec::new();
+
+        for cluster in &clusters {
+            let percentile = MilestonePolicy::percentile_rank(cluster.score, &scores);
+            if !self.policy.qualifies(percentile, clusters.len(), cluster.sources.len()) {
+                continue;
+            }
+
+            let id = TimelineEventId::from(format!("tl-{}", cluster.id).as_str());
+            let date = to_date(cluster.first_seen)?;
+            let duplicate = existing
+                .iter()
+                .any(|e| e.id == id || self.policy.is_duplicate(e, &cluster.headline, date));
+            if duplicate {
+                continue;
+            }
+
+            let event = TimelineEvent {
+                id,
+                title: cluster.headline.clone(),
+                date,
+                description: describe(cluster),
+                category: dominant_category(&taxonomy, &cluster.topics).to_string(),
+                importance_score: self.policy.importance(percentile, cluster.sources.len()),
+                url: Some(cluster.url.clone()).filter(|u| !u.is_empty()),
+                article_id: Some(cluster.representative.clone()),
+            };
+
+            self.timeline_repo.save_event(&event).await?;
+            existing.push(event.clone());
+            created.push(event);
+        }
+
+        Ok(created)
+    }
+}
+
+pub(crate) fn to_date(timestamp: i64) -> Result<NaiveDate, DomainError> {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.date_naive())
+        .ok_or_else(|| DomainError::Validation(format!("Timestamp {} out of range", timestamp)))
+}
+
+fn describe(cluster: &EventCluster) -> String {
+    format!(
+        "Covered by {} article(s) across {}",
+        cluster.articles.len(),
+        cluster.sources.join(", ")
+    )
+}
+
+// Most frequent known category among the topics; Other if none are known
+fn dominant_category(taxonomy: &TopicTaxonomy, topics: &BTreeSet<String>) -> TopicCategory {
+    let mut counts: HashMap<TopicCategory, usize> = HashMap::new();
+    for topic in topics {
+        let category = taxonomy.category_of(topic);
+        if category != TopicCategory::Other {
+            *counts.entry(category).or_insert(0) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))
+        .map(|(category, _)| category)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TimelineFilter {
+    pub category: Option<String>,
+    pub from: Option<NaiveDate>, // Inclusive
+    pub to: Option<NaiveDate>,   // Inclusive
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelineMonth {
+    pub year: i32,
+    pub month: u32,
+    pub events: Vec<TimelineEvent>, // Newest first
+}
+
+pub struct GetTimeline {
+    timeline_repo: Arc<dyn TimelineRepo>,
+}
+
+impl GetTimeline {
+    pub fn new(timeline_repo: Arc<dyn TimelineRepo>) -> Self {
+        Self { timeline_repo }
+    }
+
+    /// Matching events grouped by year/month, newest month first.
+    pub async fn execute(&self, filter: &TimelineFilter) -> Result<Vec<TimelineMonth>, DomainError> {
+        if let (Some(from), Some(to)) = (filter.from, filter.to) {
+            if from > to {
+                return Err(DomainError::Validation("'from' must not be after 'to'".to_string()));
+            }
+        }
+
+        let events = self.timeline_repo.list_events().await?;
+        let mut months: BTreeMap<(i32, u32), Vec<TimelineEvent>> = BTreeMap::new();
+
+        for event in events {
+            let category_matches = filter
+                .category
+                .as_ref()
+                .is_none_or(|c| event.category.eq_ignore_ascii_case(c));
+            let in_range = filter.from.is_none_or(|from| event.date >= from)
+                && filter.to.is_none_or(|to| event.date <= to);
+
+            if category_matches && in_range {
+                months
+                    .entry((event.date.year(), event.date.month()))
+                    .or_default()
+                    .push(event);
+            }
+        }
+
+        Ok(months
+            .into_iter()
+            .rev()
+            .map(|((year, month), mut events)| {
+                events.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| b.importance_score.total_cmp(&a.importance_score)));
+                TimelineMonth { year, month, events }
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PinOptions {
+    pub category: Option<String>,
+    pub description: Option<String>,
+    pub importance_score: Option<f64>,
+}
+
+// Manual milestones outrank anything the generator promotes by default
+const PINNED_IMPORTANCE: f64 = 100.0;
+
+pub struct PinArticle {
+    article_repo: Arc<dyn ArticleRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+    timeline_repo: Arc<dyn TimelineRepo>,
+}
+
+impl PinArticle {
+    pub fn new(
+        article_repo: Arc<dyn ArticleRepo>,
+        topic_repo: Arc<dyn TopicRepo>,
+        timeline_repo: Arc<dyn TimelineRepo>,
+    ) -> Self {
+        Self {
+            article_repo,
+            topic_repo,
+            timeline_repo,
+        }
+    }
+
+    /// Creates a milestone from an article, copying its title, date and URL. Without a category
+    /// in `options`, it takes the dominant category of the article's topics.
+    pub async fn execute(&self, article_id: &ArticleId, options: PinOptions) -> Result<TimelineEvent, DomainError> {
+        let article = self
+            .article_repo
+            .find_by_id(article_id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Article {}", article_id)))?;
+
+        let id = TimelineEventId::from(format!("pin-{}", article.id).as_str());
+        if self.timeline_repo.find_event(&id).await?.is_some() {
+            return Err(DomainError::AlreadyExists(format!("Timeline event {}", id)));
+        }
+
+        let category = match options.category {
+            Some(category) => category.parse::<TopicCategory>()?,
+            None => {
+                let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+                dominant_category(&taxonomy, &article_topics(&taxonomy, &article.tags, &article.title))
+            }
+        };
+        let event = TimelineEvent {
+            id,
+            title: article.title.clone(),
+            date: to_date(article.timestamp)?,
+            description: options.description.unwrap_or_default(),
+            category: category.to_string(),
+            importance_score: options.importance_score.unwrap_or(PINNED_IMPORTANCE),
+            url: Some(article.url.clone()).filter(|u| !u.is_empty()),
+            article_id: Some(article.id.clone()),
+        };
+
+        self.timeline_repo.save_event(&event).await?;
+        Ok(event)
+    }
+}
+
+/// Partial update; `None` fields are left unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct TimelineEventPatch {
+    pub title: Option<String>,
+    pub date: Option<NaiveDate>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    pub importance_score: Option<f64>,
+    pub url: Option<String>,
+}
+
+pub struct UpdateTimelineEvent {
+    timeline_repo: Arc<dyn TimelineRepo>,
+}
+
+impl UpdateTimelineEvent {
+    pub fn new(timeline_repo: Arc<dyn TimelineRepo>) -> Self {
+        Self { timeline_repo }
+    }
+
+    pub async fn execute(&self, id: &TimelineEventId, patch: TimelineEventPatch) -> Result<TimelineEvent, DomainError> {
+        let mut event = self
+            .timeline_repo
+            .find_event(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("Timeline event {}", id)))?;
+
+        if let Some(title) = patch.title {
+            if title.trim().is_empty() {
+                return Err(DomainError::Validation("Title cannot be empty".to_string()));
+            }
+            event.title = title;
+        }
+        if let Some(date) = patch.date {
+            event.date = date;
+        }
+        if let Some(description) = patch.description {
+            event.description = description;
+        }
+        if let Some(category) = patch.category {
+            event.category = category.parse::<TopicCategory>()?.to_string();
+        }
+        if let Some(importance_score) = patch.importance_score {
+            event.importance_score = importance_score;
+        }
+        if let Some(url) = patch.url {
+            event.url = Some(url).filter(|u| !u.is_empty());
+        }
+
+        self.timeline_repo.save_event(&event).await?;
+        Ok(event)
+    }
+}
+
+pub struct DeleteTimelineEvent {
+    timeline_repo: Arc<dyn TimelineRepo>,
+}
+
+impl DeleteTimelineEvent {
+    pub fn new(timeline_repo: Arc<dyn TimelineRepo>) -> Self {
+        Self { timeline_repo }
+    }
+
+    pub async fn execute(&self, id: &TimelineEventId) -> Result<(), DomainError> {
+        if self.timeline_repo.find_event(id).await?.is_none() {
+            return Err(DomainError::NotFound(format!("Timeline event {}", id)));
+        }
+        self.timeline_repo.delete_event(id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{Article, SaveOutcome, Source};
+    use techpulse_domain::event::ClusteringPolicy;
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+
+    mock! {
+        pub ArticleRepo {}
+        #[async_trait]
+        impl ArticleRepo for ArticleRepo {
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
+            async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
+            async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TimelineRepo {}
+        #[async_trait]
+        impl TimelineRepo for TimelineRepo {
+            async fn save_event(&self, event: &TimelineEvent) -> Result<(), DomainError>;
+            async fn find_event(&self, id: &TimelineEventId) -> Result<Option<TimelineEvent>, DomainError>;
+            async fn list_events(&self) -> Result<Vec<TimelineEvent>, DomainError>;
+            async fn delete_event(&self, id: &TimelineEventId) -> Result<(), DomainError>;
+        }
+    }
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn article(source: Source, id: &str, title: &str, score: f64) -> Article {
+        let mut a = Article::new(source, id, title.into(), "".into(), NOW - 3600).unwrap();
+        a.score = score;
+        a
+    }
+
+    fn topic_repo() -> Arc<MockTopicRepo> {
+        let mut repo = MockTopicRepo::new();
+        repo.expect_list_all().returning(|| Ok(default_topics()));
+        Arc::new(repo)
+    }
+
+    fn usecase(articles: Vec<Article>, timeline_repo: MockTimelineRepo, policy: MilestonePolicy) -> GenerateTimeline {
+        let mut article_repo = MockArticleRepo::new();
+        article_repo.expect_query().returning(move |q| {
+            let articles = articles.iter().filter(|a| q.matches(a)).cloned().collect();
+            Ok(ArticlePage { articles, next_cursor: None })
+        });
+        let clusterer = ClusterStories::new(Arc::new(article_repo), topic_repo(), ClusteringPolicy::default());
+        GenerateTimeline::new(Arc::new(clusterer), topic_repo(), Arc::new(timeline_repo), policy)
+    }
+
+    fn window() -> TimeWindow {
+        TimeWindow::ending_at(NOW, 24 * 3600).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_promotes_top_percentile_and_multi_source_events() {
+        let articles = vec![
+            // Multi-source event with low scores
+            article(Source::HackerNews, "1", "OpenAI releases GPT-5", 1.0),
+            article(Source::GitHub, "2", "GPT-5 released by OpenAI", 1.0),
+            article(Source::Reddit("ml".into()), "3", "OpenAI GPT-5 release thread", 1.0),
+            // Single high scoring article about Rust
+            article(Source::HackerNews, "4", "Rust 2.0 is out", 500.0),
+            // Ordinary single article
+            article(Source::HackerNews, "5", "A tour of my homelab", 5.0),
+        ];
+
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_list_events().returning(|| Ok(vec![]));
+        timeline_repo.expect_save_event().times(2).returning(|_| Ok(()));
+
+        let policy = MilestonePolicy { top_percentile: 0.9, min_candidates: 3, ..Default::default() };
+        let created = usecase(articles, timeline_repo, policy).execute(window(), NOW).await.unwrap();
+
+        assert_eq!(created.len(), 2);
+        let rust = created.iter().find(|e| e.title == "Rust 2.0 is out").unwrap();
+        assert_eq!(rust.id.to_string(), "tl-evt-hn-4");
+        assert_eq!(rust.category, "languages");
+        assert_eq!(rust.date, to_date(NOW - 3600).unwrap());
+        assert_eq!(rust.article_id.as_ref().unwrap().to_string(), "hn-4");
+
+        // Same-timestamp members: the event is named after the lowest article ID
+        let gpt = created.iter().find(|e| e.id.to_string() == "tl-evt-gh-2").unwrap();
+        assert_eq!(gpt.category, "ai");
+        assert!(gpt.description.contains("3 article(s)"));
+    }
+
+    #[tokio::test]
+    async fn test_single_low_score_event_is_not_a_milestone() {
+        let articles = vec![article(Source::HackerNews, "1", "A tour of my homelab", 5.0)];
+
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_list_events().returning(|| Ok(vec![]));
+        timeline_repo.expect_save_event().times(0);
+
+        let created = usecase(articles, timeline_repo, MilestonePolicy::default()).execute(window(), NOW).await.unwrap();
+        assert!(created.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_skips_existing_and_similar_events() {
+        let articles = vec![
+            article(Source::HackerNews, "4", "Rust 2.0 is out", 500.0),
+            article(Source::HackerNews, "6", "Kubernetes 2.0 announced", 400.0),
+        ];
+        let existing = vec![
+            TimelineEvent {
+                id: TimelineEventId::from("tl-evt-hn-4"),
+                title: "Rust 2.0 is out".into(),
+                date: to_date(NOW).unwrap(),
+                description: "".into(),
+                category: "languages".into(),
+                importance_score: 100.0,
+                url: None,
+                article_id: None,
+            },
+            TimelineEvent {
+                id: TimelineEventId::from("manual-1"),
+                title: "Kubernetes 2.0 announced today".into(),
+                date: to_date(NOW - 2 * 24 * 3600).unwrap(),
+                description: "".into(),
+                category: "cloud".into(),
+                importance_score: 100.0,
+                url: None,
+                article_id: None,
+            },
+        ];
+
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_list_events().returning(move || Ok(existing.clone()));
+        timeline_repo.expect_save_event().times(0);
+
+        let policy = MilestonePolicy { top_percentile: 0.0, min_candidates: 0, ..Default::default() };
+        let created = usecase(articles, timeline_repo, policy).execute(window(), NOW).await.unwrap();
+
+        assert!(created.is_empty());
+    }
+
+    fn milestone(id: &str, date: (i32, u32, u32), category: &str) -> TimelineEvent {
+        TimelineEvent {
+            id: TimelineEventId::from(id),
+            title: id.to_uppercase(),
+            date: NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap(),
+            description: "".into(),
+            category: category.into(),
+            importance_score: 50.0,
+            url: None,
+            article_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_timeline_groups_and_filters() {
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_list_events().returning(|| {
+            Ok(vec![
+                milestone("a", (2024, 3, 20), "ai"),
+                milestone("b", (2024, 3, 2), "AI"),
+                milestone("c", (2024, 1, 5), "web"),
+                milestone("d", (2023, 12, 31), "ai"),
+            ])
+        });
+        let usecase = GetTimeline::new(Arc::new(timeline_repo));
+
+        let all = usecase.execute(&TimelineFilter::default()).await.unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!((all[0].year, all[0].month), (2024, 3));
+        assert_eq!(all[0].events[0].id.to_string(), "a"); // Newest first within the month
+        assert_eq!((all[2].year, all[2].month), (2023, 12));
+
+        let filter = TimelineFilter {
+            category: Some("ai".into()),
+            from: NaiveDate::from_ymd_opt(2024, 1, 1),
+            to: None,
+        };
+        let ai_2024 = usecase.execute(&filter).await.unwrap();
+        assert_eq!(ai_2024.len(), 1);
+        assert_eq!(ai_2024[0].events.len(), 2); // Category match is case-insensitive
+
+        let invalid = TimelineFilter {
+            from: NaiveDate::from_ymd_opt(2024, 2, 1),
+            to: NaiveDate::from_ymd_opt(2024, 1, 1),
+            ..Default::default()
+        };
+        assert!(matches!(usecase.execute(&invalid).await, Err(DomainError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_pin_article_copies_fields() {
+        let mut article = Article::new(Source::HackerNews, "42", "Rust 2.0".into(), "http://rust".into(), NOW).unwrap();
+        article.score = 10.0;
+        let article_id = article.id.clone();
+
+        let mut article_repo = MockArticleRepo::new();
+        article_repo.expect_find_by_id().returning(move |_| Ok(Some(article.clone())));
+
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_find_event().returning(|_| Ok(None));
+        timeline_repo.expect_save_event().times(1).returning(|_| Ok(()));
+
+        let usecase = PinArticle::new(Arc::new(article_repo), Arc::new(MockTopicRepo::new()), Arc::new(timeline_repo));
+        let options = PinOptions { category: Some("Languages".into()), ..Default::default() };
+        let event = usecase.execute(&article_id, options).await.unwrap();
+
+        assert_eq!(event.id.to_string(), "pin-hn-42");
+        assert_eq!(event.title, "Rust 2.0");
+        assert_eq!(event.url.as_deref(), Some("http://rust"));
+        assert_eq!(event.date, to_date(NOW).unwrap());
+        assert_eq!(event.category, "languages");
+        assert_eq!(event.importance_score, PINNED_IMPORTANCE);
+        assert_eq!(event.article_id, Some(article_id));
+    }
+
+    #[tokio::test]
+    async fn test_pin_category_is_validated_or_derived() {
+        let mut article = Article::new(Source::HackerNews, "7", "GPT-5 released".into(), "".into(), NOW).unwrap();
+        article.tags.insert("llm".into());
+        let article_id = article.id.clone();
+
+        let mut article_repo = MockArticleRepo::new();
+        article_repo.expect_find_by_id().returning(move |_| Ok(Some(article.clone())));
+        let mut topic_repo = MockTopicRepo::new();
+        topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_find_event().returning(|_| Ok(None));
+        timeline_repo.expect_save_event().times(1).returning(|_| Ok(()));
+
+        let usecase = PinArticle::new(Arc::new(article_repo), Arc::new(topic_repo), Arc::new(timeline_repo));
+        let options = PinOptions { category: Some("gossip".into()), ..Default::default() };
+        assert!(matches!(usecase.execute(&article_id, options).await, Err(DomainError::Validation(_))));
+
+        let event = usecase.execute(&article_id, PinOptions::default()).await.unwrap();
+        assert_eq!(event.category, "ai");
+    }
+
+    #[tokio::test]
+    async fn test_pin_missing_or_duplicate() {
+        let mut article_repo = MockArticleRepo::new();
+        article_repo.expect_find_by_id().returning(|id| {
+            if id.to_string() == "hn-1" {
+                Ok(Some(Article::new(Source::HackerNews, "1", "T".into(), "".into(), NOW).unwrap()))
+            } else {
+                Ok(None)
+            }
+        });
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_find_event().returning(|id| Ok(Some(milestone(&id.to_string(), (2024, 1, 1), ""))));
+
+        let usecase = PinArticle::new(Arc::new(article_repo), Arc::new(MockTopicRepo::new()), Arc::new(timeline_repo));
+
+        let missing = usecase.execute(&ArticleId::from_persisted("hn-2".into()), PinOptions::default()).await;
+        assert!(matches!(missing, Err(DomainError::NotFound(_))));
+
+        let duplicate = usecase.execute(&ArticleId::from_persisted("hn-1".into()), PinOptions::default()).await;
+        assert!(matches!(duplicate, Err(DomainError::AlreadyExists(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_delete_event() {
+        let mut timeline_repo = MockTimelineRepo::new();
+        timeline_repo.expect_find_event().returning(|id| {
+            if id.to_string() == "a" {
+                Ok(Some(milestone("a", (2024, 1, 1), "ai")))
+            } else {
+                Ok(None)
+            }
+        });
+        timeline_repo
+            .expect_save_event()
+            .withf(|e| e.title == "Renamed" && e.category == "ai")
+            .times(1)
+            .returning(|_| Ok(()));
+        timeline_repo.expect_delete_event().times(1).returning(|_| Ok(()));
+        let timeline_repo = Arc::new(timeline_repo);
+
+        let update = UpdateTimelineEvent::new(timeline_repo.clone());
+        let patch = TimelineEventPatch { title: Some("Renamed".into()), ..Default::default() };
+        let updated = update.execute(&TimelineEventId::from("a"), patch).await.unwrap();
+        assert_eq!(updated.title, "Renamed");
+
+        let missing = update.execute(&TimelineEventId::from("zzz"), TimelineEventPatch::default()).await;
+        assert!(matches!(missing, Err(DomainError::NotFound(_))));
+
+        let blank = TimelineEventPatch { title: Some(" ".into()), ..Default::default() };
+        assert!(matches!(update.execute(&TimelineEventId::from("a"), blank).await, Err(DomainError::Validation(_))));
+        let unknown = TimelineEventPatch { category: Some("gossip".into()), ..Default::default() };
+        assert!(matches!(update.execute(&TimelineEventId::from("a"), unknown).await, Err(DomainError::Validation(_))));
+
+        let delete = DeleteTimelineEvent::new(timeline_repo);
+        delete.execute(&TimelineEventId::from("a")).await.unwrap();
+        assert!(matches!(delete.execute(&TimelineEventId::from("zzz")).await, Err(DomainError::NotFound(_))));
+    }
+}
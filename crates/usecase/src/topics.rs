@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{TopicRepo, TrendRepo};
+use techpulse_domain::topic::{default_topics, Topic, TopicCategory, TopicTaxonomy};
+
+/// Loads every known topic into a lookup taxonomy.
+pub async fn load_taxonomy(repo: &dyn TopicRepo) -> Result<TopicTaxonomy, DomainError> {
+    Ok(TopicTaxonomy::new(repo.list_all().await?))
+}
+
+pub struct SeedDefaultTopics {
+    repo: Arc<dyn TopicRepo>,
+}
+
+impl SeedDefaultTopics {
+    pub fn new(repo: Arc<dyn TopicRepo>) -> Self {
+        Self { repo }
+    }
+
+    /// Inserts built-in topics that are not yet stored. Existing (possibly edited) topics are kept.
+    pub async fn execute(&self) -> Result<usize, DomainError> {
+        let mut inserted = 0;
+        for topic in default_topics() {
+            if self.repo.find_by_slug(&topic.slug).await?.is_none() {
+                self.repo.save(&topic).await?;
+                inserted += 1;
+            }
+        }
+        Ok(inserted)
+    }
+}
+
+pub struct ListTopics {
+    repo: Arc<dyn TopicRepo>,
+}
+
+impl ListTopics {
+    pub fn new(repo: Arc<dyn TopicRepo>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, category: Option<TopicCategory>) -> Result<Vec<Topic>, DomainError> {
+        let topics = self.repo.list_all().await?;
+        Ok(match category {
+            Some(category) => topics.into_iter().filter(|t| t.category == category).collect(),
+            None => topics,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoryRollup {
+    pub category: TopicCategory,
+    pub score: f64,
+    pub volume: u32,
+    pub topics: Vec<String>, // Canonical slugs contributing to this category
+}
+
+pub struct GetCategoryRollup {
+    topic_repo: Arc<dyn TopicRepo>,
+    trend_repo: Arc<dyn TrendRepo>,
+}
+
+impl GetCategoryRollup {
+    pub fn new(topic_repo: Arc<dyn TopicRepo>, trend_repo: Arc<dyn TrendRepo>) -> Self {
+        Self {
+            topic_repo,
+            trend_repo,
+        }
+    }
+
+    /// Aggregates the latest trend report by topic category, highest score first.
+    pub async fn execute(&self) -> Result<Vec<CategoryRollup>, DomainError> {
+        let Some(report) = self.trend_repo.find_latest_report().await? else {
+            return Ok(Vec::new());
+        };
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+
+        let mut rollups: BTreeMap<TopicCategory, CategoryRollup> = BTreeMap::new();
+        for trend in &report.trends {
+            let category = taxonomy.category_of(&trend.keyword);
+            let slug = taxonomy.canonicalize(&trend.keyword);
+            let entry = rollups.entry(category).or_insert_with(|| CategoryRollup {
+                category,
+                score: 0.0,
+                volume: 0,
+                topics: Vec::new(),
+            });
+            entry.score += trend.score;
+            entry.volume += trend.volume;
+            if !entry.topics.contains(&slug) {
+                entry.topics.push(slug);
+            }
+        }
+
+        let mut list: Vec<CategoryRollup> = rollups.into_values().collect();
+        list.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use std::collections::HashMap;
+    use techpulse_domain::topic::TopicSlug;
+    use techpulse_domain::trend::{Trend, TrendReport};
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TrendRepo {}
+        #[async_trait]
+        impl TrendRepo for TrendRepo {
+            async fn save_report(&self, report: &TrendReport) -> Result<(), DomainError>;
+            async fn find_latest_report(&self) -> Result<Option<TrendReport>, DomainError>;
+            async fn prune(&self, before: i64) -> Result<usize, DomainError>;
+        }
+    }
+
+    fn trend(keyword: &str, score: f64, volume: u32) -> Trend {
+        Trend {
+            keyword: keyword.into(),
+            score,
+            volume,
+            velocity: 0.0,
+            related_articles: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seed_skips_existing_topics() {
+        let mut mock_repo = MockTopicRepo::new();
+        mock_repo
+            .expect_find_by_slug()
+            .returning(|slug| {
+                if slug.as_str() == "rust" {
+                    Ok(Some(Topic::new(slug.clone(), "Rust (edited)", TopicCategory::Languages)))
+                } else {
+                    Ok(None)
+                }
+            });
+        mock_repo
+            .expect_save()
+            .withf(|t| t.slug.as_str() != "rust")
+            .times(default_topics().len() - 1)
+            .returning(|_| Ok(()));
+
+        let usecase = SeedDefaultTopics::new(Arc::new(mock_repo));
+        let inserted = usecase.execute().await.unwrap();
+        assert_eq!(inserted, default_topics().len() - 1);
+    }
+
+    #[tokio::test]
+    async fn test_rollup_groups_aliases_by_category() {
+        let mut mock_topic_repo = MockTopicRepo::new();
+        let mut mock_trend_repo = MockTrendRepo::new();
+
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        mock_trend_repo.expect_find_latest_report().returning(|| {
+            Ok(Some(TrendReport {
+                timestamp: 100,
+                trends: vec![
+                    trend("AI", 30.0, 3),
+                    trend("ChatGPT", 20.0, 2),
+                    trend("Rust", 10.0, 1),
+                    trend("Zig", 5.0, 1),
+                ],
+                metadata: HashMap::new(),
+            }))
+        });
+
+        let usecase = GetCategoryRollup::new(Arc::new(mock_topic_repo), Arc::new(mock_trend_repo));
+        let rollups = usecase.execute().await.unwrap();
+
+        assert_eq!(rollups.len(), 3);
+        assert_eq!(rollups[0].category, TopicCategory::Ai);
+        assert_eq!(rollups[0].volume, 5);
+        assert_eq!(rollups[0].topics, vec!["ai".to_string(), "llm".to_string()]);
+
+        let other = rollups.iter().find(|r| r.category == TopicCategory::Other).unwrap();
+        assert_eq!(other.topics, vec!["zig".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_rollup_without_report() {
+        let mock_topic_repo = MockTopicRepo::new();
+        let mut mock_trend_repo = MockTrendRepo::new();
+        mock_trend_repo.expect_find_latest_report().returning(|| Ok(None));
+
+        let usecase = GetCategoryRollup::new(Arc::new(mock_topic_repo), Arc::new(mock_trend_repo));
+        assert!(usecase.execute().await.unwrap().is_empty());
+    }
+}
@@ -4,10 +4,14 @@ use techpulse_domain::article::ArticleId;
 use techpulse_domain::error::DomainError;
 use techpulse_domain::repository::{ArticleRepo, TrendRepo};
 use techpulse_domain::trend::{Trend, TrendReport};
+use techpulse_domain::webhook::{SpikePolicy, WebhookEvent};
+
+use crate::webhooks::PublishWebhookEvents;
 
 pub struct CalculateTrends {
     article_repo: Arc<dyn ArticleRepo>,
     trend_repo: Arc<dyn TrendRepo>,
+    webhooks: Option<(Arc<PublishWebhookEvents>, SpikePolicy)>,
 }
 
 impl CalculateTrends {
@@ -18,9 +22,16 @@ impl CalculateTrends {
         Self {
             article_repo,
             trend_repo,
+            webhooks: None,
         }
     }
 
+    /// Publishes `trend.spike` and `topic.emerging` by comparing each report with the previous one.
+    pub fn with_publisher(mut self, webhooks: Arc<PublishWebhookEvents>, policy: SpikePolicy) -> Self {
+        self.webhooks = Some((webhooks, policy));
+        self
+    }
+
     pub async fn execute(&self, keywords: &[String], now: i64) -> Result<TrendReport, DomainError> {
         let articles = self.article_repo.find_latest(100).await?;
         
@@ -63,8 +74,21 @@ impl CalculateTrends {
             metadata: HashMap::new(),
         };
         
+        let previous = match self.webhooks {
+            Some(_) => self.trend_repo.find_latest_report().await?,
+            None => None,
+        };
         self.trend_repo.save_report(&report).await?;
-        
+
+        if let Some((webhooks, policy)) = &self.webhooks {
+            let events: Vec<WebhookEvent> = policy
+                .detect(previous.as_ref(), &report)
+                .into_iter()
+                .map(|data| WebhookEvent::new(data, now))
+                .collect();
+            webhooks.execute(&events, now).await?;
+        }
+
         Ok(report)
     }
 }
@@ -72,18 +96,27 @@ impl CalculateTrends {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
     use async_trait::async_trait;
     use mockall::mock;
-    use techpulse_domain::article::{Article, ArticleId};
+    use techpulse_domain::query::{ArticlePage, ArticleQuery};
+    use techpulse_domain::search::{SearchHit, SearchQuery};
+    use techpulse_domain::article::{Article, ArticleId, SaveOutcome};
     use techpulse_domain::article::Source;
+    use techpulse_domain::repository::WebhookRepo;
+    use techpulse_domain::webhook::{WebhookData, WebhookDelivery, WebhookEventType, WebhookId, WebhookSubscription};
 
     mock! {
         pub ArticleRepo {}
         #[async_trait]
         impl ArticleRepo for ArticleRepo {
-            async fn save(&self, article: &Article) -> Result<(), DomainError>;
+            async fn save(&self, article: &Article) -> Result<SaveOutcome, DomainError>;
+            async fn save_many(&self, articles: &[Article]) -> Result<Vec<SaveOutcome>, DomainError>;
             async fn find_by_id(&self, id: &ArticleId) -> Result<Option<Article>, DomainError>;
             async fn find_latest(&self, limit: usize) -> Result<Vec<Article>, DomainError>;
+            async fn query(&self, query: &ArticleQuery) -> Result<ArticlePage, DomainError>;
+            async fn search(&self, query: &SearchQuery, limit: usize) -> Result<Vec<SearchHit>, DomainError>;
+            async fn prune(&self, before: i64, keep: &HashSet<ArticleId>) -> Result<usize, DomainError>;
         }
     }
 
@@ -93,6 +126,7 @@ mod tests {
         impl TrendRepo for TrendRepo {
             async fn save_report(&self, report: &TrendReport) -> Result<(), DomainError>;
             async fn find_latest_report(&self) -> Result<Option<TrendReport>, DomainError>;
+            async fn prune(&self, before: i64) -> Result<usize, DomainError>;
         }
     }
 
@@ -133,6 +167,60 @@ mod tests {
         assert_eq!(rust_trend.related_articles.len(), 2);
     }
 
+    mock! {
+        pub WebhookRepo {}
+        #[async_trait]
+        impl WebhookRepo for WebhookRepo {
+            async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), DomainError>;
+            async fn find_subscription(&self, id: &WebhookId) -> Result<Option<WebhookSubscription>, DomainError>;
+            async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, DomainError>;
+            async fn delete_subscription(&self, id: &WebhookId) -> Result<(), DomainError>;
+            async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), DomainError>;
+            async fn list_due_deliveries(&self, now: i64, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+            async fn list_deliveries(&self, subscription_id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spikes_are_published_to_webhooks() {
+        let mut mock_article_repo = MockArticleRepo::new();
+        let mut mock_trend_repo = MockTrendRepo::new();
+        let mut mock_webhook_repo = MockWebhookRepo::new();
+
+        let articles: Vec<Article> = (0..4)
+            .map(|i| Article::new(Source::HackerNews, &i.to_string(), format!("Rust news {}", i), "".into(), 100).unwrap())
+            .collect();
+        mock_article_repo.expect_find_latest().returning(move |_| Ok(articles.clone()));
+        mock_trend_repo.expect_find_latest_report().returning(|| {
+            Ok(Some(TrendReport {
+                timestamp: 0,
+                trends: vec![Trend {
+                    keyword: "Rust".into(),
+                    score: 10.0,
+                    volume: 1,
+                    velocity: 0.0,
+                    related_articles: vec![],
+                }],
+                metadata: HashMap::new(),
+            }))
+        });
+        mock_trend_repo.expect_save_report().returning(|_| Ok(()));
+
+        let subscription =
+            WebhookSubscription::new("https://hooks.example.com", "0123456789abcdef", vec![WebhookEventType::TrendSpike], &[], 0)
+                .unwrap();
+        mock_webhook_repo.expect_list_subscriptions().returning(move || Ok(vec![subscription.clone()]));
+        mock_webhook_repo
+            .expect_save_delivery()
+            .times(1)
+            .withf(|d| d.event.data == WebhookData::TrendSpike { keyword: "Rust".into(), volume: 4, previous_volume: 1 })
+            .returning(|_| Ok(()));
+
+        let usecase = CalculateTrends::new(Arc::new(mock_article_repo), Arc::new(mock_trend_repo))
+            .with_publisher(Arc::new(PublishWebhookEvents::new(Arc::new(mock_webhook_repo))), SpikePolicy::default());
+        usecase.execute(&["Rust".to_string()], 500).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_empty_articles() {
         let mut mock_article_repo = MockArticleRepo::new();
@@ -0,0 +1,245 @@
+use std::sync::Arc;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{TopicRepo, UserRepo};
+use techpulse_domain::user::{KnowledgeState, TopicKnowledge, UserId, UserProfile, UserSettingsPatch};
+
+use crate::topics::load_taxonomy;
+
+pub struct GetUserProfile {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl GetUserProfile {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, id: &UserId) -> Result<UserProfile, DomainError> {
+        self.user_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User '{}'", id)))
+    }
+}
+
+// Profiles are created lazily on the first write
+pub(crate) async fn load_or_create(repo: &dyn UserRepo, id: &UserId) -> Result<UserProfile, DomainError> {
+    Ok(repo
+        .find_by_id(id)
+        .await?
+        .unwrap_or_else(|| UserProfile::new(id.clone())))
+}
+
+pub struct SetTopicKnowledge {
+    user_repo: Arc<dyn UserRepo>,
+    topic_repo: Arc<dyn TopicRepo>,
+}
+
+impl SetTopicKnowledge {
+    pub fn new(user_repo: Arc<dyn UserRepo>, topic_repo: Arc<dyn TopicRepo>) -> Self {
+        Self {
+            user_repo,
+            topic_repo,
+        }
+    }
+
+    /// Records `state` for `topic` (slug or alias), keyed by its canonical slug.
+    pub async fn execute(
+        &self,
+        id: &UserId,
+        topic: &str,
+        state: KnowledgeState,
+        now: i64,
+    ) -> Result<TopicKnowledge, DomainError> {
+        if topic.trim().is_empty() {
+            return Err(DomainError::Validation("Topic cannot be empty".to_string()));
+        }
+        let taxonomy = load_taxonomy(self.topic_repo.as_ref()).await?;
+        let slug = taxonomy.canonicalize(topic);
+
+        let mut profile = load_or_create(self.user_repo.as_ref(), id).await?;
+        profile.update_knowledge(&slug, state, now);
+        self.user_repo.save(&profile).await?;
+
+        Ok(profile.knowledge.topics[&slug].clone())
+    }
+}
+
+pub struct ListTopicsByState {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl ListTopicsByState {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    /// Topics of the user's knowledge map, most recently touched first.
+    pub async fn execute(
+        &self,
+        id: &UserId,
+        state: Option<KnowledgeState>,
+    ) -> Result<Vec<TopicKnowledge>, DomainError> {
+        let profile = self
+            .user_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User '{}'", id)))?;
+
+        let mut topics: Vec<TopicKnowledge> = profile
+            .knowledge
+            .topics
+            .into_values()
+            .filter(|t| state.as_ref().is_none_or(|s| &t.state == s))
+            .collect();
+        topics.sort_by(|a, b| {
+            b.last_interaction
+                .cmp(&a.last_interaction)
+                .then_with(|| a.topic.cmp(&b.topic))
+        });
+        Ok(topics)
+    }
+}
+
+pub struct UpdateUserSettings {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl UpdateUserSettings {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    /// Applies `patch` to the stored settings; fields it leaves unset keep their value.
+    pub async fn execute(&self, id: &UserId, patch: UserSettingsPatch) -> Result<UserProfile, DomainError> {
+        let mut profile = load_or_create(self.user_repo.as_ref(), id).await?;
+        profile.settings = profile.settings.clone().patched(patch).validate()?;
+        self.user_repo.save(&profile).await?;
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::topic::{default_topics, Topic, TopicSlug};
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub TopicRepo {}
+        #[async_trait]
+        impl TopicRepo for TopicRepo {
+            async fn save(&self, topic: &Topic) -> Result<(), DomainError>;
+            async fn find_by_slug(&self, slug: &TopicSlug) -> Result<Option<Topic>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<Topic>, DomainError>;
+        }
+    }
+
+    #[tokio::test]
+    async fn test_set_knowledge_canonicalizes_topic_and_creates_profile() {
+        let mut mock_user_repo = MockUserRepo::new();
+        let mut mock_topic_repo = MockTopicRepo::new();
+
+        mock_topic_repo.expect_list_all().returning(|| Ok(default_topics()));
+        mock_user_repo.expect_find_by_id().returning(|_| Ok(None));
+        mock_user_repo
+            .expect_save()
+            .times(1)
+            .withf(|u| u.knowledge.topics.contains_key("webassembly"))
+            .returning(|_| Ok(()));
+
+        let usecase = SetTopicKnowledge::new(Arc::new(mock_user_repo), Arc::new(mock_topic_repo));
+        let entry = usecase
+            .execute(&UserId::from("u1"), "WASM", KnowledgeState::WantToLearn, 1000)
+            .await
+            .unwrap();
+
+        assert_eq!(entry.topic, "webassembly");
+        assert_eq!(entry.state, KnowledgeState::WantToLearn);
+        assert_eq!(entry.interaction_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_topics_by_state() {
+        let mut profile = UserProfile::new(UserId::from("u1"));
+        profile.update_knowledge("rust", KnowledgeState::KnowIt, 100);
+        profile.update_knowledge("go", KnowledgeState::HeardOf, 200);
+        profile.update_knowledge("llm", KnowledgeState::KnowIt, 300);
+
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(move |_| Ok(Some(profile.clone())));
+
+        let usecase = ListTopicsByState::new(Arc::new(mock_user_repo));
+        let known = usecase
+            .execute(&UserId::from("u1"), Some(KnowledgeState::KnowIt))
+            .await
+            .unwrap();
+        let slugs: Vec<&str> = known.iter().map(|t| t.topic.as_str()).collect();
+        assert_eq!(slugs, vec!["llm", "rust"]);
+
+        let all = usecase.execute(&UserId::from("u1"), None).await.unwrap();
+        assert_eq!(all.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_unknown_user_is_not_found() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(|_| Ok(None));
+
+        let usecase = GetUserProfile::new(Arc::new(mock_user_repo));
+        let result = usecase.execute(&UserId::from("ghost")).await;
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_settings_rejects_invalid_theme() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(|_| Ok(None));
+        mock_user_repo.expect_save().times(0);
+
+        let usecase = UpdateUserSettings::new(Arc::new(mock_user_repo));
+        let patch = UserSettingsPatch {
+            theme: Some("neon".into()),
+            ..Default::default()
+        };
+        let result = usecase.execute(&UserId::from("u1"), patch).await;
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_settings_keeps_delivery_preferences() {
+        let mut stored = UserProfile::new(UserId::from("u1"));
+        stored.settings.time_zone = "Asia/Tokyo".into();
+        stored.settings.delivery.enabled = true;
+        stored.settings.delivery.email = Some("ada@example.com".into());
+        let expected_delivery = stored.settings.delivery.clone();
+
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_find_by_id().returning(move |_| Ok(Some(stored.clone())));
+        mock_user_repo.expect_save().times(1).returning(|_| Ok(()));
+
+        let usecase = UpdateUserSettings::new(Arc::new(mock_user_repo));
+        let patch = UserSettingsPatch {
+            preferred_sources: Some(vec!["hn".into()]),
+            theme: Some("dark".into()),
+            ..Default::default()
+        };
+        let profile = usecase.execute(&UserId::from("u1"), patch).await.unwrap();
+        assert_eq!(profile.settings.theme, "dark");
+        assert_eq!(profile.settings.time_zone, "Asia/Tokyo");
+        assert_eq!(profile.settings.delivery, expected_delivery);
+    }
+}
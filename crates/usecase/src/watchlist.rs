@@ -0,0 +1,209 @@
+use std::sync::Arc;
+use techpulse_domain::article::Article;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::repository::{AlertRepo, UserRepo};
+use techpulse_domain::user::UserId;
+use techpulse_domain::watchlist::{WatchAlert, WatchRule, WatchRuleId};
+
+use crate::users::load_or_create;
+
+pub struct AddWatchRule {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl AddWatchRule {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    pub async fn execute(&self, id: &UserId, name: &str, expression: &str, now: i64) -> Result<WatchRule, DomainError> {
+        let rule = WatchRule::new(name, expression, now)?;
+        let mut profile = load_or_create(self.user_repo.as_ref(), id).await?;
+        profile.add_watch_rule(rule.clone())?;
+        self.user_repo.save(&profile).await?;
+        Ok(rule)
+    }
+}
+
+pub struct ListWatchRules {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl ListWatchRules {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    /// Rules in the order they were added.
+    pub async fn execute(&self, id: &UserId) -> Result<Vec<WatchRule>, DomainError> {
+        Ok(self
+            .user_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User '{}'", id)))?
+            .watchlist)
+    }
+}
+
+pub struct RemoveWatchRule {
+    user_repo: Arc<dyn UserRepo>,
+}
+
+impl RemoveWatchRule {
+    pub fn new(user_repo: Arc<dyn UserRepo>) -> Self {
+        Self { user_repo }
+    }
+
+    /// Alerts already raised by the rule stay in the user's history.
+    pub async fn execute(&self, id: &UserId, rule_id: &WatchRuleId) -> Result<(), DomainError> {
+        let mut profile = self
+            .user_repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::NotFound(format!("User '{}'", id)))?;
+        if !profile.remove_watch_rule(rule_id) {
+            return Err(DomainError::NotFound(format!("Watch rule {}", rule_id)));
+        }
+        self.user_repo.save(&profile).await
+    }
+}
+
+pub struct EvaluateWatchlists {
+    user_repo: Arc<dyn UserRepo>,
+    alert_repo: Arc<dyn AlertRepo>,
+}
+
+impl EvaluateWatchlists {
+    pub fn new(user_repo: Arc<dyn UserRepo>, alert_repo: Arc<dyn AlertRepo>) -> Self {
+        Self { user_repo, alert_repo }
+    }
+
+    /// Matches `articles` against every user's watchlist and returns how many alerts are new.
+    /// Re-evaluating an article is harmless: each rule alerts once per article.
+    pub async fn execute(&self, articles: &[Article], now: i64) -> Result<usize, DomainError> {
+        if articles.is_empty() {
+            return Ok(0);
+        }
+
+        let mut raised = 0;
+        for user in self.user_repo.list_all().await? {
+            for article in articles {
+                for rule in user.watch_matches(article) {
+                    if self.alert_repo.save(&WatchAlert::new(user.id.clone(), rule, article, now)).await? {
+                        raised += 1;
+                    }
+                }
+            }
+        }
+        Ok(raised)
+    }
+}
+
+pub struct ListAlerts {
+    alert_repo: Arc<dyn AlertRepo>,
+}
+
+impl ListAlerts {
+    pub fn new(alert_repo: Arc<dyn AlertRepo>) -> Self {
+        Self { alert_repo }
+    }
+
+    pub async fn execute(&self, id: &UserId, limit: usize) -> Result<Vec<WatchAlert>, DomainError> {
+        self.alert_repo.list_for_user(id, limit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::article::Source;
+    use techpulse_domain::user::UserProfile;
+
+    mock! {
+        pub UserRepo {}
+        #[async_trait]
+        impl UserRepo for UserRepo {
+            async fn save(&self, user: &UserProfile) -> Result<(), DomainError>;
+            async fn find_by_id(&self, id: &UserId) -> Result<Option<UserProfile>, DomainError>;
+            async fn list_all(&self) -> Result<Vec<UserProfile>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub AlertRepo {}
+        #[async_trait]
+        impl AlertRepo for AlertRepo {
+            async fn save(&self, alert: &WatchAlert) -> Result<bool, DomainError>;
+            async fn list_for_user(&self, user_id: &UserId, limit: usize) -> Result<Vec<WatchAlert>, DomainError>;
+        }
+    }
+
+    fn watcher(id: &str, expressions: &[&str]) -> UserProfile {
+        let mut user = UserProfile::new(UserId::from(id));
+        for expression in expressions {
+            user.add_watch_rule(WatchRule::new("", expression, 0).unwrap()).unwrap();
+        }
+        user
+    }
+
+    #[tokio::test]
+    async fn test_add_rule_validates_and_rejects_duplicates() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(watcher("u1", &["tokio"]))));
+        mock_user_repo
+            .expect_save()
+            .times(1)
+            .withf(|u| u.watchlist.len() == 2 && u.watchlist[1].name == "sqlite")
+            .returning(|_| Ok(()));
+
+        let usecase = AddWatchRule::new(Arc::new(mock_user_repo));
+        let id = UserId::from("u1");
+        let rule = usecase.execute(&id, "sqlite", "domain:sqlite.org OR sqlite", 10).await.unwrap();
+        assert_eq!(rule.expression.to_string(), "domain:sqlite.org OR sqlite");
+
+        assert!(matches!(usecase.execute(&id, "", "tokio", 10).await, Err(DomainError::AlreadyExists(_))));
+        assert!(matches!(usecase.execute(&id, "bad", "(tokio", 10).await, Err(DomainError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_raises_alerts_per_matching_rule() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo.expect_list_all().returning(|| {
+            Ok(vec![
+                watcher("u1", &["tokio", "author:dang"]),
+                watcher("u2", &["postgres"]),
+                UserProfile::new(UserId::from("u3")),
+            ])
+        });
+        let mut mock_alert_repo = MockAlertRepo::new();
+        mock_alert_repo
+            .expect_save()
+            .times(2)
+            .withf(|a| a.user_id == UserId::from("u1") && a.matched_at == 500)
+            .returning(|a| Ok(a.rule_name == "tokio")); // The author alert already exists
+
+        let mut article = Article::new(Source::HackerNews, "1", "Tokio 1.40 released".into(), "".into(), 100).unwrap();
+        article.author = "dang".into();
+        let other = Article::new(Source::HackerNews, "2", "Zig 0.13".into(), "".into(), 100).unwrap();
+
+        let usecase = EvaluateWatchlists::new(Arc::new(mock_user_repo), Arc::new(mock_alert_repo));
+        assert_eq!(usecase.execute(&[article, other], 500).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_remove_unknown_rule_is_not_found() {
+        let mut mock_user_repo = MockUserRepo::new();
+        mock_user_repo
+            .expect_find_by_id()
+            .returning(|_| Ok(Some(watcher("u1", &["tokio"]))));
+        mock_user_repo.expect_save().never();
+
+        let usecase = RemoveWatchRule::new(Arc::new(mock_user_repo));
+        let result = usecase.execute(&UserId::from("u1"), &WatchRuleId::from("nope")).await;
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use techpulse_domain::delivery::RetryPolicy;
+use techpulse_domain::error::DomainError;
+use techpulse_domain::gateway::WebhookGateway;
+use techpulse_domain::repository::WebhookRepo;
+use techpulse_domain::webhook::{WebhookDelivery, WebhookEvent, WebhookEventType, WebhookId, WebhookSubscription};
+
+use crate::delivery::DeliveryReport;
+
+pub struct CreateWebhook {
+    webhook_repo: Arc<dyn WebhookRepo>,
+}
+
+impl CreateWebhook {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepo>) -> Self {
+        Self { webhook_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        url: &str,
+        secret: &str,
+        event_types: Vec<WebhookEventType>,
+        keywords: &[String],
+        now: i64,
+    ) -> Result<WebhookSubscription, DomainError> {
+        let subscription = WebhookSubscription::new(url, secret, event_types, keywords, now)?;
+        self.webhook_repo.save_subscription(&subscription).await?;
+        Ok(subscription)
+    }
+}
+
+pub struct ListWebhooks {
+    webhook_repo: Arc<dyn WebhookRepo>,
+}
+
+impl ListWebhooks {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepo>) -> Self {
+        Self { webhook_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<WebhookSubscription>, DomainError> {
+        self.webhook_repo.list_subscriptions().await
+    }
+}
+
+pub struct DeleteWebhook {
+    webhook_repo: Arc<dyn WebhookRepo>,
+}
+
+impl DeleteWebhook {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepo>) -> Self {
+        Self { webhook_repo }
+    }
+
+    /// Pending deliveries of the subscription are dropped by the next dispatch.
+    pub async fn execute(&self, id: &WebhookId) -> Result<(), DomainError> {
+        if self.webhook_repo.find_subscription(id).await?.is_none() {
+            return Err(DomainError::NotFound(format!("Webhook {}", id)));
+        }
+        self.webhook_repo.delete_subscription(id).await
+    }
+}
+
+pub struct ListWebhookDeliveries {
+    webhook_repo: Arc<dyn WebhookRepo>,
+}
+
+impl ListWebhookDeliveries {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepo>) -> Self {
+        Self { webhook_repo }
+    }
+
+    pub async fn execute(&self, id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError> {
+        if self.webhook_repo.find_subscription(id).await?.is_none() {
+            return Err(DomainError::NotFound(format!("Webhook {}", id)));
+        }
+        self.webhook_repo.list_deliveries(id, limit).await
+    }
+}
+
+/// Queues events for every subscription that wants them; sending happens in `DispatchWebhooks`.
+pub struct PublishWebhookEvents {
+    webhook_repo: Arc<dyn WebhookRepo>,
+}
+
+impl PublishWebhookEvents {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepo>) -> Self {
+        Self { webhook_repo }
+    }
+
+    /// Returns the number of deliveries queued.
+    pub async fn execute(&self, events: &[WebhookEvent], now: i64) -> Result<usize, DomainError> {
+        if events.is_empty() {
+            return Ok(0);
+        }
+        let subscriptions = self.webhook_repo.list_subscriptions().await?;
+
+        let mut queued = 0;
+        for event in events {
+            for subscription in subscriptions.iter().filter(|s| s.wants(event)) {
+                let delivery = WebhookDelivery::new(subscription.id.clone(), event.clone(), now);
+                self.webhook_repo.save_delivery(&delivery).await?;
+                queued += 1;
+            }
+        }
+        Ok(queued)
+    }
+}
+
+pub struct DispatchWebhooks {
+    webhook_repo: Arc<dyn WebhookRepo>,
+    gateway: Arc<dyn WebhookGateway>,
+    policy: RetryPolicy,
+}
+
+impl DispatchWebhooks {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepo>, gateway: Arc<dyn WebhookGateway>, policy: RetryPolicy) -> Self {
+        Self {
+            webhook_repo,
+            gateway,
+            policy,
+        }
+    }
+
+    /// Attempts up to `limit` due deliveries. Non-2xx responses and network errors are retried with backoff.
+    pub async fn execute(&self, now: i64, limit: usize) -> Result<DeliveryReport, DomainError> {
+        let due = self.webhook_repo.list_due_deliveries(now, limit).await?;
+        if due.is_empty() {
+            return Ok(DeliveryReport::default());
+        }
+        let subscriptions: HashMap<WebhookId, WebhookSubscription> = self
+            .webhook_repo
+            .list_subscriptions()
+            .await?
+            .into_iter()
+            .map(|s| (s.id.clone(), s))
+            .collect();
+
+        let mut report = DeliveryReport::default();
+        for mut delivery in due {
+            match subscriptions.get(&delivery.subscription_id) {
+                None => {
+                    // Deleted since the event was queued
+                    delivery.state.abandon("Subscription deleted", now);
+                    report.failed += 1;
+                }
+                Some(subscription) => match self.gateway.post(subscription, &delivery).await {
+                    Ok(status) if (200..300).contains(&status) => {
+                        delivery.response_status = Some(status);
+                        delivery.state.record_success(now);
+                        report.sent += 1;
+                    }
+                    Ok(status) => {
+                        delivery.response_status = Some(status);
+                        delivery.state.record_failure(format!("HTTP {}", status), now, &self.policy);
+                        report.failed += 1;
+                    }
+                    Err(e) => {
+                        delivery.state.record_failure(e.to_string(), now, &self.policy);
+                        report.failed += 1;
+                    }
+                },
+            }
+            self.webhook_repo.save_delivery(&delivery).await?;
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use mockall::mock;
+    use techpulse_domain::delivery::DeliveryStatus;
+    use techpulse_domain::webhook::WebhookData;
+
+    mock! {
+        pub WebhookRepo {}
+        #[async_trait]
+        impl WebhookRepo for WebhookRepo {
+            async fn save_subscription(&self, subscription: &WebhookSubscription) -> Result<(), DomainError>;
+            async fn find_subscription(&self, id: &WebhookId) -> Result<Option<WebhookSubscription>, DomainError>;
+            async fn list_subscriptions(&self) -> Result<Vec<WebhookSubscription>, DomainError>;
+            async fn delete_subscription(&self, id: &WebhookId) -> Result<(), DomainError>;
+            async fn save_delivery(&self, delivery: &WebhookDelivery) -> Result<(), DomainError>;
+            async fn list_due_deliveries(&self, now: i64, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+            async fn list_deliveries(&self, subscription_id: &WebhookId, limit: usize) -> Result<Vec<WebhookDelivery>, DomainError>;
+        }
+    }
+
+    mock! {
+        pub WebhookGateway {}
+        #[async_trait]
+        impl WebhookGateway for WebhookGateway {
+            async fn post(&self, subscription: &WebhookSubscription, delivery: &WebhookDelivery) -> Result<u16, DomainError>;
+        }
+    }
+
+    const SECRET: &str = "0123456789abcdef";
+
+    fn subscription(types: Vec<WebhookEventType>, keywords: &[&str]) -> WebhookSubscription {
+        let keywords: Vec<String> = keywords.iter().map(|k| k.to_string()).collect();
+        WebhookSubscription::new("https://hooks.example.com", SECRET, types, &keywords, 0).unwrap()
+    }
+
+    fn spike(keyword: &str) -> WebhookEvent {
+        WebhookEvent::new(
+            WebhookData::TrendSpike { keyword: keyword.into(), volume: 6, previous_volume: 2 },
+            100,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_publish_queues_for_matching_subscriptions() {
+        let all_spikes = subscription(vec![WebhookEventType::TrendSpike], &[]);
+        let rust_only = subscription(vec![WebhookEventType::TrendSpike], &["rust"]);
+        let emerging = subscription(vec![WebhookEventType::TopicEmerging], &[]);
+
+        let mut repo = MockWebhookRepo::new();
+        let subs = vec![all_spikes.clone(), rust_only.clone(), emerging];
+        repo.expect_list_subscriptions().returning(move || Ok(subs.clone()));
+        let rust_id = rust_only.id.clone();
+        let all_id = all_spikes.id.clone();
+        repo.expect_save_delivery()
+            .times(3)
+            .withf(move |d| d.subscription_id == all_id || (d.subscription_id == rust_id && d.event.mentions("rust")))
+            .returning(|_| Ok(()));
+
+        let queued = PublishWebhookEvents::new(Arc::new(repo))
+            .execute(&[spike("rust"), spike("go")], 100)
+            .await
+            .unwrap();
+        assert_eq!(queued, 3);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_records_outcomes() {
+        let sub = subscription(vec![WebhookEventType::TrendSpike], &[]);
+        let ok = WebhookDelivery::new(sub.id.clone(), spike("rust"), 100);
+        let rejected = WebhookDelivery::new(sub.id.clone(), spike("go"), 100);
+        let orphan = WebhookDelivery::new(WebhookId::from("deleted"), spike("zig"), 100);
+        let (ok_id, rejected_id) = (ok.id.clone(), rejected.id.clone());
+
+        let mut repo = MockWebhookRepo::new();
+        let due = vec![ok, rejected, orphan];
+        repo.expect_list_due_deliveries().returning(move |_, _| Ok(due.clone()));
+        let subs = vec![sub];
+        repo.expect_list_subscriptions().returning(move || Ok(subs.clone()));
+        repo.expect_save_delivery()
+            .times(3)
+            .withf(move |d| {
+                if d.id == ok_id {
+                    d.state.status == DeliveryStatus::Sent && d.response_status == Some(204)
+                } else if d.id == rejected_id {
+                    d.state.status == DeliveryStatus::Retrying
+                        && d.response_status == Some(500)
+                        && d.state.next_attempt_at == Some(200 + RetryPolicy::default().base_delay_secs)
+                } else {
+                    d.state.status == DeliveryStatus::Failed
+                }
+            })
+            .returning(|_| Ok(()));
+
+        let mut gateway = MockWebhookGateway::new();
+        gateway.expect_post().returning(|_, d| {
+            let status = if d.event.mentions("rust") { 204 } else { 500 };
+            Ok(status)
+        });
+
+        let report = DispatchWebhooks::new(Arc::new(repo), Arc::new(gateway), RetryPolicy::default())
+            .execute(200, 50)
+            .await
+            .unwrap();
+        assert_eq!(report, DeliveryReport { sent: 1, failed: 2 });
+    }
+
+    #[tokio::test]
+    async fn test_delete_unknown_webhook() {
+        let mut repo = MockWebhookRepo::new();
+        repo.expect_find_subscription().returning(|_| Ok(None));
+        repo.expect_delete_subscription().never();
+
+        let result = DeleteWebhook::new(Arc::new(repo)).execute(&WebhookId::from("nope")).await;
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+    }
+}
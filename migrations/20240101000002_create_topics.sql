@@ -0,0 +1,9 @@
+-- Migration for the topic taxonomy
+CREATE TABLE IF NOT EXISTS topics (
+    slug TEXT PRIMARY KEY,
+    display_name TEXT NOT NULL,
+    category TEXT NOT NULL DEFAULT 'other',
+    aliases TEXT DEFAULT '[]' -- JSON string
+);
+
+CREATE INDEX IF NOT EXISTS idx_topics_category ON topics(category);
@@ -0,0 +1,12 @@
+-- Migration for topic co-occurrence graphs, one row per period
+CREATE TABLE IF NOT EXISTS cooccurrence_graphs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    period_start INTEGER NOT NULL,
+    period_end INTEGER NOT NULL,
+    document_count INTEGER NOT NULL DEFAULT 0,
+    topic_counts TEXT NOT NULL DEFAULT '{}', -- JSON data
+    edges TEXT NOT NULL DEFAULT '[]', -- JSON data
+    UNIQUE (period_start, period_end)
+);
+
+CREATE INDEX IF NOT EXISTS idx_cooccurrence_graphs_end ON cooccurrence_graphs(period_end);
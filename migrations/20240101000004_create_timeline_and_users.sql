@@ -0,0 +1,27 @@
+-- Migration for timeline events and user profiles
+CREATE TABLE IF NOT EXISTS timeline_events (
+    id TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    date TEXT NOT NULL, -- ISO 8601 (YYYY-MM-DD)
+    description TEXT DEFAULT '',
+    category TEXT DEFAULT '',
+    importance_score REAL DEFAULT 0.0
+);
+
+CREATE TABLE IF NOT EXISTS users (
+    id TEXT PRIMARY KEY,
+    settings TEXT NOT NULL DEFAULT '{}' -- JSON data
+);
+
+CREATE TABLE IF NOT EXISTS user_topic_knowledge (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    topic TEXT NOT NULL,
+    state TEXT NOT NULL DEFAULT 'never_seen',
+    first_seen INTEGER NOT NULL,
+    last_interaction INTEGER NOT NULL,
+    interaction_count INTEGER DEFAULT 0,
+    PRIMARY KEY (user_id, topic)
+);
+
+CREATE INDEX IF NOT EXISTS idx_timeline_events_date ON timeline_events(date);
+CREATE INDEX IF NOT EXISTS idx_user_topic_knowledge_state ON user_topic_knowledge(user_id, state);
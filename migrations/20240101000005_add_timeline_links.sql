@@ -0,0 +1,5 @@
+-- Link timeline events back to their source article
+ALTER TABLE timeline_events ADD COLUMN url TEXT;
+ALTER TABLE timeline_events ADD COLUMN article_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_timeline_events_article ON timeline_events(article_id);
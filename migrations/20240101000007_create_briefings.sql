@@ -0,0 +1,8 @@
+-- Migration for persisted daily briefings
+CREATE TABLE IF NOT EXISTS briefings (
+    user_id TEXT NOT NULL,
+    date TEXT NOT NULL, -- ISO 8601 (YYYY-MM-DD)
+    generated_at INTEGER NOT NULL,
+    content TEXT NOT NULL, -- JSON: stories, trends and nudges
+    PRIMARY KEY (user_id, date)
+);
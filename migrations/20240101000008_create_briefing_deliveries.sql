@@ -0,0 +1,12 @@
+-- Migration for the briefing email delivery log
+CREATE TABLE IF NOT EXISTS briefing_deliveries (
+    user_id TEXT NOT NULL,
+    date TEXT NOT NULL, -- Local date of the briefing (YYYY-MM-DD)
+    email TEXT NOT NULL,
+    status TEXT NOT NULL, -- sent, retrying, failed
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_attempt_at INTEGER NOT NULL,
+    next_attempt_at INTEGER,
+    PRIMARY KEY (user_id, date)
+);
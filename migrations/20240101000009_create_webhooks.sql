@@ -0,0 +1,26 @@
+-- Migration for outbound webhook subscriptions and their delivery log
+CREATE TABLE IF NOT EXISTS webhook_subscriptions (
+    id TEXT PRIMARY KEY,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    event_types TEXT NOT NULL, -- JSON array, e.g. ["trend.spike"]
+    keywords TEXT NOT NULL, -- JSON array of normalized terms
+    active BOOLEAN NOT NULL DEFAULT 1,
+    created_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id TEXT PRIMARY KEY,
+    subscription_id TEXT NOT NULL,
+    event TEXT NOT NULL, -- JSON payload as posted
+    response_status INTEGER,
+    created_at INTEGER NOT NULL,
+    status TEXT NOT NULL, -- sent, retrying, failed
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_attempt_at INTEGER NOT NULL,
+    next_attempt_at INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due ON webhook_deliveries(status, next_attempt_at);
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_subscription ON webhook_deliveries(subscription_id, created_at);
@@ -0,0 +1,16 @@
+-- Migration for per-user watchlists and the alerts they raise
+ALTER TABLE users ADD COLUMN watchlist TEXT NOT NULL DEFAULT '[]'; -- JSON array of watch rules
+
+CREATE TABLE IF NOT EXISTS watch_alerts (
+    user_id TEXT NOT NULL,
+    rule_id TEXT NOT NULL,
+    rule_name TEXT NOT NULL,
+    article_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    url TEXT NOT NULL,
+    source TEXT NOT NULL,
+    matched_at INTEGER NOT NULL,
+    PRIMARY KEY (user_id, rule_id, article_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_watch_alerts_user ON watch_alerts(user_id, matched_at);
@@ -0,0 +1,2 @@
+-- Migration for per-user mute rules
+ALTER TABLE users ADD COLUMN mutes TEXT NOT NULL DEFAULT '[]'; -- JSON array of mute rules
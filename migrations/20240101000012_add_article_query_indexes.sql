@@ -0,0 +1,16 @@
+-- Migration for filtered, cursor-paginated article queries
+-- Host of the article URL, written on save (see Article::domain)
+ALTER TABLE articles ADD COLUMN domain TEXT NOT NULL DEFAULT '';
+
+-- Backfill existing rows: strip scheme, path, query, fragment, credentials, port and "www."
+UPDATE articles SET domain = lower(CASE WHEN instr(url, '://') > 0 THEN substr(url, instr(url, '://') + 3) ELSE url END);
+UPDATE articles SET domain = substr(domain, 1, instr(domain, '/') - 1) WHERE instr(domain, '/') > 0;
+UPDATE articles SET domain = substr(domain, 1, instr(domain, '?') - 1) WHERE instr(domain, '?') > 0;
+UPDATE articles SET domain = substr(domain, 1, instr(domain, '#') - 1) WHERE instr(domain, '#') > 0;
+UPDATE articles SET domain = substr(domain, instr(domain, '@') + 1) WHERE instr(domain, '@') > 0;
+UPDATE articles SET domain = substr(domain, 1, instr(domain, ':') - 1) WHERE instr(domain, ':') > 0;
+UPDATE articles SET domain = substr(domain, 5) WHERE domain LIKE 'www.%';
+
+CREATE INDEX IF NOT EXISTS idx_articles_domain ON articles(domain);
+-- Keyset pagination order
+CREATE INDEX IF NOT EXISTS idx_articles_timestamp_id ON articles(timestamp DESC, id DESC);
@@ -0,0 +1,30 @@
+-- Migration for full-text article search
+ALTER TABLE articles ADD COLUMN text TEXT NOT NULL DEFAULT ''; -- Self-post body or extracted page text
+
+-- External-content index over articles; column order matches search::COLUMN_WEIGHTS
+CREATE VIRTUAL TABLE IF NOT EXISTS articles_fts USING fts5(
+    title, tags, author, text,
+    content = 'articles',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- Keep the index in sync with every write (articles are saved with upserts, so rowids are stable)
+CREATE TRIGGER IF NOT EXISTS articles_fts_insert AFTER INSERT ON articles BEGIN
+    INSERT INTO articles_fts (rowid, title, tags, author, text)
+    VALUES (new.rowid, new.title, new.tags, new.author, new.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS articles_fts_delete AFTER DELETE ON articles BEGIN
+    INSERT INTO articles_fts (articles_fts, rowid, title, tags, author, text)
+    VALUES ('delete', old.rowid, old.title, old.tags, old.author, old.text);
+END;
+
+CREATE TRIGGER IF NOT EXISTS articles_fts_update AFTER UPDATE ON articles BEGIN
+    INSERT INTO articles_fts (articles_fts, rowid, title, tags, author, text)
+    VALUES ('delete', old.rowid, old.title, old.tags, old.author, old.text);
+    INSERT INTO articles_fts (rowid, title, tags, author, text)
+    VALUES (new.rowid, new.title, new.tags, new.author, new.text);
+END;
+
+-- Index articles stored before this migration
+INSERT INTO articles_fts (articles_fts) VALUES ('rebuild');
@@ -0,0 +1,9 @@
+-- Migration for per-ingest article metric snapshots
+CREATE TABLE IF NOT EXISTS article_snapshots (
+    article_id TEXT NOT NULL,
+    captured_at INTEGER NOT NULL,
+    score REAL NOT NULL DEFAULT 0.0,
+    comment_count INTEGER NOT NULL DEFAULT 0,
+    is_hot_on_source BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (article_id, captured_at)
+);
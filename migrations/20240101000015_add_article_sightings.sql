@@ -0,0 +1,7 @@
+-- Migration for tracking when ingests saw each article
+ALTER TABLE articles ADD COLUMN first_seen_at INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE articles ADD COLUMN last_seen_at INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE articles ADD COLUMN times_seen INTEGER NOT NULL DEFAULT 1;
+
+-- Existing rows were last written by an ingest; their publish time is the best guess we have
+UPDATE articles SET first_seen_at = timestamp, last_seen_at = timestamp;
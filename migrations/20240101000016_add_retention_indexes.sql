@@ -0,0 +1,4 @@
+-- Migration for the pruning job
+-- Bookmarked ("want to learn") articles are kept regardless of age
+CREATE INDEX IF NOT EXISTS idx_interactions_kind_article ON interactions(kind, article_id);
+CREATE INDEX IF NOT EXISTS idx_article_snapshots_captured_at ON article_snapshots(captured_at);
@@ -0,0 +1,179 @@
+-- PostgreSQL schema, equivalent to the SQLite migrations one directory up as of
+-- 20240101000016. Integers are BIGINT because the repos read them as i64; JSON
+-- stays in TEXT columns so both backends bind and decode the same strings.
+CREATE TABLE IF NOT EXISTS articles (
+    id TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    url TEXT NOT NULL DEFAULT '',
+    domain TEXT NOT NULL DEFAULT '', -- Host of the article URL, written on save (see Article::domain)
+    source TEXT NOT NULL,
+    score DOUBLE PRECISION DEFAULT 0.0,
+    author TEXT DEFAULT '',
+    timestamp BIGINT NOT NULL,
+    tags TEXT DEFAULT '[]', -- JSON string
+    comment_count BIGINT DEFAULT 0,
+    is_hot_on_source BOOLEAN DEFAULT FALSE,
+    text TEXT NOT NULL DEFAULT '', -- Self-post body or extracted page text
+    first_seen_at BIGINT NOT NULL DEFAULT 0,
+    last_seen_at BIGINT NOT NULL DEFAULT 0,
+    times_seen BIGINT NOT NULL DEFAULT 1,
+    -- Weights A to D follow search::COLUMN_WEIGHTS; the JSON tags tokenize as plain words
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', title), 'A') ||
+        setweight(to_tsvector('simple', coalesce(tags, '')), 'B') ||
+        setweight(to_tsvector('simple', coalesce(author, '')), 'C') ||
+        setweight(to_tsvector('simple', text), 'D')
+    ) STORED
+);
+
+CREATE INDEX IF NOT EXISTS idx_articles_timestamp ON articles(timestamp);
+CREATE INDEX IF NOT EXISTS idx_articles_domain ON articles(domain);
+-- Keyset pagination order
+CREATE INDEX IF NOT EXISTS idx_articles_timestamp_id ON articles(timestamp DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_articles_search ON articles USING GIN (search_vector);
+
+CREATE TABLE IF NOT EXISTS article_snapshots (
+    article_id TEXT NOT NULL,
+    captured_at BIGINT NOT NULL,
+    score DOUBLE PRECISION NOT NULL DEFAULT 0.0,
+    comment_count BIGINT NOT NULL DEFAULT 0,
+    is_hot_on_source BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (article_id, captured_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_article_snapshots_captured_at ON article_snapshots(captured_at);
+
+CREATE TABLE IF NOT EXISTS trends (
+    id BIGSERIAL PRIMARY KEY,
+    timestamp BIGINT NOT NULL,
+    data TEXT NOT NULL, -- JSON data
+    metadata TEXT DEFAULT '{}' -- JSON data
+);
+
+CREATE INDEX IF NOT EXISTS idx_trends_timestamp ON trends(timestamp);
+
+CREATE TABLE IF NOT EXISTS topics (
+    slug TEXT PRIMARY KEY,
+    display_name TEXT NOT NULL,
+    category TEXT NOT NULL DEFAULT 'other',
+    aliases TEXT DEFAULT '[]' -- JSON string
+);
+
+CREATE INDEX IF NOT EXISTS idx_topics_category ON topics(category);
+
+CREATE TABLE IF NOT EXISTS cooccurrence_graphs (
+    id BIGSERIAL PRIMARY KEY,
+    period_start BIGINT NOT NULL,
+    period_end BIGINT NOT NULL,
+    document_count BIGINT NOT NULL DEFAULT 0,
+    topic_counts TEXT NOT NULL DEFAULT '{}', -- JSON data
+    edges TEXT NOT NULL DEFAULT '[]', -- JSON data
+    UNIQUE (period_start, period_end)
+);
+
+CREATE INDEX IF NOT EXISTS idx_cooccurrence_graphs_end ON cooccurrence_graphs(period_end);
+
+CREATE TABLE IF NOT EXISTS timeline_events (
+    id TEXT PRIMARY KEY,
+    title TEXT NOT NULL,
+    date TEXT NOT NULL, -- ISO 8601 (YYYY-MM-DD)
+    description TEXT DEFAULT '',
+    category TEXT DEFAULT '',
+    importance_score DOUBLE PRECISION DEFAULT 0.0,
+    url TEXT,
+    article_id TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_timeline_events_date ON timeline_events(date);
+CREATE INDEX IF NOT EXISTS idx_timeline_events_article ON timeline_events(article_id);
+
+CREATE TABLE IF NOT EXISTS users (
+    id TEXT PRIMARY KEY,
+    settings TEXT NOT NULL DEFAULT '{}', -- JSON data
+    watchlist TEXT NOT NULL DEFAULT '[]', -- JSON array of watch rules
+    mutes TEXT NOT NULL DEFAULT '[]' -- JSON array of mute rules
+);
+
+CREATE TABLE IF NOT EXISTS user_topic_knowledge (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    topic TEXT NOT NULL,
+    state TEXT NOT NULL DEFAULT 'never_seen',
+    first_seen BIGINT NOT NULL,
+    last_interaction BIGINT NOT NULL,
+    interaction_count BIGINT DEFAULT 0,
+    PRIMARY KEY (user_id, topic)
+);
+
+CREATE INDEX IF NOT EXISTS idx_user_topic_knowledge_state ON user_topic_knowledge(user_id, state);
+
+CREATE TABLE IF NOT EXISTS interactions (
+    id BIGSERIAL PRIMARY KEY,
+    user_id TEXT NOT NULL,
+    article_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    dwell_secs BIGINT, -- Only set for dwell events
+    timestamp BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_interactions_user_timestamp ON interactions(user_id, timestamp);
+CREATE INDEX IF NOT EXISTS idx_interactions_kind_article ON interactions(kind, article_id);
+
+CREATE TABLE IF NOT EXISTS briefings (
+    user_id TEXT NOT NULL,
+    date TEXT NOT NULL, -- ISO 8601 (YYYY-MM-DD)
+    generated_at BIGINT NOT NULL,
+    content TEXT NOT NULL, -- JSON: stories, trends and nudges
+    PRIMARY KEY (user_id, date)
+);
+
+CREATE TABLE IF NOT EXISTS briefing_deliveries (
+    user_id TEXT NOT NULL,
+    date TEXT NOT NULL, -- Local date of the briefing (YYYY-MM-DD)
+    email TEXT NOT NULL,
+    status TEXT NOT NULL, -- sent, retrying, failed
+    attempts BIGINT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_attempt_at BIGINT NOT NULL,
+    next_attempt_at BIGINT,
+    PRIMARY KEY (user_id, date)
+);
+
+CREATE TABLE IF NOT EXISTS webhook_subscriptions (
+    id TEXT PRIMARY KEY,
+    url TEXT NOT NULL,
+    secret TEXT NOT NULL,
+    event_types TEXT NOT NULL, -- JSON array, e.g. ["trend.spike"]
+    keywords TEXT NOT NULL, -- JSON array of normalized terms
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at BIGINT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id TEXT PRIMARY KEY,
+    subscription_id TEXT NOT NULL,
+    event TEXT NOT NULL, -- JSON payload as posted
+    response_status BIGINT,
+    created_at BIGINT NOT NULL,
+    status TEXT NOT NULL, -- sent, retrying, failed
+    attempts BIGINT NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_attempt_at BIGINT NOT NULL,
+    next_attempt_at BIGINT
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due ON webhook_deliveries(status, next_attempt_at);
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_subscription ON webhook_deliveries(subscription_id, created_at);
+
+CREATE TABLE IF NOT EXISTS watch_alerts (
+    user_id TEXT NOT NULL,
+    rule_id TEXT NOT NULL,
+    rule_name TEXT NOT NULL,
+    article_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    url TEXT NOT NULL,
+    source TEXT NOT NULL,
+    matched_at BIGINT NOT NULL,
+    PRIMARY KEY (user_id, rule_id, article_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_watch_alerts_user ON watch_alerts(user_id, matched_at);